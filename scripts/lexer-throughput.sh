@@ -0,0 +1,68 @@
+#!/bin/bash
+# Lexer and parser throughput measurement for thrushc
+# Usage: ./lexer-throughput.sh [functions]
+# This script should be run from the /scripts directory
+
+set -e
+
+functions=20000
+project_root=".."
+binary_path="$project_root/target/release/thrushc"
+work_dir="$(mktemp -d)"
+source_file="$work_dir/throughput.thrush"
+
+if [ $# -gt 0 ]; then
+    functions="$1"
+fi
+
+if ! [[ "$functions" =~ ^[0-9]+$ ]]; then
+    echo "Error: Invalid function count '$functions'."
+    exit 1
+fi
+
+if [ ! -f "$binary_path" ]; then
+    echo "Error: Binary not found at $binary_path. Build it first with 'cargo build --release'."
+    exit 1
+fi
+
+trap 'rm -rf "$work_dir"' EXIT
+
+echo "Generating synthetic input with $functions functions..."
+
+{
+    echo 'fn print(fmt: ptr) s32 @public @ignore @extern("printf");'
+    echo
+
+    for ((i = 0; i < functions; i++)); do
+        echo "fn compute_$i(a: s32, b: s32) s32 {"
+        echo "    local mut acc: s32 = a * b + $i;"
+        echo "    for local mut j: s32 = 0; j < 16; ++j; {"
+        echo "        if (acc % 2) == 0 { acc = acc / 2; } else { acc = acc * 3 + 1; }"
+        echo "    }"
+        echo "    print(\"compute_$i: %d\\n\", acc);"
+        echo "    return acc;"
+        echo "}"
+        echo
+    done
+
+    echo "fn main() s32 {"
+    echo "    return compute_0(1, 2);"
+    echo "}"
+} > "$source_file"
+
+input_size=$(du -h "$source_file" | cut -f1)
+echo "Synthetic input: $source_file ($input_size)"
+
+measure() {
+    local emitable="$1"
+    local start end
+
+    start=$(date +%s.%N)
+    "$binary_path" -llvm -build-dir "$work_dir/build" -emit "$emitable" "$source_file" > /dev/null
+    end=$(date +%s.%N)
+
+    echo "-emit $emitable: $(echo "$end - $start" | bc) seconds"
+}
+
+measure tokens
+measure ast
@@ -0,0 +1,55 @@
+#!/bin/bash
+# DWARF debug information verification for thrushc
+# Usage: ./verify-dwarf.sh [file.thrush]
+# This script should be run from the /scripts directory
+
+set -e
+
+project_root=".."
+binary_path="$project_root/target/release/thrushc"
+source_file="$project_root/examples/algorithms/bubblesort.thrush"
+work_dir="$(mktemp -d)"
+dwarfdump="${LLVM_DWARFDUMP:-llvm-dwarfdump}"
+
+if [ $# -gt 0 ]; then
+    source_file="$1"
+fi
+
+if [ ! -f "$binary_path" ]; then
+    echo "Error: Binary not found at $binary_path. Build it first with 'cargo build --release'."
+    exit 1
+fi
+
+if ! command -v "$dwarfdump" > /dev/null; then
+    echo "Error: '$dwarfdump' not found. Set LLVM_DWARFDUMP to its path."
+    exit 1
+fi
+
+trap 'rm -rf "$work_dir"' EXIT
+
+"$binary_path" -llvm -g -build-dir "$work_dir/build" --no-obfuscate-archive-names -emit obj "$source_file" > /dev/null
+
+object_file=$(find "$work_dir/build/emit/obj" -name "*.o" | head -n 1)
+
+if [ -z "$object_file" ]; then
+    echo "Error: No object file was emitted for $source_file."
+    exit 1
+fi
+
+echo "Verifying $object_file..."
+
+"$dwarfdump" --verify "$object_file"
+
+for tag in DW_TAG_compile_unit DW_TAG_subprogram DW_TAG_formal_parameter DW_TAG_variable; do
+    if ! "$dwarfdump" --debug-info "$object_file" | grep -q "$tag"; then
+        echo "Error: Missing $tag in the emitted debug information."
+        exit 1
+    fi
+done
+
+if ! "$dwarfdump" --debug-line "$object_file" | grep -q "$(basename "$source_file")"; then
+    echo "Error: The line table does not reference $(basename "$source_file")."
+    exit 1
+fi
+
+echo "DWARF debug information is valid."
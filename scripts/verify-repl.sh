@@ -0,0 +1,53 @@
+#!/bin/bash
+# REPL session verification for thrushc
+# Usage: ./verify-repl.sh
+# This script should be run from the /scripts directory
+
+set -e
+
+project_root=".."
+binary_path="$project_root/target/release/thrushc"
+
+if [ ! -f "$binary_path" ]; then
+    echo "Error: Binary not found at $binary_path. Build it first with 'cargo build --release'."
+    exit 1
+fi
+
+failures=0
+
+# Runs a session and checks that its output has the expected line.
+expect() {
+    local description="$1"
+    local session="$2"
+    local expected="$3"
+    local output
+
+    output=$(printf '%b' "$session" | "$binary_path" repl 2>&1 | sed 's/\x1b\[[0-9;]*m//g')
+
+    if echo "$output" | grep -qF "$expected"; then
+        echo "ok: $description"
+    else
+        echo "FAILED: $description"
+        echo "$output" | sed 's/^/    /'
+        failures=$((failures + 1))
+    fi
+}
+
+expect "an expression reads a previous binding" \
+    'local x: s32 = 41;\nx + 1\n:quit\n' \
+    '42: s32'
+
+expect "a statement updates a previous mutable binding" \
+    'local mut x: s32 = 1;\nx = x + 1;\nx\n:quit\n' \
+    '2: s32'
+
+expect "a function reads its arguments from a previous binding" \
+    'fn twice(n: s32) s32 { return n * 2; }\nlocal y: s32 = 21;\ntwice(y)\n:quit\n' \
+    '42: s32'
+
+if [ "$failures" -gt 0 ]; then
+    echo "$failures REPL session(s) failed."
+    exit 1
+fi
+
+echo "All REPL sessions passed."
@@ -0,0 +1,173 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::core::console::logging::{self, LoggingType};
+
+const ARCHIVE_MAGIC: &[u8] = b"!<arch>\n";
+const MEMBER_HEADER_SIZE: usize = 60;
+const MEMBER_NAME_MAX_LENGTH: usize = 15;
+
+/// Writes a GNU 'ar' static library with a symbol index, so linkers can
+/// resolve the archive members without running 'ranlib' first.
+pub struct Archiver<'archiver> {
+    files: &'archiver [PathBuf],
+    symbols: &'archiver [Vec<String>],
+    output: &'archiver Path,
+}
+
+impl<'archiver> Archiver<'archiver> {
+    pub fn new(
+        files: &'archiver [PathBuf],
+        symbols: &'archiver [Vec<String>],
+        output: &'archiver Path,
+    ) -> Self {
+        Self {
+            files,
+            symbols,
+            output,
+        }
+    }
+
+    pub fn archive(&self) -> Result<Duration, ()> {
+        let start_time: Instant = Instant::now();
+
+        let mut members: Vec<(String, Vec<u8>)> = Vec::with_capacity(self.files.len());
+
+        for file in self.files.iter() {
+            let Ok(content) = fs::read(file) else {
+                logging::log(
+                    LoggingType::Error,
+                    &format!("Object file '{}' cannot be read.", file.display()),
+                );
+
+                return Err(());
+            };
+
+            let name: String = file
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            members.push((name, content));
+        }
+
+        let archive: Vec<u8> = self.build_archive(&members);
+
+        if fs::write(self.output, archive).is_err() {
+            logging::log(
+                LoggingType::Error,
+                &format!(
+                    "Static library '{}' cannot be written.",
+                    self.output.display()
+                ),
+            );
+
+            return Err(());
+        }
+
+        Ok(start_time.elapsed())
+    }
+
+    fn build_archive(&self, members: &[(String, Vec<u8>)]) -> Vec<u8> {
+        let mut long_names: Vec<u8> = Vec::with_capacity(members.len() * 32);
+        let mut member_names: Vec<String> = Vec::with_capacity(members.len());
+
+        members.iter().for_each(|(name, _)| {
+            if name.len() <= MEMBER_NAME_MAX_LENGTH {
+                member_names.push(format!("{}/", name));
+            } else {
+                member_names.push(format!("/{}", long_names.len()));
+
+                long_names.extend_from_slice(name.as_bytes());
+                long_names.extend_from_slice(b"/\n");
+            }
+        });
+
+        let symbols_count: usize = self.symbols.iter().map(Vec::len).sum();
+
+        let symbol_table_size: usize = 4
+            + (symbols_count * 4)
+            + self
+                .symbols
+                .iter()
+                .flatten()
+                .map(|symbol| symbol.len() + 1)
+                .sum::<usize>();
+
+        let mut offset: usize = ARCHIVE_MAGIC.len() + self.get_member_size(symbol_table_size);
+
+        if !long_names.is_empty() {
+            offset += self.get_member_size(long_names.len());
+        }
+
+        let mut member_offsets: Vec<u32> = Vec::with_capacity(members.len());
+
+        members.iter().for_each(|(_, content)| {
+            member_offsets.push(offset as u32);
+            offset += self.get_member_size(content.len());
+        });
+
+        let mut symbol_table: Vec<u8> = Vec::with_capacity(symbol_table_size);
+
+        symbol_table.extend_from_slice(&(symbols_count as u32).to_be_bytes());
+
+        self.symbols
+            .iter()
+            .zip(member_offsets.iter())
+            .for_each(|(symbols, member_offset)| {
+                symbols.iter().for_each(|_| {
+                    symbol_table.extend_from_slice(&member_offset.to_be_bytes());
+                });
+            });
+
+        self.symbols.iter().flatten().for_each(|symbol| {
+            symbol_table.extend_from_slice(symbol.as_bytes());
+            symbol_table.push(0);
+        });
+
+        let mut archive: Vec<u8> = Vec::with_capacity(offset);
+
+        archive.extend_from_slice(ARCHIVE_MAGIC);
+
+        self.write_member(&mut archive, "/", &symbol_table);
+
+        if !long_names.is_empty() {
+            self.write_member(&mut archive, "//", &long_names);
+        }
+
+        members
+            .iter()
+            .zip(member_names.iter())
+            .for_each(|((_, content), name)| {
+                self.write_member(&mut archive, name, content);
+            });
+
+        archive
+    }
+
+    fn write_member(&self, archive: &mut Vec<u8>, name: &str, content: &[u8]) {
+        let header: String = format!(
+            "{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+            name,
+            0,
+            0,
+            0,
+            100644,
+            content.len()
+        );
+
+        archive.extend_from_slice(header.as_bytes());
+        archive.extend_from_slice(content);
+
+        if !content.len().is_multiple_of(2) {
+            archive.push(b'\n');
+        }
+    }
+
+    fn get_member_size(&self, content_size: usize) -> usize {
+        MEMBER_HEADER_SIZE + content_size + (content_size % 2)
+    }
+}
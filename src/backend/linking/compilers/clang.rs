@@ -6,12 +6,18 @@ use std::{
 
 use inkwell::targets::TargetTriple;
 
-use crate::core::{
-    compiler::linking::LinkingCompilersConfiguration,
-    console::logging::{self, LoggingType},
+use crate::{
+    backend::linking,
+    core::{
+        compiler::{
+            linking::{CrateType, LinkingCompilersConfiguration},
+            passes::LLVMSanitizer,
+        },
+        console::logging::{self, LoggingType},
+    },
 };
 
-use super::decompressor;
+use super::decompressor::{self, EmbeddedToolchain};
 
 #[cfg(target_os = "linux")]
 pub static LINUX_X86_64_CLANG: &[u8] =
@@ -33,6 +39,7 @@ pub struct Clang<'clang> {
     files: &'clang [PathBuf],
     config: &'clang LinkingCompilersConfiguration,
     target: &'clang TargetTriple,
+    sanitizers: &'clang [LLVMSanitizer],
 }
 
 impl<'clang> Clang<'clang> {
@@ -40,102 +47,53 @@ impl<'clang> Clang<'clang> {
         files: &'clang [PathBuf],
         config: &'clang LinkingCompilersConfiguration,
         target: &'clang TargetTriple,
+        sanitizers: &'clang [LLVMSanitizer],
     ) -> Self {
         Self {
             files,
             config,
             target,
+            sanitizers,
         }
     }
 
     pub fn link(&self) -> Result<Duration, ()> {
         let start_time: Instant = Instant::now();
 
-        #[cfg(target_os = "linux")]
-        {
-            if self.config.get_use_clang() {
-                if let Some(custom_clang) = self.config.get_custom_clang() {
-                    if self.handle_command(&mut self.build_clang_command(custom_clang)) {
-                        return Ok(start_time.elapsed());
-                    }
-
-                    return Err(());
-                }
-
-                let embedded_raw_clang: (&'static [u8], &'static str, PathBuf, PathBuf, PathBuf) =
-                    get_x86_64_linux_clang();
-                let clang_raw_bytes: &'static [u8] = embedded_raw_clang.0;
-                let clang_manifest: &'static str = embedded_raw_clang.1;
-                let clang_manifest_path: PathBuf = embedded_raw_clang.2;
-                let clang_tar_path: PathBuf = embedded_raw_clang.3;
-                let clang_output_path: PathBuf = embedded_raw_clang.4;
-
-                if let Ok(clang_path) = decompressor::dump_x86_64_clang_linux(
-                    clang_manifest,
-                    clang_raw_bytes,
-                    clang_manifest_path,
-                    clang_tar_path,
-                    clang_output_path,
-                ) {
-                    if self.handle_command(&mut self.build_clang_command(&clang_path)) {
-                        return Ok(start_time.elapsed());
-                    }
-
-                    return Err(());
-                }
-
-                return Err(());
-            }
-
-            Err(())
+        if !self.config.get_use_clang() {
+            return Err(());
         }
 
-        #[cfg(target_os = "windows")]
-        {
-            if self.config.use_clang() {
-                if let Some(custom_clang) = self.config.get_custom_clang() {
-                    if self.handle_command(&mut self.build_clang_command(custom_clang)) {
-                        return Ok(start_time.elapsed());
-                    }
-                    return Err(());
-                }
-
-                let embedded_raw_clang: (&'static [u8], &'static str, PathBuf, PathBuf, PathBuf) =
-                    get_x86_64_windows_clang();
-                let clang_raw_bytes: &'static [u8] = embedded_raw_clang.0;
-                let clang_manifest: &'static str = embedded_raw_clang.1;
-                let clang_manifest_path: PathBuf = embedded_raw_clang.2;
-                let clang_tar_path: PathBuf = embedded_raw_clang.3;
-                let clang_output_path: PathBuf = embedded_raw_clang.4;
-
-                if let Ok(clang_path) = decompressor::dump_x86_64_clang_windows(
-                    clang_manifest,
-                    clang_raw_bytes,
-                    clang_manifest_path,
-                    clang_tar_path,
-                    clang_output_path,
-                ) {
-                    if self.handle_command(&mut self.build_clang_command(&clang_path)) {
-                        return Ok(start_time.elapsed());
-                    }
-
-                    return Err(());
-                }
-
-                return Err(());
+        if let Some(custom_clang) = self.config.get_custom_clang() {
+            if self.handle_command(&mut self.build_clang_command(custom_clang)) {
+                return Ok(start_time.elapsed());
             }
 
             return Err(());
         }
 
-        #[cfg(not(any(target_os = "linux", target_os = "windows")))]
-        {
+        let Some(embedded_clang) = self::get_embedded_clang() else {
             logging::log(
                 LoggingType::Error,
                 "C compiler 'clang' is not supported for the current operating system.",
             );
 
-            Err(())
+            return Err(());
+        };
+
+        match decompressor::install(&embedded_clang, false) {
+            Ok(clang_path) => {
+                if self.handle_command(&mut self.build_clang_command(&clang_path)) {
+                    return Ok(start_time.elapsed());
+                }
+
+                Err(())
+            }
+
+            Err(error) => {
+                logging::log(LoggingType::Error, &error);
+                Err(())
+            }
         }
     }
 
@@ -147,9 +105,30 @@ impl<'clang> Clang<'clang> {
         clang_command.arg("-target");
         clang_command.arg(self.target.as_str().to_string_lossy().into_owned());
 
+        match self.config.get_crate_type() {
+            CrateType::SharedLibrary => {
+                clang_command.arg("-shared");
+            }
+            CrateType::Object => {
+                clang_command.arg("-r");
+            }
+            _ => (),
+        }
+
+        clang_command.args(
+            self.sanitizers
+                .iter()
+                .filter_map(|sanitizer| sanitizer.get_runtime_flag()),
+        );
+
         clang_command.args(self.files.iter());
         clang_command.args(self.config.get_args().iter());
 
+        if let Some(output) = self.config.get_output() {
+            clang_command.arg("-o");
+            clang_command.arg(output);
+        }
+
         if self.config.get_debug_clang_commands() {
             logging::log(
                 LoggingType::Info,
@@ -161,6 +140,11 @@ impl<'clang> Clang<'clang> {
     }
 
     pub fn handle_command(&self, command: &mut Command) -> bool {
+        if self.config.get_link_dry_run() {
+            linking::print_command(command);
+            return true;
+        }
+
         if let Ok(clang) = command.output() {
             if !clang.status.success() {
                 if !clang.stderr.is_empty() {
@@ -187,24 +171,30 @@ impl<'clang> Clang<'clang> {
     }
 }
 
-#[cfg(target_os = "linux")]
-pub fn get_x86_64_linux_clang() -> (&'static [u8], &'static str, PathBuf, PathBuf, PathBuf) {
-    (
-        LINUX_X86_64_CLANG,
-        LINUX_X86_64_CLANG_MANIFEST,
-        PathBuf::from("clang-manifest.json"),
-        PathBuf::from("clang-linux-x86_64.tar.xz"),
-        PathBuf::from("clang-17"),
-    )
-}
+/// The Clang toolchain embedded for the host operating system, if any.
+pub fn get_embedded_clang() -> Option<EmbeddedToolchain> {
+    #[cfg(target_os = "linux")]
+    {
+        Some(EmbeddedToolchain::new(
+            LINUX_X86_64_CLANG_MANIFEST,
+            LINUX_X86_64_CLANG,
+            "clang-linux-x86_64.tar.xz",
+            "clang-17",
+        ))
+    }
 
-#[cfg(target_os = "windows")]
-pub fn get_x86_64_windows_clang() -> (&'static [u8], &'static str, PathBuf, PathBuf, PathBuf) {
-    (
-        WINDOWS_X86_64_CLANG,
-        WINDOWS_X86_64_CLANG_MANIFEST,
-        PathBuf::from("clang-manifest.json"),
-        PathBuf::from("clang-windows-x86_64.zip"),
-        PathBuf::from("bin"),
-    )
+    #[cfg(target_os = "windows")]
+    {
+        Some(EmbeddedToolchain::new(
+            WINDOWS_X86_64_CLANG_MANIFEST,
+            WINDOWS_X86_64_CLANG,
+            "clang-windows-x86_64.zip",
+            "bin/bin/clang.exe",
+        ))
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        None
+    }
 }
@@ -3,13 +3,13 @@ use {fs::Permissions, std::os::unix::fs::PermissionsExt};
 
 use std::{
     env,
-    fs::{self, File, write},
-    io::BufReader,
+    fs::{self, File},
     path::{Path, PathBuf},
+    time::Duration,
 };
 
-use tar::Archive;
-use xz2::bufread::XzDecoder;
+#[cfg(target_os = "linux")]
+use {std::io::BufReader, tar::Archive, xz2::bufread::XzDecoder};
 
 #[cfg(target_os = "windows")]
 use std::io::{self};
@@ -17,163 +17,397 @@ use std::io::{self};
 #[cfg(target_os = "windows")]
 use zip::{ZipArchive, read::ZipFile};
 
-use crate::core::console::logging::{self, LoggingType};
+use colored::Colorize;
 
-#[cfg(target_os = "linux")]
-pub fn dump_x86_64_clang_linux(
-    clang_raw_manifest: &str,
-    clang_raw_bytes: &[u8],
-    clang_manifest_path: PathBuf,
-    compressed_file_path: PathBuf,
-    output_path: PathBuf,
-) -> Result<PathBuf, ()> {
-    let raw_home_path: String = env::var("HOME").unwrap_or_else(|_| {
-        logging::log(LoggingType::Panic, "Unable to get %HOME% path at linux.");
-        unreachable!()
-    });
-
-    let home_path: PathBuf = PathBuf::from(raw_home_path);
-
-    if home_path.exists() {
-        let llvm_backend: PathBuf = home_path.join("thrushlang/backends/llvm/linux");
-
-        if !llvm_backend.exists() {
-            let _ = fs::create_dir_all(&llvm_backend);
-        }
+use crate::core::{console::logging, utils::sha256};
 
-        let compressed_file: PathBuf = llvm_backend.join(compressed_file_path);
-        let output_path: PathBuf = llvm_backend.join(output_path);
-        let manifest_path: PathBuf = llvm_backend.join(clang_manifest_path);
+const MANIFEST_FILE: &str = "clang-manifest.json";
 
-        if !manifest_path.exists() {
-            let _ = write(manifest_path, clang_raw_manifest);
-        }
+/// A Clang toolchain embedded in the thrushc binary, with the manifest that
+/// describes its version and the SHA-256 of its archive.
+#[derive(Debug)]
+pub struct EmbeddedToolchain {
+    raw_manifest: &'static str,
+    raw_bytes: &'static [u8],
+    archive: &'static str,
+    executable: &'static str,
+}
 
-        if output_path.exists() {
-            return Ok(output_path);
-        }
+#[derive(Debug, PartialEq, Eq)]
+struct ToolchainManifest {
+    version: String,
+    sha256: String,
+}
 
-        let _ = write(&compressed_file, clang_raw_bytes);
+#[derive(Debug)]
+enum ToolchainState {
+    Missing,
+    Ready,
+    Broken(String),
+}
 
-        if let Ok(file) = File::open(&compressed_file) {
-            let buff_reader: BufReader<File> = BufReader::new(file);
-            let xz_decoded: XzDecoder<BufReader<File>> = XzDecoder::new(buff_reader);
-            let mut tar_file: Archive<XzDecoder<BufReader<File>>> = Archive::new(xz_decoded);
+impl EmbeddedToolchain {
+    pub fn new(
+        raw_manifest: &'static str,
+        raw_bytes: &'static [u8],
+        archive: &'static str,
+        executable: &'static str,
+    ) -> Self {
+        Self {
+            raw_manifest,
+            raw_bytes,
+            archive,
+            executable,
+        }
+    }
+}
 
-            if tar_file.unpack(llvm_backend).is_ok() {
-                if self::make_linux_executable(&output_path).is_ok() {
-                    return Ok(output_path);
-                }
+impl ToolchainManifest {
+    /// Reads the 'version' and 'sha256' fields of a flat JSON object.
+    fn parse(raw: &str) -> Option<Self> {
+        Some(Self {
+            version: self::get_json_string(raw, "version")?,
+            sha256: self::get_json_string(raw, "sha256")?.to_lowercase(),
+        })
+    }
+}
 
-                logging::log(
-                    logging::LoggingType::Error,
-                    "Failed to make Clang executable at linux.",
-                );
+/// Returns the path of the Clang executable, unpacking the embedded toolchain first
+/// when it's missing, incomplete or from another version.
+pub fn install(toolchain: &EmbeddedToolchain, force: bool) -> Result<PathBuf, String> {
+    let home: PathBuf = self::get_toolchain_home()?;
+    let manifest: ToolchainManifest = self::get_embedded_manifest(toolchain)?;
+
+    let install_dir: PathBuf = home.join(&manifest.version);
+    let executable: PathBuf = install_dir.join(toolchain.executable);
+
+    if !force
+        && matches!(
+            self::get_state(toolchain, &manifest, &install_dir),
+            ToolchainState::Ready
+        )
+    {
+        return Ok(executable);
+    }
 
-                return Err(());
-            }
+    fs::create_dir_all(&home).map_err(|error| {
+        format!(
+            "Cannot create the toolchain directory '{}': {}. Make sure your home directory is writable, or link with '-custom-clang' or '-ld'.",
+            home.display(),
+            error
+        )
+    })?;
+
+    if sha256::digest(toolchain.raw_bytes) != manifest.sha256 {
+        return Err(String::from(
+            "The embedded Clang archive doesn't match the SHA-256 of its manifest. This thrushc build is corrupted.",
+        ));
+    }
 
-            logging::log(
-                logging::LoggingType::Error,
-                "Failed to decompress Clang executable at linux.",
-            );
+    let temporal_dir: PathBuf =
+        home.join(format!(".{}.tmp-{}", manifest.version, std::process::id()));
 
-            return Err(());
-        }
+    let unpacked: Result<(), String> = self::unpack(toolchain, &temporal_dir);
 
-        logging::log(
-            logging::LoggingType::Error,
-            "Failed to get Clang compressed at linux.",
-        );
+    if let Err(error) = unpacked {
+        let _ = fs::remove_dir_all(&temporal_dir);
+        return Err(error);
+    }
 
-        return Err(());
+    if !force
+        && matches!(
+            self::get_state(toolchain, &manifest, &install_dir),
+            ToolchainState::Ready
+        )
+    {
+        let _ = fs::remove_dir_all(&temporal_dir);
+        return Ok(executable);
     }
 
-    logging::log(
-        logging::LoggingType::Error,
-        "%HOME% path not exist at linux.",
+    if install_dir.exists() {
+        fs::remove_dir_all(&install_dir).map_err(|error| {
+            format!(
+                "Cannot replace the toolchain at '{}': {}.",
+                install_dir.display(),
+                error
+            )
+        })?;
+    }
+
+    fs::rename(&temporal_dir, &install_dir).map_err(|error| {
+        let _ = fs::remove_dir_all(&temporal_dir);
+
+        format!(
+            "Cannot move the toolchain into '{}': {}.",
+            install_dir.display(),
+            error
+        )
+    })?;
+
+    self::remove_stale_entries(&home, &install_dir);
+
+    Ok(executable)
+}
+
+pub fn status(toolchain: &EmbeddedToolchain) -> Result<(), String> {
+    let home: PathBuf = self::get_toolchain_home()?;
+    let manifest: ToolchainManifest = self::get_embedded_manifest(toolchain)?;
+
+    let install_dir: PathBuf = home.join(&manifest.version);
+
+    let state: String = match self::get_state(toolchain, &manifest, &install_dir) {
+        ToolchainState::Ready => "ready".bright_green().bold().to_string(),
+        ToolchainState::Missing => "not installed".yellow().bold().to_string(),
+        ToolchainState::Broken(reason) => format!("{} ({})", "broken".bright_red().bold(), reason),
+    };
+
+    let stale: Vec<String> = fs::read_dir(&home)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| *path != install_dir)
+                .map(|path| path.display().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "{} {}\n{} {}\n{} {}\n{} {}\n",
+            "Clang version:".custom_color((141, 141, 142)).bold(),
+            manifest.version,
+            "Archive SHA-256:".custom_color((141, 141, 142)).bold(),
+            manifest.sha256,
+            "Location:".custom_color((141, 141, 142)).bold(),
+            install_dir.display(),
+            "State:".custom_color((141, 141, 142)).bold(),
+            state
+        ),
     );
 
-    Err(())
+    if !stale.is_empty() {
+        logging::write(
+            logging::OutputIn::Stdout,
+            &format!(
+                "{}\n{}\n",
+                "Stale entries, removed on the next install:"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                stale
+                    .iter()
+                    .map(|path| format!("  {}", path))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ),
+        );
+    }
+
+    Ok(())
 }
 
-#[cfg(target_os = "windows")]
-pub fn dump_x86_64_clang_windows(
-    clang_raw_manifest: &str,
-    clang_raw_bytes: &[u8],
-    clang_manifest_path: PathBuf,
-    compressed_file_path: PathBuf,
-    output_path: PathBuf,
-) -> Result<PathBuf, ()> {
-    let raw_home_path: String = env::var("APPDATA").unwrap_or_else(|_| {
-        logging::log(
-            LoggingType::Panic,
-            "Unable to get %APPDATA% path at windows.",
+pub fn clean() -> Result<(), String> {
+    let home: PathBuf = self::get_toolchain_home()?;
+
+    if !home.exists() {
+        logging::write(
+            logging::OutputIn::Stdout,
+            &format!("Nothing to clean at '{}'.\n", home.display()),
         );
-        unreachable!()
-    });
 
-    let home_path: PathBuf = PathBuf::from(raw_home_path);
+        return Ok(());
+    }
 
-    if home_path.exists() {
-        let llvm_backend: PathBuf = home_path.join("thrushlang/backends/llvm/windows");
+    fs::remove_dir_all(&home)
+        .map_err(|error| format!("Cannot remove '{}': {}.", home.display(), error))?;
 
-        if !llvm_backend.exists() {
-            let _ = fs::create_dir_all(&llvm_backend);
-        }
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!("Removed '{}'.\n", home.display()),
+    );
 
-        let compressed_file: PathBuf = llvm_backend.join(compressed_file_path);
-        let output_path: PathBuf = llvm_backend.join(output_path);
-        let manifest_path: PathBuf = llvm_backend.join(clang_manifest_path);
+    Ok(())
+}
 
-        if !manifest_path.exists() {
-            let _ = write(manifest_path, clang_raw_manifest);
-        }
+fn get_state(
+    toolchain: &EmbeddedToolchain,
+    manifest: &ToolchainManifest,
+    install_dir: &Path,
+) -> ToolchainState {
+    if !install_dir.exists() {
+        return ToolchainState::Missing;
+    }
+
+    let installed: Option<ToolchainManifest> = fs::read_to_string(install_dir.join(MANIFEST_FILE))
+        .ok()
+        .and_then(|raw| ToolchainManifest::parse(&raw));
 
-        if output_path.exists() {
-            return Ok(output_path);
+    match installed {
+        None => ToolchainState::Broken(String::from("missing or unreadable manifest")),
+        Some(installed) if installed != *manifest => {
+            ToolchainState::Broken(String::from("manifest doesn't match this thrushc build"))
         }
+        Some(_) if !install_dir.join(toolchain.executable).is_file() => {
+            ToolchainState::Broken(String::from("missing executable"))
+        }
+        Some(_) => ToolchainState::Ready,
+    }
+}
 
-        let _ = write(&compressed_file, clang_raw_bytes);
+fn get_embedded_manifest(toolchain: &EmbeddedToolchain) -> Result<ToolchainManifest, String> {
+    ToolchainManifest::parse(toolchain.raw_manifest).ok_or_else(|| {
+        String::from(
+            "The embedded Clang manifest has no 'version' or 'sha256'. This thrushc build is corrupted.",
+        )
+    })
+}
+
+fn get_toolchain_home() -> Result<PathBuf, String> {
+    #[cfg(target_os = "linux")]
+    {
+        let home: PathBuf = env::var_os("HOME").map(PathBuf::from).ok_or_else(|| {
+            String::from("$HOME is not set. Link with '-custom-clang' or '-ld' instead.")
+        })?;
 
-        if File::open(&compressed_file).is_err() {
-            if self::decompress_zip(compressed_file, llvm_backend).is_ok() {
-                let canonical_windows_clang_path: PathBuf = output_path.join("bin/clang.exe");
+        Ok(home.join("thrushlang/backends/llvm/linux"))
+    }
 
-                if canonical_windows_clang_path.exists() {
-                    return Ok(canonical_windows_clang_path);
-                }
+    #[cfg(target_os = "windows")]
+    {
+        let home: PathBuf = env::var_os("APPDATA").map(PathBuf::from).ok_or_else(|| {
+            String::from("%APPDATA% is not set. Link with '-custom-clang' instead.")
+        })?;
+
+        Ok(home.join("thrushlang/backends/llvm/windows"))
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        Err(String::from(
+            "The embedded Clang toolchain is not available for the current operating system.",
+        ))
+    }
+}
+
+/// Unpacks the archive and the manifest into a private directory, so an interrupted
+/// install never leaves a half-written toolchain behind the final path.
+fn unpack(toolchain: &EmbeddedToolchain, temporal_dir: &Path) -> Result<(), String> {
+    if temporal_dir.exists() {
+        fs::remove_dir_all(temporal_dir)
+            .map_err(|error| format!("Cannot remove '{}': {}.", temporal_dir.display(), error))?;
+    }
+
+    fs::create_dir_all(temporal_dir)
+        .map_err(|error| format!("Cannot create '{}': {}.", temporal_dir.display(), error))?;
+
+    let archive: PathBuf = temporal_dir.join(toolchain.archive);
+
+    fs::write(&archive, toolchain.raw_bytes)
+        .map_err(|error| format!("Cannot write '{}': {}.", archive.display(), error))?;
+
+    self::extract(&archive, temporal_dir)?;
+
+    fs::remove_file(&archive)
+        .map_err(|error| format!("Cannot remove '{}': {}.", archive.display(), error))?;
+
+    let executable: PathBuf = temporal_dir.join(toolchain.executable);
+
+    if !executable.is_file() {
+        return Err(format!(
+            "The embedded Clang archive doesn't contain '{}'.",
+            toolchain.executable
+        ));
+    }
 
+    #[cfg(target_os = "linux")]
+    self::make_linux_executable(&executable)
+        .map_err(|error| format!("Cannot make Clang executable: {}.", error))?;
+
+    fs::write(temporal_dir.join(MANIFEST_FILE), toolchain.raw_manifest)
+        .map_err(|error| format!("Cannot write the toolchain manifest: {}.", error))?;
+
+    Ok(())
+}
+
+/// Removes older versions and leftovers of interrupted installs. Temporal directories
+/// younger than an hour may belong to another thrushc still unpacking, so they stay.
+fn remove_stale_entries(home: &Path, install_dir: &Path) {
+    let Ok(entries) = fs::read_dir(home) else {
+        return;
+    };
+
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path != install_dir)
+        .filter(|path| !self::is_recent_install(path))
+        .for_each(|path| {
+            let removed: Result<(), std::io::Error> = if path.is_dir() {
+                fs::remove_dir_all(&path)
+            } else {
+                fs::remove_file(&path)
+            };
+
+            if let Err(error) = removed {
                 logging::log(
-                    logging::LoggingType::Error,
-                    "Failed to get Clang executable at windows.",
+                    logging::LoggingType::Warning,
+                    &format!(
+                        "Cannot remove stale toolchain entry '{}': {}.",
+                        path.display(),
+                        error
+                    ),
                 );
-
-                return Err(());
             }
+        });
+}
 
-            logging::log(
-                logging::LoggingType::Error,
-                "Failed to decompress Clang at windows.",
-            );
+fn is_recent_install(path: &Path) -> bool {
+    let is_temporal: bool = path
+        .file_name()
+        .is_some_and(|name| name.to_string_lossy().contains(".tmp-"));
+
+    is_temporal
+        && fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|elapsed| elapsed < Duration::from_secs(60 * 60))
+}
 
-            return Err(());
-        }
+fn get_json_string(raw: &str, key: &str) -> Option<String> {
+    let quoted_key: String = format!("\"{}\"", key);
 
-        logging::log(
-            logging::LoggingType::Error,
-            "Failed to open Clang compressed at windows.",
-        );
+    let after_key: &str = &raw[raw.find(&quoted_key)? + quoted_key.len()..];
+    let after_colon: &str = after_key.trim_start().strip_prefix(':')?.trim_start();
+    let value: &str = after_colon.strip_prefix('"')?;
 
-        return Err(());
-    }
+    value.find('"').map(|end| value[..end].to_string())
+}
 
-    logging::log(
-        logging::LoggingType::Error,
-        "%APPDATA% path not exist at windows.",
-    );
+#[cfg(target_os = "linux")]
+fn extract(archive: &Path, output: &Path) -> Result<(), String> {
+    let file: File = File::open(archive)
+        .map_err(|error| format!("Cannot open '{}': {}.", archive.display(), error))?;
+
+    let buff_reader: BufReader<File> = BufReader::new(file);
+    let xz_decoded: XzDecoder<BufReader<File>> = XzDecoder::new(buff_reader);
+    let mut tar_file: Archive<XzDecoder<BufReader<File>>> = Archive::new(xz_decoded);
+
+    tar_file
+        .unpack(output)
+        .map_err(|error| format!("Failed to decompress Clang: {}.", error))
+}
+
+#[cfg(target_os = "windows")]
+fn extract(archive: &Path, output: &Path) -> Result<(), String> {
+    self::decompress_zip(archive, output)
+        .map_err(|error| format!("Failed to decompress Clang: {}.", error))
+}
 
-    Err(())
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn extract(_: &Path, _: &Path) -> Result<(), String> {
+    Err(String::from(
+        "The embedded Clang toolchain is not available for the current operating system.",
+    ))
 }
 
 #[cfg(target_os = "linux")]
@@ -185,15 +419,15 @@ fn make_linux_executable(path: &Path) -> Result<(), Box<dyn std::error::Error>>
 }
 
 #[cfg(target_os = "windows")]
-fn decompress_zip(zip_path: PathBuf, extract_to: PathBuf) -> zip::result::ZipResult<()> {
+fn decompress_zip(zip_path: &Path, extract_to: &Path) -> zip::result::ZipResult<()> {
     let file: File = File::open(zip_path)?;
     let mut archive: ZipArchive<File> = ZipArchive::new(file)?;
 
-    fs::create_dir_all(&extract_to)?;
+    fs::create_dir_all(extract_to)?;
 
     for i in 0..archive.len() {
         let mut file: ZipFile = archive.by_index(i)?;
-        let outpath: PathBuf = Path::new(&extract_to).join(file.name());
+        let outpath: PathBuf = extract_to.join(file.name());
 
         if file.name().ends_with('/') {
             fs::create_dir_all(&outpath)?;
@@ -6,19 +6,34 @@ use std::{
     time::{Duration, Instant},
 };
 
-use crate::core::{
-    compiler::linking::LinkingCompilersConfiguration,
-    console::logging::{self, LoggingType},
+use crate::{
+    backend::linking,
+    core::{
+        compiler::{
+            linking::{CrateType, LinkingCompilersConfiguration},
+            passes::LLVMSanitizer,
+        },
+        console::logging::{self, LoggingType},
+    },
 };
 
 pub struct GCC<'clang> {
     files: &'clang [PathBuf],
     config: &'clang LinkingCompilersConfiguration,
+    sanitizers: &'clang [LLVMSanitizer],
 }
 
 impl<'clang> GCC<'clang> {
-    pub fn new(files: &'clang [PathBuf], config: &'clang LinkingCompilersConfiguration) -> Self {
-        Self { files, config }
+    pub fn new(
+        files: &'clang [PathBuf],
+        config: &'clang LinkingCompilersConfiguration,
+        sanitizers: &'clang [LLVMSanitizer],
+    ) -> Self {
+        Self {
+            files,
+            config,
+            sanitizers,
+        }
     }
 
     pub fn link(&self) -> Result<Duration, ()> {
@@ -55,9 +70,30 @@ impl<'clang> GCC<'clang> {
 
         gcc_command.arg("-v");
 
+        match self.config.get_crate_type() {
+            CrateType::SharedLibrary => {
+                gcc_command.arg("-shared");
+            }
+            CrateType::Object => {
+                gcc_command.arg("-r");
+            }
+            _ => (),
+        }
+
+        gcc_command.args(
+            self.sanitizers
+                .iter()
+                .filter_map(|sanitizer| sanitizer.get_runtime_flag()),
+        );
+
         gcc_command.args(self.files.iter());
         gcc_command.args(self.config.get_args().iter());
 
+        if let Some(output) = self.config.get_output() {
+            gcc_command.arg("-o");
+            gcc_command.arg(output);
+        }
+
         if self.config.get_debug_gcc_commands() {
             logging::log(
                 LoggingType::Info,
@@ -69,6 +105,11 @@ impl<'clang> GCC<'clang> {
     }
 
     pub fn handle_command(&self, command: &mut Command) -> bool {
+        if self.config.get_link_dry_run() {
+            linking::print_command(command);
+            return true;
+        }
+
         if let Ok(gcc) = command.output() {
             if !gcc.status.success() {
                 if !gcc.stderr.is_empty() {
@@ -0,0 +1,406 @@
+#![allow(clippy::upper_case_acronyms)]
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use inkwell::targets::{RelocMode, TargetTriple};
+
+use crate::{
+    backend::linking,
+    core::{
+        compiler::{
+            linking::{CrateType, LinkingCompilersConfiguration},
+            passes::LLVMSanitizer,
+        },
+        console::logging::{self, LoggingType},
+    },
+};
+
+/// Links ELF objects by calling 'ld.lld' or 'ld' directly, without a C compiler
+/// driver. The crt objects, the libc directories and the dynamic linker are
+/// resolved from the target triple.
+pub struct LD<'ld> {
+    files: &'ld [PathBuf],
+    config: &'ld LinkingCompilersConfiguration,
+    target: &'ld TargetTriple,
+    reloc_mode: RelocMode,
+    sanitizers: &'ld [LLVMSanitizer],
+}
+
+#[derive(Debug)]
+struct LinuxTarget {
+    arch: &'static str,
+    multiarch: &'static str,
+    emulation: &'static str,
+    dynamic_linker: &'static str,
+    musl: bool,
+}
+
+impl<'ld> LD<'ld> {
+    pub fn new(
+        files: &'ld [PathBuf],
+        config: &'ld LinkingCompilersConfiguration,
+        target: &'ld TargetTriple,
+        reloc_mode: RelocMode,
+        sanitizers: &'ld [LLVMSanitizer],
+    ) -> Self {
+        Self {
+            files,
+            config,
+            target,
+            reloc_mode,
+            sanitizers,
+        }
+    }
+
+    pub fn link(&self) -> Result<Duration, ()> {
+        let start_time: Instant = Instant::now();
+
+        let Some(ld_path) = self.config.get_custom_ld() else {
+            return Err(());
+        };
+
+        if !self.sanitizers.is_empty() {
+            logging::log(
+                LoggingType::Error,
+                "Sanitizer runtimes can't be linked with '-ld'. Use '-clang' or '-gcc' instead.",
+            );
+
+            return Err(());
+        }
+
+        let mut ld_command: Command = match self.build_ld_command(ld_path) {
+            Ok(ld_command) => ld_command,
+            Err(error) => {
+                logging::log(LoggingType::Error, &error);
+                return Err(());
+            }
+        };
+
+        if self.config.get_link_dry_run() {
+            linking::print_command(&ld_command);
+
+            return Ok(start_time.elapsed());
+        }
+
+        if self.handle_command(&mut ld_command) {
+            return Ok(start_time.elapsed());
+        }
+
+        Err(())
+    }
+
+    pub fn build_ld_command(&self, ld_path: &Path) -> Result<Command, String> {
+        let mut ld_command: Command = Command::new(ld_path);
+
+        let crate_type: CrateType = self.config.get_crate_type();
+
+        if crate_type == CrateType::Object {
+            ld_command.arg("-r");
+            ld_command.args(self.files.iter());
+            ld_command.args(self.config.get_args().iter());
+
+            if let Some(output) = self.config.get_output() {
+                ld_command.arg("-o");
+                ld_command.arg(output);
+            }
+
+            return Ok(ld_command);
+        }
+
+        let target: LinuxTarget = self::get_linux_target(self.target)?;
+
+        let libc_dirs: Vec<PathBuf> = self::get_libc_dirs(&target);
+        let gcc_dir: Option<PathBuf> = self::get_gcc_dir(&target);
+
+        let pie: bool =
+            crate_type == CrateType::Executable && matches!(self.reloc_mode, RelocMode::PIC);
+
+        let find_crt = |name: &str| -> Result<PathBuf, String> {
+            libc_dirs
+                .iter()
+                .map(|dir| dir.join(name))
+                .find(|path| path.is_file())
+                .ok_or_else(|| {
+                    format!(
+                        "Cannot find '{}' for target '{}'. Searched in: {}.",
+                        name,
+                        self.target.as_str().to_string_lossy(),
+                        libc_dirs
+                            .iter()
+                            .map(|dir| format!("'{}'", dir.display()))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                })
+        };
+
+        let find_gcc_crt = |name: &str| -> Option<PathBuf> {
+            gcc_dir
+                .as_ref()
+                .map(|dir| dir.join(name))
+                .filter(|path| path.is_file())
+        };
+
+        let (crt_begin, crt_end): (&str, &str) = if pie || crate_type == CrateType::SharedLibrary {
+            ("crtbeginS.o", "crtendS.o")
+        } else {
+            ("crtbegin.o", "crtend.o")
+        };
+
+        ld_command.arg("-m").arg(target.emulation);
+        ld_command.arg("--eh-frame-hdr");
+
+        match crate_type {
+            CrateType::SharedLibrary => {
+                ld_command.arg("-shared");
+            }
+
+            _ if pie => {
+                ld_command.arg("-pie");
+                ld_command.arg("-dynamic-linker").arg(target.dynamic_linker);
+            }
+
+            _ => {
+                ld_command.arg("-no-pie");
+                ld_command.arg("-dynamic-linker").arg(target.dynamic_linker);
+            }
+        }
+
+        match crate_type {
+            CrateType::SharedLibrary => (),
+            _ if pie => {
+                ld_command.arg(find_crt("Scrt1.o")?);
+            }
+            _ => {
+                ld_command.arg(find_crt("crt1.o")?);
+            }
+        }
+
+        ld_command.arg(find_crt("crti.o")?);
+        ld_command.args(find_gcc_crt(crt_begin));
+
+        libc_dirs.iter().filter(|dir| dir.is_dir()).for_each(|dir| {
+            ld_command.arg(format!("-L{}", dir.display()));
+        });
+
+        if let Some(gcc_dir) = gcc_dir.as_ref() {
+            ld_command.arg(format!("-L{}", gcc_dir.display()));
+        }
+
+        ld_command.args(self.files.iter());
+        ld_command.args(self.config.get_args().iter());
+
+        if gcc_dir.is_some() && !target.musl {
+            ld_command.args(["-lgcc", "--as-needed", "-lgcc_s", "--no-as-needed"]);
+        }
+
+        ld_command.arg("-lc");
+
+        if gcc_dir.is_some() && !target.musl {
+            ld_command.args(["-lgcc", "--as-needed", "-lgcc_s", "--no-as-needed"]);
+        }
+
+        ld_command.args(find_gcc_crt(crt_end));
+        ld_command.arg(find_crt("crtn.o")?);
+
+        if let Some(output) = self.config.get_output() {
+            ld_command.arg("-o");
+            ld_command.arg(output);
+        }
+
+        Ok(ld_command)
+    }
+
+    pub fn handle_command(&self, command: &mut Command) -> bool {
+        if let Ok(ld) = command.output() {
+            if !ld.status.success() {
+                if !ld.stderr.is_empty() {
+                    logging::log(
+                        logging::LoggingType::Error,
+                        String::from_utf8_lossy(&ld.stderr).trim_end(),
+                    );
+                }
+
+                if !ld.stdout.is_empty() {
+                    logging::log(
+                        logging::LoggingType::Warning,
+                        String::from_utf8_lossy(&ld.stdout).trim_end(),
+                    );
+                }
+
+                return false;
+            }
+
+            return true;
+        }
+
+        false
+    }
+}
+
+fn get_linux_target(target: &TargetTriple) -> Result<LinuxTarget, String> {
+    let triple: String = target.as_str().to_string_lossy().into_owned();
+
+    let parts: Vec<&str> = triple.split('-').collect();
+
+    let arch: &str = parts.first().copied().unwrap_or_default();
+    let environment: &str = parts.last().copied().unwrap_or_default();
+
+    if !parts.contains(&"linux") {
+        return Err(format!(
+            "Linking with '-ld' only supports Linux targets, found '{}'.",
+            triple
+        ));
+    }
+
+    let musl: bool = environment.starts_with("musl");
+
+    let target: LinuxTarget = match (arch, musl) {
+        ("x86_64", false) => LinuxTarget {
+            arch: "x86_64",
+            multiarch: "x86_64-linux-gnu",
+            emulation: "elf_x86_64",
+            dynamic_linker: "/lib64/ld-linux-x86-64.so.2",
+            musl,
+        },
+        ("x86_64", true) => LinuxTarget {
+            arch: "x86_64",
+            multiarch: "x86_64-linux-musl",
+            emulation: "elf_x86_64",
+            dynamic_linker: "/lib/ld-musl-x86_64.so.1",
+            musl,
+        },
+        ("i386" | "i586" | "i686", false) => LinuxTarget {
+            arch: "i386",
+            multiarch: "i386-linux-gnu",
+            emulation: "elf_i386",
+            dynamic_linker: "/lib/ld-linux.so.2",
+            musl,
+        },
+        ("i386" | "i586" | "i686", true) => LinuxTarget {
+            arch: "i386",
+            multiarch: "i386-linux-musl",
+            emulation: "elf_i386",
+            dynamic_linker: "/lib/ld-musl-i386.so.1",
+            musl,
+        },
+        ("aarch64", false) => LinuxTarget {
+            arch: "aarch64",
+            multiarch: "aarch64-linux-gnu",
+            emulation: "aarch64linux",
+            dynamic_linker: "/lib/ld-linux-aarch64.so.1",
+            musl,
+        },
+        ("aarch64", true) => LinuxTarget {
+            arch: "aarch64",
+            multiarch: "aarch64-linux-musl",
+            emulation: "aarch64linux",
+            dynamic_linker: "/lib/ld-musl-aarch64.so.1",
+            musl,
+        },
+        ("riscv64" | "riscv64gc", false) => LinuxTarget {
+            arch: "riscv64",
+            multiarch: "riscv64-linux-gnu",
+            emulation: "elf64lriscv",
+            dynamic_linker: "/lib/ld-linux-riscv64-lp64d.so.1",
+            musl,
+        },
+        ("riscv64" | "riscv64gc", true) => LinuxTarget {
+            arch: "riscv64",
+            multiarch: "riscv64-linux-musl",
+            emulation: "elf64lriscv",
+            dynamic_linker: "/lib/ld-musl-riscv64.so.1",
+            musl,
+        },
+        ("arm" | "armv7", false) if environment == "gnueabihf" => LinuxTarget {
+            arch: "arm",
+            multiarch: "arm-linux-gnueabihf",
+            emulation: "armelf_linux_eabi",
+            dynamic_linker: "/lib/ld-linux-armhf.so.3",
+            musl,
+        },
+        ("arm" | "armv7", true) if environment == "musleabihf" => LinuxTarget {
+            arch: "arm",
+            multiarch: "arm-linux-musleabihf",
+            emulation: "armelf_linux_eabi",
+            dynamic_linker: "/lib/ld-musl-armhf.so.1",
+            musl,
+        },
+        _ => {
+            return Err(format!(
+                "Linking with '-ld' doesn't support the target '{}' yet. Use '-clang' or '-gcc' instead.",
+                triple
+            ));
+        }
+    };
+
+    Ok(target)
+}
+
+/// Directories where the crt objects and libc live, in search order. Debian keeps
+/// them in multiarch directories, Fedora and Arch in 'lib64' or 'lib', and musl
+/// toolchains under their own prefix.
+fn get_libc_dirs(target: &LinuxTarget) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = Vec::with_capacity(8);
+
+    if target.musl {
+        dirs.push(PathBuf::from("/usr/lib/musl/lib"));
+        dirs.push(PathBuf::from("/usr/local/musl/lib"));
+        dirs.push(PathBuf::from(format!("/usr/lib/{}", target.multiarch)));
+        dirs.push(PathBuf::from(format!("/usr/{}/lib", target.multiarch)));
+
+        return dirs;
+    }
+
+    dirs.push(PathBuf::from(format!("/usr/lib/{}", target.multiarch)));
+    dirs.push(PathBuf::from(format!("/lib/{}", target.multiarch)));
+    dirs.push(PathBuf::from(format!("/usr/{}/lib", target.multiarch)));
+
+    if matches!(target.arch, "x86_64" | "aarch64" | "riscv64") {
+        dirs.push(PathBuf::from("/usr/lib64"));
+        dirs.push(PathBuf::from("/lib64"));
+    }
+
+    dirs.push(PathBuf::from("/usr/lib"));
+    dirs.push(PathBuf::from("/lib"));
+
+    dirs
+}
+
+/// The newest GCC runtime directory for the target, which holds 'crtbegin.o',
+/// 'crtend.o' and 'libgcc'. Linking still works without it for plain C code.
+fn get_gcc_dir(target: &LinuxTarget) -> Option<PathBuf> {
+    let triples: [String; 3] = [
+        target.multiarch.to_string(),
+        format!("{}-pc-linux-gnu", target.arch),
+        format!("{}-redhat-linux", target.arch),
+    ];
+
+    triples
+        .iter()
+        .flat_map(|triple| {
+            [
+                PathBuf::from(format!("/usr/lib/gcc/{}", triple)),
+                PathBuf::from(format!("/usr/lib64/gcc/{}", triple)),
+            ]
+        })
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.filter_map(|entry| entry.ok().map(|entry| entry.path())))
+        .filter(|dir| dir.join("crtbegin.o").is_file())
+        .max_by_key(|dir| self::parse_version(dir))
+}
+
+fn parse_version(dir: &Path) -> Vec<u32> {
+    dir.file_name()
+        .map(|name| {
+            name.to_string_lossy()
+                .split('.')
+                .filter_map(|part| part.parse::<u32>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
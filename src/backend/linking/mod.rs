@@ -1 +1,26 @@
+use std::process::Command;
+
+use crate::core::console::logging;
+
+pub mod archiver;
 pub mod compilers;
+pub mod linkers;
+
+/// Prints a linker command instead of running it, for '--link-dry-run'.
+pub fn print_command(command: &Command) {
+    let command: String = std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|arg| {
+            let arg: String = arg.to_string_lossy().into_owned();
+
+            if arg.contains(char::is_whitespace) {
+                format!("'{}'", arg)
+            } else {
+                arg
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    logging::write(logging::OutputIn::Stdout, &format!("{}\n", command));
+}
@@ -10,7 +10,7 @@ use inkwell::{
 };
 
 use crate::{
-    backend::llvm::compiler::{context::LLVMCodeGenContext, typegen, utils},
+    backend::llvm::compiler::{context::LLVMCodeGenContext, repl::ReplSymbols, typegen, utils},
     core::console::logging::{self, LoggingType},
     frontend::{
         types::{
@@ -41,6 +41,46 @@ pub fn alloc<'ctx>(
     }
 }
 
+/// A local of a REPL snippet lives in a global of the JIT, so later snippets can
+/// still use it. The locals of earlier snippets are only declared.
+pub fn repl_binding<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    ascii_name: &str,
+    kind: &Type,
+) -> PointerValue<'ctx> {
+    let llvm_module: &Module = context.get_llvm_module();
+    let target_data: &TargetData = context.get_target_data();
+
+    let llvm_type: BasicTypeEnum = typegen::generate_subtype(context.get_llvm_context(), kind);
+
+    let Some(repl) = context.get_repl_symbols() else {
+        self::codegen_abort("A REPL binding was allocated outside a REPL session.");
+        unreachable!()
+    };
+
+    // The replayed locals are the ones in the body of the entry itself.
+    let replayed: Option<&str> = match context.get_table().get_scope() {
+        1 => repl.get_binding(ascii_name),
+        _ => None,
+    };
+
+    let (name, is_declaration): (String, bool) = match replayed {
+        Some(symbol) => (symbol.to_string(), true),
+        None => (repl.get_binding_symbol(ascii_name), false),
+    };
+
+    let global: GlobalValue =
+        llvm_module.add_global(llvm_type, Some(AddressSpace::default()), &name);
+
+    global.set_alignment(target_data.get_preferred_alignment_of_global(&global));
+
+    if !is_declaration {
+        global.set_initializer(&llvm_type.const_zero());
+    }
+
+    global.as_pointer_value()
+}
+
 fn try_alloc_heap<'ctx>(
     context: &LLVMCodeGenContext<'_, 'ctx>,
     llvm_type: BasicTypeEnum<'ctx>,
@@ -124,7 +164,8 @@ pub fn global_constant<'ctx>(
 
     global.set_alignment(target_data.get_preferred_alignment_of_global(&global));
 
-    if !attributes.has_public_attribute() {
+    // Every module of a REPL session has its own copy of the constants.
+    if !attributes.has_public_attribute() || context.get_repl_symbols().is_some() {
         global.set_linkage(Linkage::LinkerPrivate);
     }
 
@@ -195,11 +236,16 @@ pub fn global_static<'ctx>(
         global.set_unnamed_addr(true);
     }
 
-    if !attributes.has_public_attribute() {
+    let repl: Option<&ReplSymbols> = context.get_repl_symbols();
+
+    if !attributes.has_public_attribute() && repl.is_none() {
         global.set_linkage(Linkage::LinkerPrivate);
     }
 
-    global.set_initializer(&llvm_value);
+    // An earlier snippet of the REPL session already holds its value.
+    if !repl.is_some_and(|repl| repl.is_defined(ascii_name)) {
+        global.set_initializer(&llvm_value);
+    }
 
     global.as_pointer_value()
 }
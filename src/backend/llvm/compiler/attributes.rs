@@ -36,6 +36,12 @@ pub enum LLVMAttribute<'ctx> {
     AsmSyntax(&'ctx str, Span),
     AsmAlignStack(Span),
     AsmSideEffects(Span),
+
+    // Linter Attributes
+    Allow(&'ctx str, Span),
+
+    // Testing Attributes
+    Test(Span),
 }
 
 impl LLVMAttribute<'_> {
@@ -104,6 +110,16 @@ impl LLVMAttribute<'_> {
         matches!(self, LLVMAttribute::AsmAlignStack(..))
     }
 
+    #[inline]
+    pub fn is_allow_attribute(&self) -> bool {
+        matches!(self, LLVMAttribute::Allow(..))
+    }
+
+    #[inline]
+    pub fn is_test_attribute(&self) -> bool {
+        matches!(self, LLVMAttribute::Test(..))
+    }
+
     #[inline]
     pub fn get_span(&self) -> Span {
         match self {
@@ -126,6 +142,8 @@ impl LLVMAttribute<'_> {
             LLVMAttribute::AsmAlignStack(span) => *span,
             LLVMAttribute::Stack(span) => *span,
             LLVMAttribute::Heap(span) => *span,
+            LLVMAttribute::Allow(_, span) => *span,
+            LLVMAttribute::Test(span) => *span,
         }
     }
 }
@@ -9,14 +9,24 @@ use {
         },
     },
     inkwell::{
-        AddressSpace,
+        AddressSpace, IntPredicate,
+        basic_block::BasicBlock,
         builder::Builder,
         context::Context,
-        values::{BasicValueEnum, IntValue},
+        intrinsics::Intrinsic,
+        module::Module,
+        values::{BasicValueEnum, IntValue, StructValue},
     },
     std::fmt::Display,
 };
 
+// Check kinds passed to 'llvm.ubsantrap', matching the ones Clang uses.
+const UB_ADD_OVERFLOW: u64 = 0;
+const UB_DIVREM_OVERFLOW: u64 = 3;
+const UB_MUL_OVERFLOW: u64 = 12;
+const UB_SHIFT_OUT_OF_BOUNDS: u64 = 20;
+const UB_SUB_OVERFLOW: u64 = 21;
+
 fn int_operation<'ctx>(
     context: &LLVMCodeGenContext<'_, 'ctx>,
     left: BasicValueEnum<'ctx>,
@@ -38,6 +48,10 @@ fn int_operation<'ctx>(
 
         let (left, right) = cast::integer_together(context, left, right);
 
+        if context.get_ub_checks() {
+            self::compile_ub_checks(context, left, right, signatures.0 || signatures.1, operator);
+        }
+
         return match operator {
             TokenType::Plus => llvm_builder
                 .build_int_nsw_add(left, right, "")
@@ -165,6 +179,179 @@ pub fn compile<'ctx>(
     self::compile_null_ptr(context)
 }
 
+/// Traps before the operation when it would overflow a signed integer, divide by
+/// zero or shift by at least the bit width of the operands.
+fn compile_ub_checks<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    left: IntValue<'ctx>,
+    right: IntValue<'ctx>,
+    signed: bool,
+    operator: &TokenType,
+) {
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let cintgen_abort = |_| {
+        self::codegen_abort("Cannot generate undefined behavior check.");
+        unreachable!()
+    };
+
+    let bit_width: u32 = left.get_type().get_bit_width();
+
+    match operator {
+        TokenType::Plus if signed => {
+            let overflow: IntValue =
+                self::compile_overflow_flag(context, "llvm.sadd.with.overflow", left, right);
+
+            self::compile_ub_trap(context, overflow, UB_ADD_OVERFLOW);
+        }
+
+        TokenType::Minus if signed => {
+            let overflow: IntValue =
+                self::compile_overflow_flag(context, "llvm.ssub.with.overflow", left, right);
+
+            self::compile_ub_trap(context, overflow, UB_SUB_OVERFLOW);
+        }
+
+        TokenType::Star if signed => {
+            let overflow: IntValue =
+                self::compile_overflow_flag(context, "llvm.smul.with.overflow", left, right);
+
+            self::compile_ub_trap(context, overflow, UB_MUL_OVERFLOW);
+        }
+
+        TokenType::Slash => {
+            let mut failed: IntValue = llvm_builder
+                .build_int_compare(IntPredicate::EQ, right, right.get_type().const_zero(), "")
+                .unwrap_or_else(cintgen_abort);
+
+            if signed && bit_width <= 64 {
+                let min: IntValue = left.get_type().const_int(1 << (bit_width - 1), false);
+
+                let is_min: IntValue = llvm_builder
+                    .build_int_compare(IntPredicate::EQ, left, min, "")
+                    .unwrap_or_else(cintgen_abort);
+
+                let is_minus_one: IntValue = llvm_builder
+                    .build_int_compare(
+                        IntPredicate::EQ,
+                        right,
+                        right.get_type().const_all_ones(),
+                        "",
+                    )
+                    .unwrap_or_else(cintgen_abort);
+
+                let overflow: IntValue = llvm_builder
+                    .build_and(is_min, is_minus_one, "")
+                    .unwrap_or_else(cintgen_abort);
+
+                failed = llvm_builder
+                    .build_or(failed, overflow, "")
+                    .unwrap_or_else(cintgen_abort);
+            }
+
+            self::compile_ub_trap(context, failed, UB_DIVREM_OVERFLOW);
+        }
+
+        TokenType::LShift | TokenType::RShift => {
+            let failed: IntValue = llvm_builder
+                .build_int_compare(
+                    IntPredicate::UGE,
+                    right,
+                    right.get_type().const_int(bit_width as u64, false),
+                    "",
+                )
+                .unwrap_or_else(cintgen_abort);
+
+            self::compile_ub_trap(context, failed, UB_SHIFT_OUT_OF_BOUNDS);
+        }
+
+        _ => (),
+    }
+}
+
+fn compile_overflow_flag<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    intrinsic_name: &str,
+    left: IntValue<'ctx>,
+    right: IntValue<'ctx>,
+) -> IntValue<'ctx> {
+    let llvm_module: &Module = context.get_llvm_module();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let Some(intrinsic) = Intrinsic::find(intrinsic_name)
+        .and_then(|intrinsic| intrinsic.get_declaration(llvm_module, &[left.get_type().into()]))
+    else {
+        self::codegen_abort(format!("Cannot find the '{}' intrinsic.", intrinsic_name));
+        unreachable!()
+    };
+
+    let result: StructValue = llvm_builder
+        .build_call(intrinsic, &[left.into(), right.into()], "")
+        .ok()
+        .and_then(|call| call.try_as_basic_value().left())
+        .map(|value| value.into_struct_value())
+        .unwrap_or_else(|| {
+            self::codegen_abort("Cannot compute integer overflow flag.");
+            unreachable!()
+        });
+
+    llvm_builder
+        .build_extract_value(result, 1, "")
+        .map(|flag| flag.into_int_value())
+        .unwrap_or_else(|_| {
+            self::codegen_abort("Cannot compute integer overflow flag.");
+            unreachable!()
+        })
+}
+
+fn compile_ub_trap<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    failed: IntValue<'ctx>,
+    check: u64,
+) {
+    let llvm_module: &Module = context.get_llvm_module();
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let Some(function) = llvm_builder
+        .get_insert_block()
+        .and_then(|block| block.get_parent())
+    else {
+        self::codegen_abort("Cannot place an undefined behavior check outside a function.");
+        return;
+    };
+
+    let Some(trap) = Intrinsic::find("llvm.ubsantrap")
+        .and_then(|intrinsic| intrinsic.get_declaration(llvm_module, &[]))
+    else {
+        self::codegen_abort("Cannot find the 'llvm.ubsantrap' intrinsic.");
+        return;
+    };
+
+    let trap_block: BasicBlock = llvm_context.append_basic_block(function, "ub.trap");
+    let continue_block: BasicBlock = llvm_context.append_basic_block(function, "ub.continue");
+
+    if llvm_builder
+        .build_conditional_branch(failed, trap_block, continue_block)
+        .is_err()
+    {
+        self::codegen_abort("Cannot generate undefined behavior check.");
+        return;
+    }
+
+    llvm_builder.position_at_end(trap_block);
+
+    let check: IntValue = llvm_context.i8_type().const_int(check, false);
+
+    if llvm_builder.build_call(trap, &[check.into()], "").is_err()
+        || llvm_builder.build_unreachable().is_err()
+    {
+        self::codegen_abort("Cannot generate undefined behavior check.");
+    }
+
+    llvm_builder.position_at_end(continue_block);
+}
+
 fn codegen_abort<T: Display>(message: T) {
     logging::log(LoggingType::BackendBug, &format!("{}", message));
 }
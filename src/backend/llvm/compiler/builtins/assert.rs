@@ -0,0 +1,136 @@
+use inkwell::{
+    FloatPredicate, IntPredicate,
+    basic_block::BasicBlock,
+    builder::Builder,
+    context::Context,
+    types::IntType,
+    values::{BasicValueEnum, IntValue},
+};
+
+use crate::{
+    backend::llvm::compiler::{context::LLVMCodeGenContext, runtime, valuegen},
+    core::console::logging::{self, LoggingType},
+    frontend::{lexer::span::Span, types::ast::Ast, typesystem::types::Type},
+};
+
+pub fn compile_assert<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    condition: &'ctx Ast,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let condition: IntValue =
+        valuegen::compile(context, condition, Some(&Type::Bool)).into_int_value();
+
+    self::compile_check(context, condition, "assertion failed", span);
+    self::compile_unit(context)
+}
+
+pub fn compile_assert_eq<'ctx>(
+    context: &mut LLVMCodeGenContext<'_, 'ctx>,
+    left: &'ctx Ast,
+    right: &'ctx Ast,
+    span: Span,
+) -> BasicValueEnum<'ctx> {
+    let left_type: &Type = left.get_type_unwrapped();
+
+    let left: BasicValueEnum = valuegen::compile(context, left, None);
+    let right: BasicValueEnum = valuegen::compile(context, right, Some(left_type));
+
+    let llvm_builder: &Builder = context.get_llvm_builder();
+    let llvm_context: &Context = context.get_llvm_context();
+
+    let equal: Option<IntValue> = match (left, right) {
+        (BasicValueEnum::IntValue(left), BasicValueEnum::IntValue(right)) => llvm_builder
+            .build_int_compare(IntPredicate::EQ, left, right, "")
+            .ok(),
+
+        (BasicValueEnum::FloatValue(left), BasicValueEnum::FloatValue(right)) => llvm_builder
+            .build_float_compare(FloatPredicate::OEQ, left, right, "")
+            .ok(),
+
+        (BasicValueEnum::PointerValue(left), BasicValueEnum::PointerValue(right)) => {
+            let intptr_type: IntType = llvm_context.i64_type();
+
+            match (
+                llvm_builder.build_ptr_to_int(left, intptr_type, ""),
+                llvm_builder.build_ptr_to_int(right, intptr_type, ""),
+            ) {
+                (Ok(left), Ok(right)) => llvm_builder
+                    .build_int_compare(IntPredicate::EQ, left, right, "")
+                    .ok(),
+                _ => None,
+            }
+        }
+
+        _ => None,
+    };
+
+    let Some(equal) = equal else {
+        self::codegen_abort("Cannot compare the values of 'assert_eq'.");
+        return self::compile_unit(context);
+    };
+
+    self::compile_check(context, equal, "assertion 'left == right' failed", span);
+    self::compile_unit(context)
+}
+
+/// Branches to a cold block that reports the location, then aborts, when the
+/// condition is false.
+fn compile_check<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    condition: IntValue<'ctx>,
+    reason: &str,
+    span: Span,
+) {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let Some(function) = llvm_builder
+        .get_insert_block()
+        .and_then(|block| block.get_parent())
+    else {
+        self::codegen_abort("Cannot place an assertion outside a function.");
+        return;
+    };
+
+    let failed_block: BasicBlock = llvm_context.append_basic_block(function, "assert.failed");
+    let continue_block: BasicBlock = llvm_context.append_basic_block(function, "assert.continue");
+
+    if llvm_builder
+        .build_conditional_branch(condition, continue_block, failed_block)
+        .is_err()
+    {
+        self::codegen_abort("Cannot generate assertion.");
+        return;
+    }
+
+    llvm_builder.position_at_end(failed_block);
+
+    self::compile_failure_report(context, reason, span);
+
+    llvm_builder.position_at_end(continue_block);
+}
+
+fn compile_failure_report<'ctx>(context: &LLVMCodeGenContext<'_, 'ctx>, reason: &str, span: Span) {
+    let file: String = context
+        .get_diagnostician()
+        .get_source_map()
+        .get(span.get_file())
+        .map(|source| source.get_path().display().to_string())
+        .unwrap_or_else(|| String::from("<unknown>"));
+
+    let message: String = format!("thrush: {} at {}:{}.\n", reason, file, span.get_line());
+
+    if runtime::compile_abort(context, &message.replace('%', "%%"), &[]).is_err() {
+        self::codegen_abort("Cannot generate assertion.");
+    }
+}
+
+/// The assertions don't produce a value; statements discard this one.
+fn compile_unit<'ctx>(context: &LLVMCodeGenContext<'_, 'ctx>) -> BasicValueEnum<'ctx> {
+    context.get_llvm_context().bool_type().const_zero().into()
+}
+
+fn codegen_abort(message: &str) {
+    logging::log(LoggingType::BackendBug, message);
+}
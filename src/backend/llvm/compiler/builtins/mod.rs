@@ -4,9 +4,10 @@ use inkwell::values::BasicValueEnum;
 
 use crate::{
     backend::llvm::compiler::context::LLVMCodeGenContext,
-    frontend::{types::ast::Ast, typesystem::types::Type},
+    frontend::{lexer::span::Span, types::ast::Ast, typesystem::types::Type},
 };
 
+pub mod assert;
 pub mod mem;
 pub mod sizeof;
 
@@ -34,6 +35,17 @@ pub enum Builtin<'ctx> {
     AlignOf {
         align_of: Type,
     },
+
+    // Testing Builtins
+    Assert {
+        condition: Rc<Ast<'ctx>>,
+        span: Span,
+    },
+    AssertEq {
+        left: Rc<Ast<'ctx>>,
+        right: Rc<Ast<'ctx>>,
+        span: Span,
+    },
 }
 
 pub fn compile<'ctx>(
@@ -63,5 +75,11 @@ pub fn compile<'ctx>(
         } => mem::memset::compile(context, destination, new_size, size),
 
         Builtin::Halloc { alloc } => mem::halloc::compile(context, alloc),
+
+        Builtin::Assert { condition, span } => assert::compile_assert(context, condition, *span),
+
+        Builtin::AssertEq { left, right, span } => {
+            assert::compile_assert_eq(context, left, right, *span)
+        }
     }
 }
@@ -1,9 +1,9 @@
 #![allow(clippy::collapsible_if)]
 
-use std::fmt::Display;
+use std::{fmt::Display, path::PathBuf};
 
 use crate::backend::llvm::compiler::{
-    binaryop, builtins, declarations, expressions, ptrgen, statements,
+    binaryop, builtins, coverage, declarations, expressions, harness, ptrgen, statements,
 };
 use crate::backend::types::{repr::LLVMFunction, traits::AssemblerFunctionExtensions};
 use crate::core::console::logging::{self, LoggingType};
@@ -53,6 +53,9 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
         self.ast.iter().for_each(|ast| {
             self.codegen(ast);
         });
+
+        self.context.finalize_debug_info();
+        self.context.finalize_coverage();
     }
 
     fn codegen(&mut self, decl: &'ctx Ast) {
@@ -69,16 +72,34 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
         ########################################################################*/
 
         match decl {
-            Ast::EntryPoint { body, .. } => {
+            // The test harness provides the entry point of a test build.
+            Ast::EntryPoint { .. } if self.context.get_test_mode() => (),
+
+            Ast::EntryPoint { body, span } => {
                 let entrypoint: FunctionValue = self.entrypoint();
 
                 self.context.set_current_fn(entrypoint);
 
+                self.context
+                    .begin_debug_function(entrypoint, "main", &Type::S32, &[], true, *span);
+
                 self.codegen_block(body);
+
+                self.context.end_debug_function();
             }
 
-            Ast::Function { body, .. } => {
-                if body.is_null() {
+            Ast::Function {
+                ascii_name, body, ..
+            } => {
+                if body.is_null() || (decl.is_test_function() && !self.context.get_test_mode()) {
+                    return;
+                }
+
+                if self
+                    .context
+                    .get_repl_symbols()
+                    .is_some_and(|repl| repl.is_defined(ascii_name))
+                {
                     return;
                 }
 
@@ -113,17 +134,27 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
         ########################################################################*/
 
         match stmt {
-            Ast::Block { stmts, .. } => {
+            Ast::Block { stmts, span } => {
                 self.context.begin_scope();
+                self.context.begin_debug_scope(*span);
+                self.context.instrument_coverage(Some(*span), stmts);
 
-                stmts.iter().for_each(|stmt| {
+                stmts.iter().enumerate().for_each(|(idx, stmt)| {
                     self.codegen_block(stmt);
+
+                    if coverage::is_branching(stmt) {
+                        self.context.instrument_coverage(None, &stmts[idx + 1..]);
+                    }
                 });
 
+                self.context.end_debug_scope();
                 self.context.end_scope();
             }
 
-            stmt => self.stmt(stmt),
+            stmt => {
+                self.context.set_debug_location(stmt.get_span());
+                self.stmt(stmt);
+            }
         }
 
         /* ######################################################################
@@ -211,19 +242,20 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                 value,
                 attributes,
                 metadata,
-                ..
+                span,
             } => {
                 let metadata: &LocalMetadata = metadata;
 
                 if metadata.is_undefined() {
                     self.context.new_local(name, ascii_name, kind, attributes);
-                    return;
+                } else {
+                    statements::local::compile(
+                        self.context,
+                        (name, ascii_name, kind, value, attributes),
+                    );
                 }
 
-                statements::local::compile(
-                    self.context,
-                    (name, ascii_name, kind, value, attributes),
-                );
+                self.context.declare_debug_local(name, kind, *span);
             }
 
             Ast::Const {
@@ -422,7 +454,9 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
         let name: &str = function.0;
         let function_type: &Type = function.2;
         let parameters: &[Ast<'ctx>] = function.3;
+        let parameters_types: &[Type] = function.4;
         let body: &Ast = function.5;
+        let attributes: &ThrushAttributes = function.6;
 
         let get_llvm_function: LLVMFunction = self.context.get_table().get_function(name);
 
@@ -436,16 +470,31 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
 
         self.context.set_current_fn(llvm_function);
 
+        self.context.begin_debug_function(
+            llvm_function,
+            name,
+            function_type,
+            parameters_types,
+            attributes.has_public_attribute(),
+            raw_function.get_span(),
+        );
+
         parameters.iter().for_each(|parameter| {
             if let Ast::FunctionParameter {
                 name,
                 ascii_name,
                 kind,
                 position,
+                span,
                 ..
             } = parameter
             {
                 self.compile_function_parameter(llvm_function, (name, ascii_name, kind, *position));
+
+                if let Some(value) = llvm_function.get_nth_param(*position) {
+                    self.context
+                        .declare_debug_parameter(name, kind, value, *position, *span);
+                }
             }
         });
 
@@ -459,6 +508,7 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
             }
         }
 
+        self.context.end_debug_function();
         self.context.unset_current_function();
     }
 
@@ -476,7 +526,7 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
                 self.declare_asm_function(ast);
             }
 
-            if ast.is_function() {
+            if ast.is_function() && (self.context.get_test_mode() || !ast.is_test_function()) {
                 self.declare_function(ast);
             }
 
@@ -624,7 +674,8 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
         let attributes: &ThrushAttributes = function.6;
 
         let ignore_args: bool = attributes.has_ignore_attribute();
-        let is_public: bool = attributes.has_public_attribute();
+        let is_test: bool = attributes.has_test_attribute();
+        let is_public: bool = attributes.has_public_attribute() || is_test;
 
         let mut extern_name: Option<&str> = None;
         let mut convention: u32 = CallConvention::Standard as u32;
@@ -640,8 +691,22 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
             _ => (),
         });
 
+        let test_name: Option<String> = is_test.then(|| {
+            let file: PathBuf = self
+                .context
+                .get_diagnostician()
+                .get_source_map()
+                .get(stmt.get_span().get_file())
+                .map(|source| source.get_path().to_path_buf())
+                .unwrap_or_default();
+
+            harness::get_test_symbol(&file, ascii_name)
+        });
+
         let external_name: &str = if let Some(ffi_name) = extern_name {
             ffi_name
+        } else if let Some(test_name) = test_name.as_deref() {
+            test_name
         } else {
             ascii_name
         };
@@ -664,7 +729,8 @@ impl<'a, 'ctx> LLVMCodegen<'a, 'ctx> {
 
         attribute_builder.add_function_attributes(&mut convention);
 
-        if !is_public && extern_name.is_none() {
+        // The later snippets of a REPL session call it from other modules.
+        if !is_public && extern_name.is_none() && self.context.get_repl_symbols().is_none() {
             llvm_function.set_linkage(Linkage::LinkerPrivate);
         }
 
@@ -10,14 +10,19 @@ use {
                 alloc::{self},
                 anchors::PointerAnchor,
                 control::LoopContext,
+                coverage::LLVMCoverageContext,
+                debug::LLVMDebugContext,
+                repl::ReplSymbols,
                 symbols::SymbolsTable,
             },
             types::repr::LLVMFunction,
         },
         core::diagnostic::diagnostician::Diagnostician,
         frontend::{
+            lexer::span::Span,
             types::{
-                ast::metadata::staticvar::StaticMetadata, parser::stmts::types::ThrushAttributes,
+                ast::{Ast, metadata::staticvar::StaticMetadata},
+                parser::stmts::types::ThrushAttributes,
             },
             typesystem::types::Type,
         },
@@ -46,6 +51,13 @@ pub struct LLVMCodeGenContext<'a, 'ctx> {
     ptr_anchor: Option<PointerAnchor<'ctx>>,
     function: Option<FunctionValue<'ctx>>,
 
+    debug: Option<LLVMDebugContext<'ctx>>,
+    coverage: Option<LLVMCoverageContext<'ctx>>,
+    ub_checks: bool,
+    bounds_checks: bool,
+    test_mode: bool,
+    repl: Option<ReplSymbols>,
+
     diagnostician: Diagnostician,
 }
 
@@ -55,6 +67,8 @@ impl<'a, 'ctx> LLVMCodeGenContext<'a, 'ctx> {
         context: &'ctx Context,
         builder: &'ctx Builder<'ctx>,
         target_data: TargetData,
+        debug: Option<LLVMDebugContext<'ctx>>,
+        coverage: Option<LLVMCoverageContext<'ctx>>,
         diagnostician: Diagnostician,
     ) -> Self {
         Self {
@@ -69,6 +83,13 @@ impl<'a, 'ctx> LLVMCodeGenContext<'a, 'ctx> {
             ptr_anchor: None,
             function: None,
 
+            debug,
+            coverage,
+            ub_checks: false,
+            bounds_checks: false,
+            test_mode: false,
+            repl: None,
+
             diagnostician,
         }
     }
@@ -82,7 +103,11 @@ impl<'ctx> LLVMCodeGenContext<'_, 'ctx> {
         kind: &'ctx Type,
         attributes: &'ctx ThrushAttributes<'ctx>,
     ) {
-        let ptr: PointerValue = alloc::alloc(self, ascii_name, kind, attributes);
+        let ptr: PointerValue = if self.is_repl_binding() {
+            alloc::repl_binding(self, ascii_name, kind)
+        } else {
+            alloc::alloc(self, ascii_name, kind, attributes)
+        };
 
         let local: SymbolAllocated =
             SymbolAllocated::new(SymbolToAllocate::Local, kind, ptr.into());
@@ -258,6 +283,129 @@ impl<'ctx> LLVMCodeGenContext<'_, 'ctx> {
     }
 }
 
+impl<'ctx> LLVMCodeGenContext<'_, 'ctx> {
+    pub fn begin_debug_function(
+        &mut self,
+        llvm_function: FunctionValue<'ctx>,
+        name: &str,
+        return_type: &Type,
+        parameters_types: &[Type],
+        is_public: bool,
+        span: Span,
+    ) {
+        if let Some(debug) = self.debug.as_mut() {
+            debug.begin_function(
+                self.context,
+                self.builder,
+                &self.target_data,
+                llvm_function,
+                name,
+                return_type,
+                parameters_types,
+                is_public,
+                span,
+            );
+        }
+    }
+
+    pub fn end_debug_function(&mut self) {
+        if let Some(debug) = self.debug.as_mut() {
+            debug.end_function(self.builder);
+        }
+    }
+
+    pub fn begin_debug_scope(&mut self, span: Span) {
+        if let Some(debug) = self.debug.as_mut() {
+            debug.begin_scope(span);
+        }
+    }
+
+    pub fn end_debug_scope(&mut self) {
+        if let Some(debug) = self.debug.as_mut() {
+            debug.end_scope();
+        }
+    }
+
+    pub fn set_debug_location(&self, span: Span) {
+        if let Some(debug) = self.debug.as_ref() {
+            debug.set_location(self.context, self.builder, span);
+        }
+    }
+
+    pub fn declare_debug_local(&self, name: &str, kind: &Type, span: Span) {
+        if let Some(debug) = self.debug.as_ref() {
+            let ptr: PointerValue = self.table.get_symbol(name).get_ptr();
+
+            debug.declare_variable(
+                self.context,
+                self.builder,
+                &self.target_data,
+                ptr,
+                name,
+                kind,
+                None,
+                span,
+            );
+        }
+    }
+
+    /// Parameters live in registers, so they are spilled into a stack slot the
+    /// debugger can read. The slot is only created when debug info is enabled.
+    pub fn declare_debug_parameter(
+        &self,
+        name: &str,
+        kind: &Type,
+        value: BasicValueEnum<'ctx>,
+        position: u32,
+        span: Span,
+    ) {
+        if let Some(debug) = self.debug.as_ref() {
+            let Ok(ptr) = self
+                .builder
+                .build_alloca(typegen::generate_type(self.context, kind), "")
+            else {
+                self::codegen_abort("Unable to allocate the debug slot of a parameter.");
+                return;
+            };
+
+            if self.builder.build_store(ptr, value).is_err() {
+                self::codegen_abort("Unable to store the debug slot of a parameter.");
+            }
+
+            debug.declare_variable(
+                self.context,
+                self.builder,
+                &self.target_data,
+                ptr,
+                name,
+                kind,
+                Some(position + 1),
+                span,
+            );
+        }
+    }
+
+    pub fn finalize_debug_info(&self) {
+        if let Some(debug) = self.debug.as_ref() {
+            debug.finalize();
+        }
+    }
+}
+
+impl<'ctx> LLVMCodeGenContext<'_, 'ctx> {
+    pub fn instrument_coverage(&mut self, header: Option<Span>, stmts: &[Ast]) {
+        if let Some(coverage) = self.coverage.as_mut() {
+            coverage.instrument(self.module, self.context, self.builder, header, stmts);
+        }
+    }
+
+    pub fn finalize_coverage(&self) {
+        if let Some(coverage) = self.coverage.as_ref() {
+            coverage.finalize(self.module, self.context, self.builder);
+        }
+    }
+}
+
 impl<'ctx> LLVMCodeGenContext<'_, 'ctx> {
     pub fn set_pointer_anchor(&mut self, anchor: PointerAnchor<'ctx>) {
         self.ptr_anchor = Some(anchor);
@@ -313,6 +461,55 @@ impl<'a, 'ctx> LLVMCodeGenContext<'a, 'ctx> {
     pub fn get_diagnostician(&self) -> &Diagnostician {
         &self.diagnostician
     }
+
+    #[inline]
+    pub fn get_ub_checks(&self) -> bool {
+        self.ub_checks
+    }
+
+    #[inline]
+    pub fn get_bounds_checks(&self) -> bool {
+        self.bounds_checks
+    }
+
+    #[inline]
+    pub fn get_test_mode(&self) -> bool {
+        self.test_mode
+    }
+
+    #[inline]
+    pub fn get_repl_symbols(&self) -> Option<&ReplSymbols> {
+        self.repl.as_ref()
+    }
+
+    /// The locals of a REPL snippet outlive it: the earlier ones are replayed in the
+    /// body of its entry, the new ones are declared in the block nested in it.
+    fn is_repl_binding(&self) -> bool {
+        let in_entry: bool = match (self.repl.as_ref(), self.function) {
+            (Some(repl), Some(function)) => repl.is_entry(&function.get_name().to_string_lossy()),
+            _ => false,
+        };
+
+        in_entry && matches!(self.table.get_scope(), 1 | 2)
+    }
+}
+
+impl LLVMCodeGenContext<'_, '_> {
+    pub fn set_ub_checks(&mut self, ub_checks: bool) {
+        self.ub_checks = ub_checks;
+    }
+
+    pub fn set_bounds_checks(&mut self, bounds_checks: bool) {
+        self.bounds_checks = bounds_checks;
+    }
+
+    pub fn set_test_mode(&mut self, test_mode: bool) {
+        self.test_mode = test_mode;
+    }
+
+    pub fn set_repl_symbols(&mut self, repl: ReplSymbols) {
+        self.repl = Some(repl);
+    }
 }
 
 fn codegen_abort<T: Display>(message: T) {
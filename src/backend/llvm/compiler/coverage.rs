@@ -0,0 +1,404 @@
+use std::sync::Arc;
+
+use inkwell::{
+    AddressSpace, AtomicOrdering, AtomicRMWBinOp,
+    basic_block::BasicBlock,
+    builder::{Builder, BuilderError},
+    context::Context,
+    module::{Linkage, Module},
+    types::{FunctionType, IntType, PointerType, StructType},
+    values::{FunctionValue, GlobalValue, IntValue, PointerValue, StructValue},
+};
+
+use crate::{
+    backend::llvm::compiler::runtime,
+    core::{
+        compiler::{
+            constants::{COVERAGE_DEFAULT_FILE, COVERAGE_FILE_ENV},
+            sourcemap::SourceFile,
+        },
+        console::logging::{self, LoggingType},
+    },
+    frontend::{lexer::span::Span, types::ast::Ast},
+};
+
+/// The libc functions the coverage dump calls.
+struct CoverageRuntime<'ctx> {
+    getenv: FunctionValue<'ctx>,
+    fopen: FunctionValue<'ctx>,
+    fprintf: FunctionValue<'ctx>,
+    fclose: FunctionValue<'ctx>,
+    atexit: FunctionValue<'ctx>,
+}
+
+/// A counter is bumped every time its region runs. A region starts at a function
+/// entry or a branch target, and ends at the next statement that branches.
+#[derive(Debug)]
+pub struct LLVMCoverageContext<'ctx> {
+    source: Arc<SourceFile>,
+    counters: Vec<(GlobalValue<'ctx>, Vec<usize>)>,
+}
+
+impl<'ctx> LLVMCoverageContext<'ctx> {
+    pub fn new(source: Arc<SourceFile>) -> Self {
+        Self {
+            source,
+            counters: Vec::with_capacity(100),
+        }
+    }
+}
+
+impl<'ctx> LLVMCoverageContext<'ctx> {
+    pub fn instrument(
+        &mut self,
+        llvm_module: &Module<'ctx>,
+        llvm_context: &'ctx Context,
+        llvm_builder: &Builder<'ctx>,
+        header: Option<Span>,
+        stmts: &[Ast],
+    ) {
+        let lines: Vec<usize> = self::get_region_lines(header, stmts);
+
+        if lines.is_empty() {
+            return;
+        }
+
+        let Some(block) = llvm_builder.get_insert_block() else {
+            return;
+        };
+
+        if block.get_terminator().is_some() {
+            return;
+        }
+
+        let counter_type: IntType = llvm_context.i64_type();
+
+        let counter: GlobalValue =
+            llvm_module.add_global(counter_type, None, "__thrush_coverage_counter");
+
+        counter.set_linkage(Linkage::Private);
+        counter.set_initializer(&counter_type.const_zero());
+
+        if llvm_builder
+            .build_atomicrmw(
+                AtomicRMWBinOp::Add,
+                counter.as_pointer_value(),
+                counter_type.const_int(1, false),
+                AtomicOrdering::Monotonic,
+            )
+            .is_err()
+        {
+            self::codegen_abort("Cannot increment a coverage counter.");
+        }
+
+        self.counters.push((counter, lines));
+    }
+
+    /// Registers with 'atexit' a function that appends every counter to the coverage
+    /// file, as 'path:lines:count' records.
+    pub fn finalize(
+        &self,
+        llvm_module: &Module<'ctx>,
+        llvm_context: &'ctx Context,
+        llvm_builder: &Builder<'ctx>,
+    ) {
+        if self.counters.is_empty() {
+            return;
+        }
+
+        let Some(runtime) = self::declare_runtime(llvm_module, llvm_context) else {
+            return;
+        };
+
+        llvm_builder.unset_current_debug_location();
+
+        let dump: FunctionValue =
+            self.compile_dump(llvm_module, llvm_context, llvm_builder, &runtime);
+
+        self::compile_register(llvm_module, llvm_context, llvm_builder, &runtime, dump);
+    }
+}
+
+impl<'ctx> LLVMCoverageContext<'ctx> {
+    fn compile_dump(
+        &self,
+        llvm_module: &Module<'ctx>,
+        llvm_context: &'ctx Context,
+        llvm_builder: &Builder<'ctx>,
+        runtime: &CoverageRuntime<'ctx>,
+    ) -> FunctionValue<'ctx> {
+        let dump: FunctionValue = llvm_module.add_function(
+            "__thrush_coverage_dump",
+            llvm_context.void_type().fn_type(&[], false),
+            Some(Linkage::Internal),
+        );
+
+        let entry: BasicBlock = llvm_context.append_basic_block(dump, "");
+        let write: BasicBlock = llvm_context.append_basic_block(dump, "coverage.write");
+        let exit: BasicBlock = llvm_context.append_basic_block(dump, "coverage.exit");
+
+        llvm_builder.position_at_end(entry);
+
+        let env_name: PointerValue = llvm_builder
+            .build_global_string_ptr(COVERAGE_FILE_ENV, "")
+            .unwrap_or_else(self::dump_abort)
+            .as_pointer_value();
+
+        let default_path: PointerValue = llvm_builder
+            .build_global_string_ptr(COVERAGE_DEFAULT_FILE, "")
+            .unwrap_or_else(self::dump_abort)
+            .as_pointer_value();
+
+        let mode: PointerValue = llvm_builder
+            .build_global_string_ptr("a", "")
+            .unwrap_or_else(self::dump_abort)
+            .as_pointer_value();
+
+        let env_path: PointerValue = self::call_ptr(llvm_builder, runtime.getenv, &[env_name]);
+
+        let env_unset: IntValue = llvm_builder
+            .build_is_null(env_path, "")
+            .unwrap_or_else(self::dump_abort);
+
+        let path: PointerValue = llvm_builder
+            .build_select(env_unset, default_path, env_path, "")
+            .unwrap_or_else(self::dump_abort)
+            .into_pointer_value();
+
+        let file: PointerValue = self::call_ptr(llvm_builder, runtime.fopen, &[path, mode]);
+
+        let file_unopened: IntValue = llvm_builder
+            .build_is_null(file, "")
+            .unwrap_or_else(self::dump_abort);
+
+        llvm_builder
+            .build_conditional_branch(file_unopened, exit, write)
+            .unwrap_or_else(self::dump_abort);
+
+        llvm_builder.position_at_end(write);
+
+        let path: String = self
+            .source
+            .get_path()
+            .canonicalize()
+            .unwrap_or_else(|_| self.source.get_path().to_path_buf())
+            .display()
+            .to_string()
+            .replace('%', "%%");
+
+        self.counters.iter().for_each(|(counter, lines)| {
+            let lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+
+            let record: PointerValue = llvm_builder
+                .build_global_string_ptr(&format!("{}:{}:%llu\n", path, lines.join(",")), "")
+                .unwrap_or_else(self::dump_abort)
+                .as_pointer_value();
+
+            let count: IntValue = llvm_builder
+                .build_load(llvm_context.i64_type(), counter.as_pointer_value(), "")
+                .unwrap_or_else(self::dump_abort)
+                .into_int_value();
+
+            llvm_builder
+                .build_call(
+                    runtime.fprintf,
+                    &[file.into(), record.into(), count.into()],
+                    "",
+                )
+                .unwrap_or_else(self::dump_abort);
+        });
+
+        llvm_builder
+            .build_call(runtime.fclose, &[file.into()], "")
+            .unwrap_or_else(self::dump_abort);
+
+        llvm_builder
+            .build_unconditional_branch(exit)
+            .unwrap_or_else(self::dump_abort);
+
+        llvm_builder.position_at_end(exit);
+        llvm_builder
+            .build_return(None)
+            .unwrap_or_else(self::dump_abort);
+
+        dump
+    }
+}
+
+/// Runs a module constructor that hands the dump function to 'atexit'.
+fn compile_register<'ctx>(
+    llvm_module: &Module<'ctx>,
+    llvm_context: &'ctx Context,
+    llvm_builder: &Builder<'ctx>,
+    runtime: &CoverageRuntime<'ctx>,
+    dump: FunctionValue<'ctx>,
+) {
+    let ptr_type: PointerType = llvm_context.ptr_type(AddressSpace::default());
+
+    let register: FunctionValue = llvm_module.add_function(
+        "__thrush_coverage_register",
+        llvm_context.void_type().fn_type(&[], false),
+        Some(Linkage::Internal),
+    );
+
+    let entry: BasicBlock = llvm_context.append_basic_block(register, "");
+
+    llvm_builder.position_at_end(entry);
+
+    let dump_ptr: PointerValue = dump.as_global_value().as_pointer_value();
+
+    if llvm_builder
+        .build_call(runtime.atexit, &[dump_ptr.into()], "")
+        .and_then(|_| llvm_builder.build_return(None))
+        .is_err()
+    {
+        self::codegen_abort("Cannot compile the coverage constructor.");
+    }
+
+    let ctor_type: StructType = llvm_context.struct_type(
+        &[
+            llvm_context.i32_type().into(),
+            ptr_type.into(),
+            ptr_type.into(),
+        ],
+        false,
+    );
+
+    let ctor: StructValue = llvm_context.const_struct(
+        &[
+            llvm_context.i32_type().const_int(65535, false).into(),
+            register.as_global_value().as_pointer_value().into(),
+            ptr_type.const_null().into(),
+        ],
+        false,
+    );
+
+    let ctors: GlobalValue =
+        llvm_module.add_global(ctor_type.array_type(1), None, "llvm.global_ctors");
+
+    ctors.set_linkage(Linkage::Appending);
+    ctors.set_initializer(&ctor_type.const_array(&[ctor]));
+}
+
+/// The lines of a region: its header, and every statement up to the first one
+/// that branches, which belongs to the region too.
+fn get_region_lines(header: Option<Span>, stmts: &[Ast]) -> Vec<usize> {
+    let mut lines: Vec<usize> = Vec::with_capacity(stmts.len() + 1);
+
+    if let Some(header) = header {
+        lines.push(header.get_line());
+    }
+
+    for stmt in stmts {
+        let line: usize = stmt.get_span().get_line();
+
+        if !lines.contains(&line) {
+            lines.push(line);
+        }
+
+        if self::is_branching(stmt) {
+            break;
+        }
+    }
+
+    lines
+}
+
+pub fn is_branching(stmt: &Ast) -> bool {
+    matches!(
+        stmt,
+        Ast::If { .. }
+            | Ast::For { .. }
+            | Ast::While { .. }
+            | Ast::Loop { .. }
+            | Ast::Block { .. }
+            | Ast::Return { .. }
+            | Ast::Break { .. }
+            | Ast::Continue { .. }
+    )
+}
+
+/// The libc functions of the dump. A program that declares one of them with another
+/// signature is compiled without the dump.
+fn declare_runtime<'ctx>(
+    llvm_module: &Module<'ctx>,
+    llvm_context: &'ctx Context,
+) -> Option<CoverageRuntime<'ctx>> {
+    let ptr_type: PointerType = llvm_context.ptr_type(AddressSpace::default());
+    let i32_type: IntType = llvm_context.i32_type();
+
+    Some(CoverageRuntime {
+        getenv: self::declare(
+            llvm_module,
+            "getenv",
+            ptr_type.fn_type(&[ptr_type.into()], false),
+        )?,
+        fopen: self::declare(
+            llvm_module,
+            "fopen",
+            ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false),
+        )?,
+        fprintf: self::declare(
+            llvm_module,
+            "fprintf",
+            i32_type.fn_type(&[ptr_type.into(), ptr_type.into()], true),
+        )?,
+        fclose: self::declare(
+            llvm_module,
+            "fclose",
+            i32_type.fn_type(&[ptr_type.into()], false),
+        )?,
+        atexit: self::declare(
+            llvm_module,
+            "atexit",
+            i32_type.fn_type(&[ptr_type.into()], false),
+        )?,
+    })
+}
+
+fn declare<'ctx>(
+    llvm_module: &Module<'ctx>,
+    name: &str,
+    kind: FunctionType<'ctx>,
+) -> Option<FunctionValue<'ctx>> {
+    let function: Option<FunctionValue> = runtime::get_or_declare(llvm_module, name, kind);
+
+    if function.is_none() {
+        logging::log(
+            LoggingType::Warning,
+            &format!(
+                "The coverage counters are not written, since the program declares '{}' with another signature.",
+                name
+            ),
+        );
+    }
+
+    function
+}
+
+fn call_ptr<'ctx>(
+    llvm_builder: &Builder<'ctx>,
+    function: FunctionValue<'ctx>,
+    args: &[PointerValue<'ctx>],
+) -> PointerValue<'ctx> {
+    let args: Vec<_> = args.iter().map(|arg| (*arg).into()).collect();
+
+    llvm_builder
+        .build_call(function, &args, "")
+        .ok()
+        .and_then(|call| call.try_as_basic_value().left())
+        .map(|value| value.into_pointer_value())
+        .unwrap_or_else(|| {
+            self::codegen_abort("Cannot compile a call of the coverage runtime.");
+            unreachable!()
+        })
+}
+
+fn dump_abort<T>(_: BuilderError) -> T {
+    self::codegen_abort("Cannot compile the coverage dump.");
+    unreachable!()
+}
+
+fn codegen_abort(message: &str) {
+    logging::log(LoggingType::BackendBug, message);
+}
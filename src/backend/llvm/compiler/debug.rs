@@ -0,0 +1,537 @@
+use std::{ops::Range, path::Path, sync::Arc};
+
+use ahash::AHashMap as HashMap;
+
+use inkwell::{
+    AddressSpace,
+    builder::Builder,
+    context::Context,
+    debug_info::{
+        AsDIScope, DICompileUnit, DIFile, DIFlags, DIFlagsConstants, DILocalVariable, DILocation,
+        DIScope, DISubprogram, DISubroutineType, DIType, DWARFEmissionKind, DWARFSourceLanguage,
+        DebugInfoBuilder, LLVMDWARFTypeEncoding,
+    },
+    module::{FlagBehavior, Module},
+    targets::TargetData,
+    types::{BasicTypeEnum, StructType},
+    values::{FunctionValue, PointerValue},
+};
+
+use crate::{
+    backend::llvm::compiler::typegen,
+    core::{
+        compiler::sourcemap::SourceFile,
+        console::logging::{self, LoggingType},
+    },
+    frontend::{lexer::span::Span, types::ast::Ast, typesystem::types::Type},
+};
+
+const DWARF_VERSION: u64 = 4;
+
+const DW_ATE_BOOLEAN: LLVMDWARFTypeEncoding = 0x02;
+const DW_ATE_FLOAT: LLVMDWARFTypeEncoding = 0x04;
+const DW_ATE_SIGNED: LLVMDWARFTypeEncoding = 0x05;
+const DW_ATE_SIGNED_CHAR: LLVMDWARFTypeEncoding = 0x06;
+const DW_ATE_UNSIGNED: LLVMDWARFTypeEncoding = 0x08;
+
+#[derive(Debug)]
+pub struct LLVMDebugContext<'ctx> {
+    builder: DebugInfoBuilder<'ctx>,
+    compile_unit: DICompileUnit<'ctx>,
+    file: DIFile<'ctx>,
+    source: Arc<SourceFile>,
+    scopes: Vec<DIScope<'ctx>>,
+    structs: HashMap<&'ctx str, Vec<&'ctx str>>,
+    is_optimized: bool,
+}
+
+impl<'ctx> LLVMDebugContext<'ctx> {
+    pub fn new(
+        module: &Module<'ctx>,
+        source: Arc<SourceFile>,
+        ast: &'ctx [Ast<'ctx>],
+        is_optimized: bool,
+    ) -> Self {
+        let path: &Path = source.get_path();
+
+        let filename: String = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let directory: String = path
+            .canonicalize()
+            .ok()
+            .and_then(|path| path.parent().map(|parent| parent.to_path_buf()))
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+
+        module.add_basic_value_flag(
+            "Debug Info Version",
+            FlagBehavior::Warning,
+            module
+                .get_context()
+                .i32_type()
+                .const_int(inkwell::debug_info::debug_metadata_version().into(), false),
+        );
+
+        module.add_basic_value_flag(
+            "Dwarf Version",
+            FlagBehavior::Warning,
+            module
+                .get_context()
+                .i32_type()
+                .const_int(DWARF_VERSION, false),
+        );
+
+        let (builder, compile_unit) = module.create_debug_info_builder(
+            true,
+            DWARFSourceLanguage::C,
+            &filename,
+            &directory,
+            &format!("thrushc {}", env!("CARGO_PKG_VERSION")),
+            is_optimized,
+            "",
+            0,
+            "",
+            DWARFEmissionKind::Full,
+            0,
+            false,
+            false,
+            "",
+            "",
+        );
+
+        let mut structs: HashMap<&'ctx str, Vec<&'ctx str>> = HashMap::with_capacity(50);
+
+        ast.iter().for_each(|node| {
+            if let Ast::Struct { name, fields, .. } = node {
+                structs.insert(name, fields.1.iter().map(|field| field.0).collect());
+            }
+        });
+
+        Self {
+            builder,
+            file: compile_unit.get_file(),
+            compile_unit,
+            source,
+            scopes: Vec::with_capacity(100),
+            structs,
+            is_optimized,
+        }
+    }
+
+    pub fn finalize(&self) {
+        self.builder.finalize();
+    }
+}
+
+impl<'ctx> LLVMDebugContext<'ctx> {
+    /// Attaches a 'DISubprogram' to the function and makes it the current scope.
+    #[allow(clippy::too_many_arguments)]
+    pub fn begin_function(
+        &mut self,
+        context: &'ctx Context,
+        llvm_builder: &Builder<'ctx>,
+        target_data: &TargetData,
+        llvm_function: FunctionValue<'ctx>,
+        name: &str,
+        return_type: &Type,
+        parameters_types: &[Type],
+        is_public: bool,
+        span: Span,
+    ) {
+        let return_type: Option<DIType> = self.get_type(context, target_data, return_type);
+
+        let parameters_types: Vec<DIType> = parameters_types
+            .iter()
+            .filter_map(|kind| self.get_type(context, target_data, kind))
+            .collect();
+
+        let subroutine_type: DISubroutineType = self.builder.create_subroutine_type(
+            self.file,
+            return_type,
+            &parameters_types,
+            DIFlags::ZERO,
+        );
+
+        let line: u32 = self.get_line(span);
+        let linkage_name: String = llvm_function.get_name().to_string_lossy().to_string();
+
+        let subprogram: DISubprogram = self.builder.create_function(
+            self.compile_unit.as_debug_info_scope(),
+            name,
+            Some(&linkage_name),
+            self.file,
+            line,
+            subroutine_type,
+            !is_public,
+            true,
+            line,
+            DIFlags::PROTOTYPED,
+            self.is_optimized,
+        );
+
+        llvm_function.set_subprogram(subprogram);
+
+        self.scopes.clear();
+        self.scopes.push(subprogram.as_debug_info_scope());
+
+        self.set_location(context, llvm_builder, span);
+    }
+
+    pub fn end_function(&mut self, llvm_builder: &Builder<'ctx>) {
+        self.scopes.clear();
+        llvm_builder.unset_current_debug_location();
+    }
+
+    pub fn begin_scope(&mut self, span: Span) {
+        if self.scopes.is_empty() {
+            return;
+        }
+
+        let (line, column) = self.get_line_and_column(span);
+
+        let lexical_block: DIScope = self
+            .builder
+            .create_lexical_block(self.get_scope(), self.file, line, column)
+            .as_debug_info_scope();
+
+        self.scopes.push(lexical_block);
+    }
+
+    pub fn end_scope(&mut self) {
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
+    }
+
+    pub fn set_location(&self, context: &'ctx Context, llvm_builder: &Builder<'ctx>, span: Span) {
+        if self.scopes.is_empty() {
+            return;
+        }
+
+        llvm_builder.set_current_debug_location(self.get_location(context, span));
+    }
+
+    /// Describes a stack slot as a source level variable. Parameters carry their
+    /// one-based position in 'arg_no'.
+    #[allow(clippy::too_many_arguments)]
+    pub fn declare_variable(
+        &self,
+        context: &'ctx Context,
+        llvm_builder: &Builder<'ctx>,
+        target_data: &TargetData,
+        storage: PointerValue<'ctx>,
+        name: &str,
+        kind: &Type,
+        arg_no: Option<u32>,
+        span: Span,
+    ) {
+        if self.scopes.is_empty() {
+            return;
+        }
+
+        let Some(variable_type) = self.get_type(context, target_data, kind) else {
+            return;
+        };
+
+        let Some(block) = llvm_builder.get_insert_block() else {
+            return;
+        };
+
+        let line: u32 = self.get_line(span);
+
+        let variable: DILocalVariable = match arg_no {
+            Some(arg_no) => self.builder.create_parameter_variable(
+                self.get_scope(),
+                name,
+                arg_no,
+                self.file,
+                line,
+                variable_type,
+                true,
+                DIFlags::ZERO,
+            ),
+            None => self.builder.create_auto_variable(
+                self.get_scope(),
+                name,
+                self.file,
+                line,
+                variable_type,
+                true,
+                DIFlags::ZERO,
+                0,
+            ),
+        };
+
+        self.builder.insert_declare_at_end(
+            storage,
+            Some(variable),
+            None,
+            self.get_location(context, span),
+            block,
+        );
+    }
+}
+
+impl<'ctx> LLVMDebugContext<'ctx> {
+    fn get_type(
+        &self,
+        context: &'ctx Context,
+        target_data: &TargetData,
+        kind: &Type,
+    ) -> Option<DIType<'ctx>> {
+        match kind {
+            Type::S8 => self.create_basic_type("s8", 8, DW_ATE_SIGNED),
+            Type::S16 => self.create_basic_type("s16", 16, DW_ATE_SIGNED),
+            Type::S32 => self.create_basic_type("s32", 32, DW_ATE_SIGNED),
+            Type::S64 => self.create_basic_type("s64", 64, DW_ATE_SIGNED),
+            Type::U8 => self.create_basic_type("u8", 8, DW_ATE_UNSIGNED),
+            Type::U16 => self.create_basic_type("u16", 16, DW_ATE_UNSIGNED),
+            Type::U32 => self.create_basic_type("u32", 32, DW_ATE_UNSIGNED),
+            Type::U64 => self.create_basic_type("u64", 64, DW_ATE_UNSIGNED),
+            Type::F32 => self.create_basic_type("f32", 32, DW_ATE_FLOAT),
+            Type::F64 => self.create_basic_type("f64", 64, DW_ATE_FLOAT),
+            Type::Bool => self.create_basic_type("bool", 8, DW_ATE_BOOLEAN),
+            Type::Char => self.create_basic_type("char", 8, DW_ATE_SIGNED_CHAR),
+
+            Type::Const(inner) => self.get_type(context, target_data, inner),
+
+            Type::Mut(inner) | Type::Ptr(Some(inner)) => {
+                let pointee: DIType = self.get_type(context, target_data, inner)?;
+                Some(self.create_pointer_type(context, target_data, "ptr", pointee))
+            }
+
+            Type::Ptr(None) | Type::Addr => {
+                let pointee: DIType = self.create_basic_type("u8", 8, DW_ATE_UNSIGNED)?;
+                Some(self.create_pointer_type(context, target_data, "ptr", pointee))
+            }
+
+            Type::Str => {
+                let pointee: DIType = self.create_basic_type("char", 8, DW_ATE_SIGNED_CHAR)?;
+                self.create_fat_pointer_type(context, target_data, kind, "str", pointee)
+            }
+
+            Type::Array(inner) => {
+                let pointee: DIType = self.get_type(context, target_data, inner)?;
+                self.create_fat_pointer_type(context, target_data, kind, "array", pointee)
+            }
+
+            Type::FixedArray(inner, size) => {
+                let element_type: DIType = self.get_type(context, target_data, inner)?;
+                let llvm_type: BasicTypeEnum = typegen::generate_type(context, kind);
+
+                let subscript: Range<i64> = 0..i64::from(*size);
+
+                Some(
+                    self.builder
+                        .create_array_type(
+                            element_type,
+                            target_data.get_bit_size(&llvm_type),
+                            target_data.get_abi_alignment(&llvm_type) * 8,
+                            std::slice::from_ref(&subscript),
+                        )
+                        .as_type(),
+                )
+            }
+
+            Type::Struct(name, fields) => {
+                let llvm_type: BasicTypeEnum = typegen::generate_type(context, kind);
+                let struct_type: StructType = llvm_type.into_struct_type();
+
+                let field_names: Option<&Vec<&str>> = self.structs.get(name.as_str());
+
+                let members: Vec<DIType> = fields
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, field)| {
+                        let field_name: String = field_names
+                            .and_then(|names| names.get(idx))
+                            .map(|name| name.to_string())
+                            .unwrap_or_else(|| format!("field{}", idx));
+
+                        self.create_member_type(
+                            context,
+                            target_data,
+                            struct_type,
+                            idx as u32,
+                            &field_name,
+                            field,
+                        )
+                    })
+                    .collect();
+
+                Some(
+                    self.builder
+                        .create_struct_type(
+                            self.compile_unit.as_debug_info_scope(),
+                            name,
+                            self.file,
+                            0,
+                            target_data.get_bit_size(&llvm_type),
+                            target_data.get_abi_alignment(&llvm_type) * 8,
+                            DIFlags::ZERO,
+                            None,
+                            &members,
+                            0,
+                            None,
+                            name,
+                        )
+                        .as_type(),
+                )
+            }
+
+            Type::Void => None,
+        }
+    }
+
+    fn create_basic_type(
+        &self,
+        name: &str,
+        size_in_bits: u64,
+        encoding: LLVMDWARFTypeEncoding,
+    ) -> Option<DIType<'ctx>> {
+        self.builder
+            .create_basic_type(name, size_in_bits, encoding, DIFlags::ZERO)
+            .map(|basic_type| basic_type.as_type())
+            .map_err(|error| {
+                logging::log(
+                    LoggingType::BackendBug,
+                    &format!("Unable to create the debug type '{}': {}.", name, error),
+                );
+            })
+            .ok()
+    }
+
+    fn create_pointer_type(
+        &self,
+        context: &'ctx Context,
+        target_data: &TargetData,
+        name: &str,
+        pointee: DIType<'ctx>,
+    ) -> DIType<'ctx> {
+        let ptr_size: u64 = target_data.get_bit_size(&context.ptr_type(AddressSpace::default()));
+
+        self.builder
+            .create_pointer_type(name, pointee, ptr_size, 0, AddressSpace::default())
+            .as_type()
+    }
+
+    /// Describes the '{ ptr, u32 }' representation shared by 'str' and arrays.
+    fn create_fat_pointer_type(
+        &self,
+        context: &'ctx Context,
+        target_data: &TargetData,
+        kind: &Type,
+        name: &str,
+        pointee: DIType<'ctx>,
+    ) -> Option<DIType<'ctx>> {
+        let llvm_type: BasicTypeEnum = typegen::generate_type(context, kind);
+        let struct_type: StructType = llvm_type.into_struct_type();
+
+        let ptr_type: DIType = self.create_pointer_type(context, target_data, "ptr", pointee);
+        let len_type: DIType = self.create_basic_type("u32", 32, DW_ATE_UNSIGNED)?;
+
+        let members: [DIType; 2] = [
+            self.create_member(target_data, struct_type, 0, "ptr", ptr_type),
+            self.create_member(target_data, struct_type, 1, "len", len_type),
+        ];
+
+        Some(
+            self.builder
+                .create_struct_type(
+                    self.compile_unit.as_debug_info_scope(),
+                    name,
+                    self.file,
+                    0,
+                    target_data.get_bit_size(&llvm_type),
+                    target_data.get_abi_alignment(&llvm_type) * 8,
+                    DIFlags::ZERO,
+                    None,
+                    &members,
+                    0,
+                    None,
+                    name,
+                )
+                .as_type(),
+        )
+    }
+
+    fn create_member_type(
+        &self,
+        context: &'ctx Context,
+        target_data: &TargetData,
+        struct_type: StructType<'ctx>,
+        idx: u32,
+        name: &str,
+        kind: &Type,
+    ) -> Option<DIType<'ctx>> {
+        let member_type: DIType = self.get_type(context, target_data, kind)?;
+
+        Some(self.create_member(target_data, struct_type, idx, name, member_type))
+    }
+
+    fn create_member(
+        &self,
+        target_data: &TargetData,
+        struct_type: StructType<'ctx>,
+        idx: u32,
+        name: &str,
+        member_type: DIType<'ctx>,
+    ) -> DIType<'ctx> {
+        let offset: u64 = target_data
+            .offset_of_element(&struct_type, idx)
+            .unwrap_or_default()
+            * 8;
+
+        self.builder
+            .create_member_type(
+                self.compile_unit.as_debug_info_scope(),
+                name,
+                self.file,
+                0,
+                member_type.get_size_in_bits(),
+                member_type.get_align_in_bits(),
+                offset,
+                DIFlags::ZERO,
+                member_type,
+            )
+            .as_type()
+    }
+}
+
+impl<'ctx> LLVMDebugContext<'ctx> {
+    fn get_scope(&self) -> DIScope<'ctx> {
+        self.scopes
+            .last()
+            .copied()
+            .unwrap_or_else(|| self.compile_unit.as_debug_info_scope())
+    }
+
+    fn get_location(&self, context: &'ctx Context, span: Span) -> DILocation<'ctx> {
+        let (line, column) = self.get_line_and_column(span);
+
+        self.builder
+            .create_debug_location(context, line, column, self.get_scope(), None)
+    }
+
+    fn get_line(&self, span: Span) -> u32 {
+        self.get_line_and_column(span).0
+    }
+
+    /// Resolves the one-based line and column of the span start. Spans from other
+    /// files keep the line the lexer recorded, without a column.
+    fn get_line_and_column(&self, span: Span) -> (u32, u32) {
+        if span.get_file() != self.source.get_id() {
+            return (span.get_line() as u32, 0);
+        }
+
+        let offset: usize = span.get_span_start();
+        let line: usize = self.source.get_line_index(offset);
+        let column: usize = offset.saturating_sub(self.source.get_line_start(line));
+
+        ((line + 1) as u32, (column + 1) as u32)
+    }
+}
@@ -0,0 +1,351 @@
+use std::path::Path;
+
+use inkwell::{
+    AddressSpace, IntPredicate,
+    basic_block::BasicBlock,
+    builder::{Builder, BuilderError},
+    context::Context,
+    module::Module,
+    types::{FunctionType, IntType, PointerType},
+    values::{FunctionValue, IntValue, PointerValue},
+};
+
+use crate::{
+    backend::llvm::compiler::runtime,
+    core::{
+        console::logging::{self, LoggingType},
+        utils::sha256,
+    },
+};
+
+/// A '@test' function: the symbol the harness calls and the name it reports.
+pub type TestFunction = (String, String);
+
+/// The symbol of a test function. Tests with the same name in different files get
+/// different symbols.
+pub fn get_test_symbol(file: &Path, ascii_name: &str) -> String {
+    let path_hash: String = sha256::digest(file.to_string_lossy().as_bytes());
+
+    format!("__thrush_test_{}_{}", &path_hash[..16], ascii_name)
+}
+
+/// The harness forks a process for every test, so it needs a POSIX target.
+pub fn is_supported_target(target_triple: &str) -> bool {
+    const POSIX_SYSTEMS: [&str; 11] = [
+        "linux",
+        "darwin",
+        "macos",
+        "ios",
+        "freebsd",
+        "netbsd",
+        "openbsd",
+        "dragonfly",
+        "solaris",
+        "illumos",
+        "haiku",
+    ];
+
+    target_triple.split('-').any(|component| {
+        POSIX_SYSTEMS
+            .iter()
+            .any(|system| component.starts_with(system))
+    })
+}
+
+/// Builds the 'main' of a test build. Every test runs in a child process, so a failed
+/// assertion only fails its own test; the exit code tells whether any test failed.
+pub fn compile<'ctx>(
+    llvm_module: &Module<'ctx>,
+    llvm_context: &'ctx Context,
+    llvm_builder: &Builder<'ctx>,
+    tests: &[TestFunction],
+) {
+    let i32_type: IntType = llvm_context.i32_type();
+    let ptr_type: PointerType = llvm_context.ptr_type(AddressSpace::default());
+
+    let printf: FunctionValue = self::declare(
+        llvm_module,
+        "printf",
+        i32_type.fn_type(&[ptr_type.into()], true),
+    );
+
+    let fflush: FunctionValue = self::declare(
+        llvm_module,
+        "fflush",
+        i32_type.fn_type(&[ptr_type.into()], false),
+    );
+
+    let fork: FunctionValue = self::declare(llvm_module, "fork", i32_type.fn_type(&[], false));
+
+    let waitpid: FunctionValue = self::declare(
+        llvm_module,
+        "waitpid",
+        i32_type.fn_type(&[i32_type.into(), ptr_type.into(), i32_type.into()], false),
+    );
+
+    let exit: FunctionValue = self::declare(
+        llvm_module,
+        "_exit",
+        llvm_context.void_type().fn_type(&[i32_type.into()], false),
+    );
+
+    let main: FunctionValue = llvm_module.add_function("main", i32_type.fn_type(&[], false), None);
+
+    let entry: BasicBlock = llvm_context.append_basic_block(main, "");
+
+    llvm_builder.position_at_end(entry);
+
+    let passed: PointerValue = llvm_builder
+        .build_alloca(i32_type, "passed")
+        .unwrap_or_else(self::harness_abort);
+
+    let failed: PointerValue = llvm_builder
+        .build_alloca(i32_type, "failed")
+        .unwrap_or_else(self::harness_abort);
+
+    let status: PointerValue = llvm_builder
+        .build_alloca(i32_type, "status")
+        .unwrap_or_else(self::harness_abort);
+
+    llvm_builder
+        .build_store(passed, i32_type.const_zero())
+        .unwrap_or_else(self::harness_abort);
+
+    llvm_builder
+        .build_store(failed, i32_type.const_zero())
+        .unwrap_or_else(self::harness_abort);
+
+    let null: PointerValue = ptr_type.const_null();
+
+    let header: PointerValue = self::compile_string(
+        llvm_builder,
+        &format!("\nrunning {} test(s)\n", tests.len()),
+    );
+
+    llvm_builder
+        .build_call(printf, &[header.into()], "")
+        .unwrap_or_else(self::harness_abort);
+
+    tests.iter().for_each(|(symbol, name)| {
+        let test: FunctionValue = self::declare(
+            llvm_module,
+            symbol,
+            llvm_context.void_type().fn_type(&[], false),
+        );
+
+        let child: BasicBlock = llvm_context.append_basic_block(main, "test.run");
+        let parent: BasicBlock = llvm_context.append_basic_block(main, "test.wait");
+        let ok: BasicBlock = llvm_context.append_basic_block(main, "test.ok");
+        let failure: BasicBlock = llvm_context.append_basic_block(main, "test.failed");
+        let next: BasicBlock = llvm_context.append_basic_block(main, "test.next");
+
+        // Pending output would be printed twice, once by each process.
+        llvm_builder
+            .build_call(fflush, &[null.into()], "")
+            .unwrap_or_else(self::harness_abort);
+
+        let pid: IntValue = self::call_int(llvm_builder, fork, &[]);
+
+        let is_child: IntValue = llvm_builder
+            .build_int_compare(IntPredicate::EQ, pid, i32_type.const_zero(), "")
+            .unwrap_or_else(self::harness_abort);
+
+        llvm_builder
+            .build_conditional_branch(is_child, child, parent)
+            .unwrap_or_else(self::harness_abort);
+
+        llvm_builder.position_at_end(child);
+
+        llvm_builder
+            .build_call(test, &[], "")
+            .and_then(|_| llvm_builder.build_call(fflush, &[null.into()], ""))
+            .and_then(|_| llvm_builder.build_call(exit, &[i32_type.const_zero().into()], ""))
+            .and_then(|_| llvm_builder.build_unreachable())
+            .unwrap_or_else(self::harness_abort);
+
+        llvm_builder.position_at_end(parent);
+
+        llvm_builder
+            .build_store(status, i32_type.const_all_ones())
+            .unwrap_or_else(self::harness_abort);
+
+        let forked: IntValue = llvm_builder
+            .build_int_compare(IntPredicate::SGT, pid, i32_type.const_zero(), "")
+            .unwrap_or_else(self::harness_abort);
+
+        llvm_builder
+            .build_call(
+                waitpid,
+                &[pid.into(), status.into(), i32_type.const_zero().into()],
+                "",
+            )
+            .unwrap_or_else(self::harness_abort);
+
+        let status_value: IntValue = llvm_builder
+            .build_load(i32_type, status, "")
+            .unwrap_or_else(self::harness_abort)
+            .into_int_value();
+
+        let exited_cleanly: IntValue = llvm_builder
+            .build_int_compare(IntPredicate::EQ, status_value, i32_type.const_zero(), "")
+            .unwrap_or_else(self::harness_abort);
+
+        let succeeded: IntValue = llvm_builder
+            .build_and(forked, exited_cleanly, "")
+            .unwrap_or_else(self::harness_abort);
+
+        llvm_builder
+            .build_conditional_branch(succeeded, ok, failure)
+            .unwrap_or_else(self::harness_abort);
+
+        self::compile_outcome(
+            llvm_context,
+            llvm_builder,
+            (ok, next),
+            printf,
+            passed,
+            &format!("test {} ... ok\n", name),
+        );
+
+        self::compile_outcome(
+            llvm_context,
+            llvm_builder,
+            (failure, next),
+            printf,
+            failed,
+            &format!("test {} ... FAILED\n", name),
+        );
+
+        llvm_builder.position_at_end(next);
+    });
+
+    let passed: IntValue = llvm_builder
+        .build_load(i32_type, passed, "")
+        .unwrap_or_else(self::harness_abort)
+        .into_int_value();
+
+    let failed: IntValue = llvm_builder
+        .build_load(i32_type, failed, "")
+        .unwrap_or_else(self::harness_abort)
+        .into_int_value();
+
+    let any_failed: IntValue = llvm_builder
+        .build_int_compare(IntPredicate::NE, failed, i32_type.const_zero(), "")
+        .unwrap_or_else(self::harness_abort);
+
+    let ok_result: PointerValue = self::compile_string(llvm_builder, "ok");
+    let failed_result: PointerValue = self::compile_string(llvm_builder, "FAILED");
+
+    let result: PointerValue = llvm_builder
+        .build_select(any_failed, failed_result, ok_result, "")
+        .unwrap_or_else(self::harness_abort)
+        .into_pointer_value();
+
+    let summary: PointerValue =
+        self::compile_string(llvm_builder, "\ntest result: %s. %d passed; %d failed.\n");
+
+    llvm_builder
+        .build_call(
+            printf,
+            &[summary.into(), result.into(), passed.into(), failed.into()],
+            "",
+        )
+        .and_then(|_| llvm_builder.build_call(fflush, &[null.into()], ""))
+        .unwrap_or_else(self::harness_abort);
+
+    let exit_code: IntValue = llvm_builder
+        .build_int_z_extend(any_failed, i32_type, "")
+        .unwrap_or_else(self::harness_abort);
+
+    llvm_builder
+        .build_return(Some(&exit_code))
+        .unwrap_or_else(self::harness_abort);
+}
+
+/// Prints the outcome of a test and counts it.
+fn compile_outcome<'ctx>(
+    llvm_context: &'ctx Context,
+    llvm_builder: &Builder<'ctx>,
+    blocks: (BasicBlock<'ctx>, BasicBlock<'ctx>),
+    printf: FunctionValue<'ctx>,
+    counter: PointerValue<'ctx>,
+    message: &str,
+) {
+    let (block, next) = blocks;
+
+    llvm_builder.position_at_end(block);
+
+    let message: PointerValue = self::compile_string(llvm_builder, &message.replace('%', "%%"));
+
+    llvm_builder
+        .build_call(printf, &[message.into()], "")
+        .unwrap_or_else(self::harness_abort);
+
+    let counter_type: IntType = llvm_context.i32_type();
+
+    let count: IntValue = llvm_builder
+        .build_load(counter_type, counter, "")
+        .unwrap_or_else(self::harness_abort)
+        .into_int_value();
+
+    let count: IntValue = llvm_builder
+        .build_int_add(count, counter_type.const_int(1, false), "")
+        .unwrap_or_else(self::harness_abort);
+
+    llvm_builder
+        .build_store(counter, count)
+        .and_then(|_| llvm_builder.build_unconditional_branch(next))
+        .unwrap_or_else(self::harness_abort);
+}
+
+fn compile_string<'ctx>(llvm_builder: &Builder<'ctx>, value: &str) -> PointerValue<'ctx> {
+    llvm_builder
+        .build_global_string_ptr(value, "")
+        .unwrap_or_else(self::harness_abort)
+        .as_pointer_value()
+}
+
+fn declare<'ctx>(
+    llvm_module: &Module<'ctx>,
+    name: &str,
+    kind: FunctionType<'ctx>,
+) -> FunctionValue<'ctx> {
+    runtime::get_or_declare(llvm_module, name, kind).unwrap_or_else(|| {
+        logging::log(
+            LoggingType::Panic,
+            &format!(
+                "The test harness cannot call '{}', since it is declared with another signature.",
+                name
+            ),
+        );
+
+        unreachable!()
+    })
+}
+
+fn call_int<'ctx>(
+    llvm_builder: &Builder<'ctx>,
+    function: FunctionValue<'ctx>,
+    args: &[IntValue<'ctx>],
+) -> IntValue<'ctx> {
+    let args: Vec<_> = args.iter().map(|arg| (*arg).into()).collect();
+
+    llvm_builder
+        .build_call(function, &args, "")
+        .ok()
+        .and_then(|call| call.try_as_basic_value().left())
+        .map(|value| value.into_int_value())
+        .unwrap_or_else(|| {
+            self::codegen_abort("Cannot compile a call of the test harness.");
+            unreachable!()
+        })
+}
+
+fn harness_abort<T>(_: BuilderError) -> T {
+    self::codegen_abort("Cannot compile the test harness.");
+    unreachable!()
+}
+
+fn codegen_abort(message: &str) {
+    logging::log(LoggingType::BackendBug, message);
+}
@@ -1,8 +1,20 @@
-use inkwell::{context::Context, values::IntValue};
+use inkwell::{
+    IntPredicate,
+    basic_block::BasicBlock,
+    builder::Builder,
+    context::Context,
+    types::{BasicTypeEnum, IntType},
+    values::{InstructionValue, IntValue, MetadataValue, PointerValue},
+};
 
 use crate::{
-    backend::llvm::compiler::{context::LLVMCodeGenContext, generation::intgen, valuegen},
+    backend::llvm::compiler::{
+        context::LLVMCodeGenContext, generation::intgen, memory::SymbolAllocated, runtime, typegen,
+        valuegen,
+    },
+    core::console::logging::{self, LoggingType},
     frontend::{
+        lexer::span::Span,
         types::ast::Ast,
         typesystem::{traits::TypeMutableExtensions, types::Type},
     },
@@ -12,6 +24,8 @@ pub fn compile<'ctx>(
     context: &mut LLVMCodeGenContext<'_, 'ctx>,
     indexes: &'ctx [Ast],
     expr_type: &'ctx Type,
+    array_size: Option<IntValue<'ctx>>,
+    span: Span,
 ) -> Vec<IntValue<'ctx>> {
     let llvm_context: &Context = context.get_llvm_context();
 
@@ -24,13 +38,198 @@ pub fn compile<'ctx>(
                 let depth: IntValue =
                     valuegen::compile(context, index, Some(&Type::U32)).into_int_value();
 
+                if let Some(size) =
+                    self::get_fixed_array_size(expr_type).filter(|_| context.get_bounds_checks())
+                {
+                    let size: IntValue = depth.get_type().const_int(size as u64, false);
+                    self::compile_bounds_check(context, depth, size, span);
+                }
+
                 vec![base, depth]
             } else {
                 let depth: IntValue =
                     valuegen::compile(context, index, Some(&Type::U64)).into_int_value();
 
+                if let Some(size) = array_size {
+                    self::compile_bounds_check(context, depth, size, span);
+                }
+
                 vec![depth]
             }
         })
         .collect()
 }
+
+/// Loads the runtime length of a dynamic array held by a symbol, when bounds checks are enabled.
+pub fn compile_symbol_array_size<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    symbol: &SymbolAllocated<'ctx>,
+) -> Option<IntValue<'ctx>> {
+    if !context.get_bounds_checks() || !self::is_dynamic_array(symbol.get_type()) {
+        return None;
+    }
+
+    if symbol.is_pointer() {
+        let size_ptr: PointerValue =
+            symbol.gep_struct(context.get_llvm_context(), context.get_llvm_builder(), 1);
+
+        return self::load_array_size(context, size_ptr);
+    }
+
+    Some(
+        symbol
+            .extract_value(context.get_llvm_builder(), 1)
+            .into_int_value(),
+    )
+}
+
+/// Loads the runtime length of a dynamic array behind a pointer, when bounds checks are enabled.
+pub fn compile_anon_array_size<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    ptr: PointerValue<'ctx>,
+    kind: &Type,
+) -> Option<IntValue<'ctx>> {
+    if !context.get_bounds_checks() || !self::is_dynamic_array(kind) {
+        return None;
+    }
+
+    let wrapper_type: BasicTypeEnum =
+        typegen::generate_subtype_with_all(context.get_llvm_context(), kind);
+
+    let size_ptr: PointerValue = context
+        .get_llvm_builder()
+        .build_struct_gep(wrapper_type, ptr, 1, "")
+        .ok()?;
+
+    self::load_array_size(context, size_ptr)
+}
+
+fn load_array_size<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    size_ptr: PointerValue<'ctx>,
+) -> Option<IntValue<'ctx>> {
+    context
+        .get_llvm_builder()
+        .build_load(context.get_llvm_context().i32_type(), size_ptr, "")
+        .ok()
+        .map(|size| size.into_int_value())
+}
+
+fn get_fixed_array_size(kind: &Type) -> Option<u32> {
+    match kind {
+        Type::FixedArray(_, size) => Some(*size),
+        Type::Mut(inner) => self::get_fixed_array_size(inner),
+        _ => None,
+    }
+}
+
+fn is_dynamic_array(kind: &Type) -> bool {
+    match kind {
+        Type::Array(..) => true,
+        Type::Mut(inner) | Type::Const(inner) | Type::Ptr(Some(inner)) => {
+            self::is_dynamic_array(inner)
+        }
+        _ => false,
+    }
+}
+
+/// Branches to a cold block that reports the location and the index, then aborts,
+/// when the index isn't lower than the size.
+fn compile_bounds_check<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    index: IntValue<'ctx>,
+    size: IntValue<'ctx>,
+    span: Span,
+) {
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let Some(function) = llvm_builder
+        .get_insert_block()
+        .and_then(|block| block.get_parent())
+    else {
+        self::codegen_abort("Cannot place a bounds check outside a function.");
+        return;
+    };
+
+    let index: IntValue = self::extend_to_i64(context, index);
+    let size: IntValue = self::extend_to_i64(context, size);
+
+    let Ok(out_of_bounds) = llvm_builder.build_int_compare(IntPredicate::UGE, index, size, "")
+    else {
+        self::codegen_abort("Cannot generate bounds check.");
+        return;
+    };
+
+    let trap_block: BasicBlock = llvm_context.append_basic_block(function, "bounds.trap");
+    let continue_block: BasicBlock = llvm_context.append_basic_block(function, "bounds.continue");
+
+    let Ok(branch) =
+        llvm_builder.build_conditional_branch(out_of_bounds, trap_block, continue_block)
+    else {
+        self::codegen_abort("Cannot generate bounds check.");
+        return;
+    };
+
+    self::mark_unlikely(llvm_context, branch);
+
+    llvm_builder.position_at_end(trap_block);
+
+    self::compile_bounds_report(context, index, size, span);
+
+    llvm_builder.position_at_end(continue_block);
+}
+
+fn compile_bounds_report<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    index: IntValue<'ctx>,
+    size: IntValue<'ctx>,
+    span: Span,
+) {
+    let file: String = context
+        .get_diagnostician()
+        .get_source_map()
+        .get(span.get_file())
+        .map(|source| source.get_path().display().to_string())
+        .unwrap_or_else(|| String::from("<unknown>"));
+
+    let message: String = format!(
+        "thrush: index out of bounds at {}:{}: the index is %llu but the length is %llu.\n",
+        file.replace('%', "%%"),
+        span.get_line()
+    );
+
+    if runtime::compile_abort(context, &message, &[index.into(), size.into()]).is_err() {
+        self::codegen_abort("Cannot generate bounds check.");
+    }
+}
+
+fn extend_to_i64<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    value: IntValue<'ctx>,
+) -> IntValue<'ctx> {
+    let i64_type: IntType = context.get_llvm_context().i64_type();
+
+    if value.get_type().get_bit_width() >= 64 {
+        return value;
+    }
+
+    context
+        .get_llvm_builder()
+        .build_int_z_extend(value, i64_type, "")
+        .unwrap_or(value)
+}
+
+fn mark_unlikely(llvm_context: &Context, branch: InstructionValue) {
+    let weights: MetadataValue = llvm_context.metadata_node(&[
+        llvm_context.metadata_string("branch_weights").into(),
+        llvm_context.i32_type().const_int(1, false).into(),
+        llvm_context.i32_type().const_int(1 << 20, false).into(),
+    ]);
+
+    let _ = branch.set_metadata(weights, llvm_context.get_kind_id("prof"));
+}
+
+fn codegen_abort(message: &str) {
+    logging::log(LoggingType::BackendBug, message);
+}
@@ -10,14 +10,19 @@ pub mod constgen;
 pub mod context;
 pub mod control;
 pub mod conventions;
+pub mod coverage;
+pub mod debug;
 pub mod declarations;
 pub mod expressions;
 pub mod generation;
+pub mod harness;
 pub mod indexes;
 pub mod memory;
 pub mod optimizations;
 pub mod predicates;
 pub mod ptrgen;
+pub mod repl;
+pub mod runtime;
 pub mod statements;
 pub mod symbols;
 pub mod typegen;
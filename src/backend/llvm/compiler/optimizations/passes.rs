@@ -1,8 +1,16 @@
 use inkwell::{
-    OptimizationLevel, module::Module, passes::PassBuilderOptions, targets::TargetMachine,
+    OptimizationLevel,
+    attributes::{Attribute, AttributeLoc},
+    context::ContextRef,
+    module::Module,
+    passes::PassBuilderOptions,
+    targets::TargetMachine,
 };
 
-use crate::core::{compiler::passes::LLVMModificatorPasses, console::logging};
+use crate::core::{
+    compiler::passes::{LLVMModificatorPasses, LLVMSanitizer},
+    console::logging,
+};
 
 pub struct LLVMOptimizer<'a, 'ctx> {
     module: &'a Module<'ctx>,
@@ -10,6 +18,7 @@ pub struct LLVMOptimizer<'a, 'ctx> {
     opt_level: OptimizationLevel,
     custom_passes: &'ctx str,
     modicator_passes: &'ctx [LLVMModificatorPasses],
+    sanitizers: &'ctx [LLVMSanitizer],
 }
 
 impl<'a, 'ctx> LLVMOptimizer<'a, 'ctx> {
@@ -19,6 +28,7 @@ impl<'a, 'ctx> LLVMOptimizer<'a, 'ctx> {
         opt_level: OptimizationLevel,
         custom_passes: &'ctx str,
         modicator_passes: &'ctx [LLVMModificatorPasses],
+        sanitizers: &'ctx [LLVMSanitizer],
     ) -> Self {
         Self {
             module,
@@ -26,83 +36,71 @@ impl<'a, 'ctx> LLVMOptimizer<'a, 'ctx> {
             opt_level,
             custom_passes,
             modicator_passes,
+            sanitizers,
         }
     }
 
     pub fn optimize(&self) {
+        self.add_sanitizer_attributes();
+
+        let mut passes: Vec<&str> = Vec::with_capacity(5);
+
         if !self.custom_passes.is_empty() {
-            if let Err(error) = self.module.run_passes(
-                self.custom_passes,
-                self.target_machine,
-                self.create_passes_builder(),
-            ) {
-                logging::log(
-                    logging::LoggingType::Warning,
-                    &format!(
-                        "Some optimizations passes could not be performed because: '{:?}'.",
-                        error
-                    ),
-                );
-
-                return;
+            passes.push(self.custom_passes);
+        } else {
+            match self.opt_level {
+                OptimizationLevel::None => (),
+                OptimizationLevel::Default => passes.push("default<O1>"),
+                OptimizationLevel::Less => passes.push("default<O2>"),
+                OptimizationLevel::Aggressive => passes.push("default<O3>"),
             }
-
-            return;
         }
 
-        match self.opt_level {
-            OptimizationLevel::None => (),
-
-            OptimizationLevel::Default => {
-                if let Err(error) = self.module.run_passes(
-                    "default<O1>",
-                    self.target_machine,
-                    self.create_passes_builder(),
-                ) {
-                    logging::log(
-                        logging::LoggingType::Warning,
-                        &format!(
-                            "Some optimizations passes could not be performed because: '{:?}'.",
-                            error
-                        ),
-                    );
-                }
-            }
+        passes.extend(
+            self.sanitizers
+                .iter()
+                .filter_map(|sanitizer| sanitizer.get_pass()),
+        );
 
-            OptimizationLevel::Less => {
-                if let Err(error) = self.module.run_passes(
-                    "default<O2>",
-                    self.target_machine,
-                    self.create_passes_builder(),
-                ) {
-                    logging::log(
-                        logging::LoggingType::Warning,
-                        &format!(
-                            "Some optimizations passes could not be performed because: '{:?}'.",
-                            error
-                        ),
-                    );
-                }
-            }
+        if passes.is_empty() {
+            return;
+        }
 
-            OptimizationLevel::Aggressive => {
-                if let Err(error) = self.module.run_passes(
-                    "default<O3>",
-                    self.target_machine,
-                    self.create_passes_builder(),
-                ) {
-                    logging::log(
-                        logging::LoggingType::Warning,
-                        &format!(
-                            "Some optimizations passes could not be performed because: '{:?}'.",
-                            error
-                        ),
-                    );
-                }
-            }
+        if let Err(error) = self.module.run_passes(
+            &passes.join(","),
+            self.target_machine,
+            self.create_passes_builder(),
+        ) {
+            logging::log(
+                logging::LoggingType::Warning,
+                &format!(
+                    "Some optimizations passes could not be performed because: '{:?}'.",
+                    error
+                ),
+            );
         }
     }
 
+    /// The sanitizer passes only instrument the functions that carry their attribute.
+    fn add_sanitizer_attributes(&self) {
+        let context: ContextRef = self.module.get_context();
+
+        self.sanitizers
+            .iter()
+            .filter_map(|sanitizer| sanitizer.get_function_attribute())
+            .for_each(|attribute| {
+                let attribute: Attribute =
+                    context.create_enum_attribute(Attribute::get_named_enum_kind_id(attribute), 0);
+
+                self.module
+                    .get_functions()
+                    .filter(|function| function.count_basic_blocks() > 0)
+                    .for_each(|function| {
+                        function.add_attribute(AttributeLoc::Function, attribute);
+                    });
+            });
+    }
+
     fn create_passes_builder(&self) -> PassBuilderOptions {
         let passes_builder: PassBuilderOptions = PassBuilderOptions::create();
 
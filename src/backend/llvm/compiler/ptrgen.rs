@@ -12,6 +12,7 @@ use crate::backend::types::repr::LLVMFunction;
 use crate::backend::types::traits::AssemblerFunctionExtensions;
 use crate::core::console::logging::{self, LoggingType};
 
+use crate::frontend::lexer::span::Span;
 use crate::frontend::types::ast::Ast;
 use crate::frontend::types::ast::types::AstEitherExpression;
 use crate::frontend::types::parser::stmts::traits::ThrushAttributesExtensions;
@@ -74,8 +75,11 @@ pub fn compile<'ctx>(
 
         // Compiles an indexing operation (e.g., array access)
         Ast::Index {
-            source, indexes, ..
-        } => self::compile_index(context, source, indexes),
+            source,
+            indexes,
+            span,
+            ..
+        } => self::compile_index(context, source, indexes, *span),
 
         // Low-Level Operations
         Ast::Load { .. } | Ast::Address { .. } | Ast::Alloc { .. } => {
@@ -378,6 +382,7 @@ fn compile_index<'ctx>(
     context: &mut LLVMCodeGenContext<'_, 'ctx>,
     source: &'ctx LLVMEitherExpression<'ctx>,
     indexes: &'ctx [Ast],
+    span: Span,
 ) -> BasicValueEnum<'ctx> {
     let llvm_context: &Context = context.get_llvm_context();
     let llvm_builder: &Builder = context.get_llvm_builder();
@@ -387,7 +392,10 @@ fn compile_index<'ctx>(
             let symbol: SymbolAllocated = context.get_table().get_symbol(name);
             let symbol_type: &Type = symbol.get_type();
 
-            let ordered_indexes: Vec<IntValue> = indexes::compile(context, indexes, symbol_type);
+            let array_size: Option<IntValue> = indexes::compile_symbol_array_size(context, &symbol);
+
+            let ordered_indexes: Vec<IntValue> =
+                indexes::compile(context, indexes, symbol_type, array_size, span);
 
             symbol
                 .gep(llvm_context, llvm_builder, &ordered_indexes)
@@ -397,7 +405,11 @@ fn compile_index<'ctx>(
             let expr_ptr: PointerValue = ptrgen::compile(context, expr, None).into_pointer_value();
             let expr_type: &Type = expr.get_type_unwrapped();
 
-            let ordered_indexes: Vec<IntValue> = indexes::compile(context, indexes, expr_type);
+            let array_size: Option<IntValue> =
+                indexes::compile_anon_array_size(context, expr_ptr, expr_type);
+
+            let ordered_indexes: Vec<IntValue> =
+                indexes::compile(context, indexes, expr_type, array_size, span);
 
             memory::gep_anon(context, expr_ptr, expr_type, &ordered_indexes).into()
         }
@@ -0,0 +1,50 @@
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+/// What the earlier snippets of a 'thrushc repl' session left in the JIT.
+///
+/// Every snippet is compiled into its own module, so the functions, statics and
+/// locals that an earlier module defined are only declared again.
+#[derive(Debug)]
+pub struct ReplSymbols {
+    entry: String,
+    defined: HashSet<String>,
+    bindings: HashMap<String, String>,
+}
+
+impl ReplSymbols {
+    pub fn new(entry: String, defined: HashSet<String>, bindings: HashMap<String, String>) -> Self {
+        Self {
+            entry,
+            defined,
+            bindings,
+        }
+    }
+}
+
+impl ReplSymbols {
+    /// Whether an earlier module of the session already defines the symbol.
+    #[inline]
+    pub fn is_defined(&self, symbol: &str) -> bool {
+        self.defined.contains(symbol)
+    }
+
+    #[inline]
+    pub fn is_entry(&self, name: &str) -> bool {
+        self.entry == name
+    }
+
+    /// The global that holds a local of an earlier snippet.
+    #[inline]
+    pub fn get_binding(&self, ascii_name: &str) -> Option<&str> {
+        self.bindings.get(ascii_name).map(String::as_str)
+    }
+
+    /// The global that holds a local of the snippet being compiled.
+    pub fn get_binding_symbol(&self, ascii_name: &str) -> String {
+        self::get_binding_symbol(&self.entry, ascii_name)
+    }
+}
+
+pub fn get_binding_symbol(entry: &str, ascii_name: &str) -> String {
+    format!("{}.{}", entry, ascii_name)
+}
@@ -0,0 +1,77 @@
+use inkwell::{
+    AddressSpace,
+    builder::{Builder, BuilderError},
+    context::Context,
+    module::{Linkage, Module},
+    types::{FunctionType, PointerType},
+    values::{BasicMetadataValueEnum, FunctionValue, PointerValue},
+};
+
+use crate::backend::llvm::compiler::context::LLVMCodeGenContext;
+
+/// Prints the message of a failed runtime check and aborts. The message is a 'printf'
+/// format of the arguments.
+pub fn compile_abort<'ctx>(
+    context: &LLVMCodeGenContext<'_, 'ctx>,
+    message: &str,
+    args: &[BasicMetadataValueEnum<'ctx>],
+) -> Result<(), BuilderError> {
+    let llvm_module: &Module = context.get_llvm_module();
+    let llvm_context: &Context = context.get_llvm_context();
+    let llvm_builder: &Builder = context.get_llvm_builder();
+
+    let ptr_type: PointerType = llvm_context.ptr_type(AddressSpace::default());
+
+    let printf: Option<FunctionValue> = self::get_or_declare(
+        llvm_module,
+        "printf",
+        llvm_context.i32_type().fn_type(&[ptr_type.into()], true),
+    );
+
+    let fflush: Option<FunctionValue> = self::get_or_declare(
+        llvm_module,
+        "fflush",
+        llvm_context.i32_type().fn_type(&[ptr_type.into()], false),
+    );
+
+    if let (Some(printf), Some(fflush)) = (printf, fflush) {
+        let message: PointerValue = llvm_builder
+            .build_global_string_ptr(message, "")?
+            .as_pointer_value();
+
+        let printf_args: Vec<BasicMetadataValueEnum> = std::iter::once(message.into())
+            .chain(args.iter().copied())
+            .collect();
+
+        llvm_builder.build_call(printf, &printf_args, "")?;
+        llvm_builder.build_call(fflush, &[ptr_type.const_null().into()], "")?;
+    }
+
+    let abort: Option<FunctionValue> = self::get_or_declare(
+        llvm_module,
+        "abort",
+        llvm_context.void_type().fn_type(&[], false),
+    );
+
+    if let Some(abort) = abort {
+        llvm_builder.build_call(abort, &[], "")?;
+    }
+
+    llvm_builder.build_unreachable()?;
+
+    Ok(())
+}
+
+/// Reuses a libc function already declared by the program, as long as its
+/// signature matches the one the generated runtime code needs.
+pub fn get_or_declare<'ctx>(
+    llvm_module: &Module<'ctx>,
+    name: &str,
+    kind: FunctionType<'ctx>,
+) -> Option<FunctionValue<'ctx>> {
+    match llvm_module.get_function(name) {
+        Some(function) if function.get_type() == kind => Some(function),
+        Some(_) => None,
+        None => Some(llvm_module.add_function(name, kind, Some(Linkage::External))),
+    }
+}
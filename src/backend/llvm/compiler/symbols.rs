@@ -152,6 +152,10 @@ impl SymbolsTable<'_> {
             self.parameters.clear();
         }
     }
+
+    pub fn get_scope(&self) -> usize {
+        self.scope
+    }
 }
 
 fn codegen_abort<T: Display>(message: T) {
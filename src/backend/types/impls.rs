@@ -34,6 +34,8 @@ impl Display for LLVMAttribute<'_> {
             LLVMAttribute::AsmSyntax(..) => write!(f, "@asmsyntax"),
             LLVMAttribute::AsmSideEffects(..) => write!(f, "@asmeffects"),
             LLVMAttribute::AsmAlignStack(..) => write!(f, "@asmalingstack"),
+            LLVMAttribute::Allow(lint, ..) => write!(f, "@allow({})", lint),
+            LLVMAttribute::Test(..) => write!(f, "@test"),
         }
     }
 }
@@ -1,19 +1,25 @@
 use inkwell::targets::{CodeModel, RelocMode, TargetMachine, TargetTriple};
 
 use crate::core::compiler::{
-    jit::JITConfiguration, linking::LinkingCompilersConfiguration, options::ThrushOptimization,
-    passes::LLVMModificatorPasses,
+    jit::JITConfiguration,
+    linking::LinkingCompilersConfiguration,
+    options::ThrushOptimization,
+    passes::{LLVMModificatorPasses, LLVMSanitizer},
 };
 
 #[derive(Debug)]
 pub struct LLVMBackend {
     target_cpu: String,
-    target_triple: TargetTriple,
+    target_triple: String,
     optimization: ThrushOptimization,
     reloc_mode: RelocMode,
     code_model: CodeModel,
     modificator_passes: Vec<LLVMModificatorPasses>,
     opt_passes: String,
+    debug_info: bool,
+    sanitizers: Vec<LLVMSanitizer>,
+    bounds_checks: Option<bool>,
+    coverage: bool,
     linking_compilers_config: LinkingCompilersConfiguration,
     jit_config: Option<JITConfiguration>,
 }
@@ -22,12 +28,19 @@ impl LLVMBackend {
     pub fn new() -> Self {
         Self {
             target_cpu: String::with_capacity(100),
-            target_triple: TargetMachine::get_default_triple(),
+            target_triple: TargetMachine::get_default_triple()
+                .as_str()
+                .to_string_lossy()
+                .into_owned(),
             optimization: ThrushOptimization::None,
             reloc_mode: RelocMode::PIC,
             code_model: CodeModel::Default,
             modificator_passes: Vec::with_capacity(10),
             opt_passes: String::with_capacity(100),
+            debug_info: false,
+            sanitizers: Vec::with_capacity(2),
+            bounds_checks: None,
+            coverage: false,
             linking_compilers_config: LinkingCompilersConfiguration::new(),
             jit_config: None,
         }
@@ -41,8 +54,14 @@ impl LLVMBackend {
         self.code_model
     }
 
-    pub fn get_target_triple(&self) -> &TargetTriple {
-        &self.target_triple
+    pub fn get_target_triple(&self) -> &str {
+        self.target_triple.as_str()
+    }
+
+    /// The triple is kept as a string, so the options can be shared by the threads of a
+    /// parallel build; each of them creates its own LLVM triple.
+    pub fn create_target_triple(&self) -> TargetTriple {
+        TargetTriple::create(&self.target_triple)
     }
 
     pub fn get_optimization(&self) -> ThrushOptimization {
@@ -57,6 +76,42 @@ impl LLVMBackend {
         self.opt_passes.as_str()
     }
 
+    pub fn get_debug_info(&self) -> bool {
+        self.debug_info
+    }
+
+    pub fn get_sanitizers(&self) -> &[LLVMSanitizer] {
+        &self.sanitizers
+    }
+
+    /// Bounds checks are on by default when the program isn't optimized.
+    pub fn get_bounds_checks(&self) -> bool {
+        self.bounds_checks
+            .unwrap_or(self.optimization.is_none_opt())
+    }
+
+    pub fn get_coverage(&self) -> bool {
+        self.coverage
+    }
+
+    /// Every setting that changes the generated objects.
+    pub fn get_codegen_fingerprint(&self) -> String {
+        format!(
+            "{}|{}|{:?}|{:?}|{:?}|{:?}|{}|{}|{:?}|{}|{}",
+            self.target_triple,
+            self.target_cpu,
+            self.optimization,
+            self.reloc_mode,
+            self.code_model,
+            self.modificator_passes,
+            self.opt_passes,
+            self.debug_info,
+            self.sanitizers,
+            self.get_bounds_checks(),
+            self.coverage
+        )
+    }
+
     pub fn get_modificator_passes(&self) -> &[LLVMModificatorPasses] {
         &self.modificator_passes
     }
@@ -93,7 +148,7 @@ impl LLVMBackend {
         self.code_model = code_model;
     }
 
-    pub fn set_target_triple(&mut self, target_triple: TargetTriple) {
+    pub fn set_target_triple(&mut self, target_triple: String) {
         self.target_triple = target_triple;
     }
 
@@ -109,6 +164,24 @@ impl LLVMBackend {
         self.modificator_passes = modificator_passes;
     }
 
+    pub fn set_debug_info(&mut self, debug_info: bool) {
+        self.debug_info = debug_info;
+    }
+
+    pub fn add_sanitizer(&mut self, sanitizer: LLVMSanitizer) {
+        if !self.sanitizers.contains(&sanitizer) {
+            self.sanitizers.push(sanitizer);
+        }
+    }
+
+    pub fn set_bounds_checks(&mut self, bounds_checks: bool) {
+        self.bounds_checks = Some(bounds_checks);
+    }
+
+    pub fn set_coverage(&mut self, coverage: bool) {
+        self.coverage = coverage;
+    }
+
     pub fn set_jit_config(&mut self, jit: JITConfiguration) {
         self.jit_config = Some(jit);
     }
@@ -177,3 +177,81 @@ pub const LLVM_X86_64_SUPPORTED_CPUS: [&str; 128] = [
 
 
 ########################################################################*/
+
+/* ######################################################################
+
+
+    COVERAGE | CONSTANTS - START
+
+
+########################################################################*/
+
+pub const COVERAGE_DEFAULT_FILE: &str = "thrush.coverage";
+pub const COVERAGE_FILE_ENV: &str = "THRUSH_COVERAGE_FILE";
+
+/* ######################################################################
+
+
+    COVERAGE | CONSTANTS - END
+
+
+########################################################################*/
+
+/* ######################################################################
+
+
+    PROJECT | CONSTANTS - START
+
+
+########################################################################*/
+
+pub const PROJECT_MANIFEST_FILE: &str = "thrush.toml";
+pub const PROJECT_DEFAULT_BUILD_DIR: &str = "build";
+pub const PROJECT_DEFAULT_PROFILE: &str = "debug";
+pub const PROJECT_TESTS_OUTPUT: &str = "thrush-tests";
+pub const PROJECT_DOC_OUTPUT: &str = "doc";
+
+/* ######################################################################
+
+
+    PROJECT | CONSTANTS - END
+
+
+########################################################################*/
+
+/* ######################################################################
+
+
+    INCREMENTAL | CONSTANTS - START
+
+
+########################################################################*/
+
+pub const INCREMENTAL_CACHE_DIR: &str = "incremental";
+
+/* ######################################################################
+
+
+    INCREMENTAL | CONSTANTS - END
+
+
+########################################################################*/
+
+/* ######################################################################
+
+
+    REPL | CONSTANTS - START
+
+
+########################################################################*/
+
+pub const REPL_SOURCE_NAME: &str = "<repl>";
+pub const REPL_ENTRY_PREFIX: &str = "__thrush_repl_";
+
+/* ######################################################################
+
+
+    REPL | CONSTANTS - END
+
+
+########################################################################*/
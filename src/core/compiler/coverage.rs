@@ -0,0 +1,140 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use ahash::AHashMap as HashMap;
+use colored::Colorize;
+
+use crate::core::console::logging;
+
+/// Prints the line coverage of every '.thrush' file found in a coverage file.
+///
+/// Each record holds the lines of a region and the times it ran. Records of the same
+/// region are summed, since every run of the program appends its own. A line shared
+/// by several regions takes the highest count.
+pub fn report(coverage_file: &Path) -> Result<(), String> {
+    let data: String = std::fs::read_to_string(coverage_file).map_err(|_| {
+        format!(
+            "Coverage file '{}' cannot be read. Run a program compiled with '-fcoverage' first.",
+            coverage_file.display()
+        )
+    })?;
+
+    let mut regions: HashMap<(PathBuf, &str), u64> = HashMap::with_capacity(100);
+
+    for (idx, record) in data.lines().enumerate() {
+        let mut parts = record.rsplitn(3, ':');
+
+        let (Some(count), Some(lines), Some(path)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(format!(
+                "Malformed coverage record at '{}:{}'.",
+                coverage_file.display(),
+                idx + 1
+            ));
+        };
+
+        let Ok(count) = count.parse::<u64>() else {
+            return Err(format!(
+                "Malformed coverage count at '{}:{}'.",
+                coverage_file.display(),
+                idx + 1
+            ));
+        };
+
+        *regions.entry((PathBuf::from(path), lines)).or_insert(0) += count;
+    }
+
+    let mut files: BTreeMap<PathBuf, BTreeMap<usize, u64>> = BTreeMap::new();
+
+    regions.iter().for_each(|((path, lines), count)| {
+        let file: &mut BTreeMap<usize, u64> = files.entry(path.clone()).or_default();
+
+        lines
+            .split(',')
+            .filter_map(|line| line.parse::<usize>().ok())
+            .for_each(|line| {
+                let line_count: &mut u64 = file.entry(line).or_insert(0);
+                *line_count = (*line_count).max(*count);
+            });
+    });
+
+    let mut total_lines: usize = 0;
+    let mut total_covered: usize = 0;
+
+    files.iter().for_each(|(path, lines)| {
+        let covered: usize = lines.values().filter(|count| **count > 0).count();
+
+        total_lines += lines.len();
+        total_covered += covered;
+
+        self::print_file(path, lines, covered);
+    });
+
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "{} {}\n",
+            "Total".custom_color((141, 141, 142)).bold(),
+            self::format_ratio(total_covered, total_lines)
+        ),
+    );
+
+    Ok(())
+}
+
+fn print_file(path: &Path, lines: &BTreeMap<usize, u64>, covered: usize) {
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "\n{} {}\n\n",
+            path.display().to_string().bold(),
+            self::format_ratio(covered, lines.len())
+        ),
+    );
+
+    let Ok(code) = std::fs::read_to_string(path) else {
+        logging::write(
+            logging::OutputIn::Stdout,
+            &format!(
+                "{}\n\n",
+                "The source file is no longer available."
+                    .custom_color((141, 141, 142))
+                    .bold()
+            ),
+        );
+
+        return;
+    };
+
+    code.lines().enumerate().for_each(|(idx, text)| {
+        let count: String = match lines.get(&(idx + 1)) {
+            Some(0) => format!("{:>10}", "#####").bright_red().bold().to_string(),
+            Some(count) => format!("{:>10}", count).bright_green().to_string(),
+            None => format!("{:>10}", "-")
+                .custom_color((141, 141, 142))
+                .to_string(),
+        };
+
+        logging::write(
+            logging::OutputIn::Stdout,
+            &format!(
+                "{} | {} | {}\n",
+                count,
+                format!("{:>5}", idx + 1).custom_color((141, 141, 142)),
+                text
+            ),
+        );
+    });
+}
+
+fn format_ratio(covered: usize, total: usize) -> String {
+    let percentage: f64 = if total == 0 {
+        100.0
+    } else {
+        covered as f64 * 100.0 / total as f64
+    };
+
+    format!("{}/{} lines ({:.2}%)", covered, total, percentage)
+}
@@ -0,0 +1,359 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    backend::llvm::compiler::attributes::LLVMAttribute,
+    core::{
+        compiler::{options::CompilerOptions, thrushc::TheThrushCompiler},
+        utils::rand,
+    },
+    frontend::{
+        parser::attributes::CALL_CONVENTIONS,
+        types::{
+            ast::Ast,
+            parser::stmts::{
+                traits::ThrushAttributesExtensions,
+                types::{EnumFields, StructFields, ThrushAttributes},
+            },
+        },
+        typesystem::types::Type,
+    },
+};
+
+pub fn emit_c_header(
+    compiler: &TheThrushCompiler,
+    ast: &[Ast],
+    build_dir: &Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let compiler_options: &CompilerOptions = compiler.get_options();
+    let obfuscate: bool = compiler_options.ofuscate_archive_names();
+
+    let headers_base_path: PathBuf = build_dir.join("emit").join("c-header");
+
+    if !headers_base_path.exists() {
+        let _ = std::fs::create_dir_all(&headers_base_path);
+    }
+
+    let header_stem: String = Path::new(file_name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_name.to_string());
+
+    let header_file_name: String = if obfuscate {
+        format!("{}_{}.h", rand::generate_random_string(), header_stem)
+    } else {
+        format!("{}.h", header_stem)
+    };
+
+    let header_file_path: PathBuf = headers_base_path.join(header_file_name);
+
+    std::fs::write(header_file_path, self::generate_c_header(ast, &header_stem))
+}
+
+/// Builds a C header with the declarations other languages can link against. Structs and
+/// enums are always emitted, since public signatures may depend on them.
+fn generate_c_header(ast: &[Ast], header_stem: &str) -> String {
+    let guard: String = format!(
+        "THRUSH_{}_H",
+        header_stem
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>()
+    );
+
+    let mut header: String = String::with_capacity(4096);
+
+    header.push_str(&format!(
+        "/* Generated by thrushc {} from '{}'. Do not edit. */\n\n",
+        env!("CARGO_PKG_VERSION"),
+        header_stem
+    ));
+
+    header.push_str(&format!("#ifndef {}\n#define {}\n\n", guard, guard));
+    header.push_str("#include <stdbool.h>\n#include <stdint.h>\n\n");
+    header.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n");
+
+    header.push_str("#ifndef THRUSH_FAT_POINTERS\n#define THRUSH_FAT_POINTERS\n");
+    header.push_str("typedef struct { const char *ptr; uint32_t size; } thrush_str;\n");
+    header.push_str("typedef struct { void *ptr; uint32_t size; } thrush_array;\n");
+    header.push_str("#endif\n\n");
+
+    ast.iter().for_each(|node| match node {
+        Ast::Struct { name, fields, .. } => {
+            header.push_str(&self::generate_struct(name, fields));
+        }
+
+        Ast::Enum { name, fields, .. } => {
+            header.push_str(&self::generate_enum(name, fields));
+        }
+
+        Ast::Const {
+            ascii_name,
+            kind,
+            attributes,
+            ..
+        } if attributes.has_public_attribute() => {
+            header.push_str(&format!(
+                "extern const {};\n\n",
+                self::generate_declarator(kind, ascii_name)
+            ));
+        }
+
+        Ast::Function {
+            ascii_name,
+            parameters,
+            body,
+            return_type,
+            attributes,
+            ..
+        } if attributes.has_public_attribute() && !body.is_null() => {
+            let parameters: Vec<String> = parameters
+                .iter()
+                .filter_map(|parameter| match parameter {
+                    Ast::FunctionParameter {
+                        ascii_name, kind, ..
+                    } => Some(self::generate_declarator(kind, ascii_name)),
+                    _ => None,
+                })
+                .collect();
+
+            header.push_str(&self::generate_function(
+                ascii_name,
+                &parameters,
+                return_type,
+                attributes,
+            ));
+        }
+
+        Ast::AssemblerFunction {
+            ascii_name,
+            parameters,
+            return_type,
+            attributes,
+            ..
+        } if attributes.has_public_attribute() => {
+            let parameters: Vec<String> = parameters
+                .iter()
+                .filter_map(|parameter| match parameter {
+                    Ast::AssemblerFunctionParameter { name, kind, .. } => {
+                        Some(self::generate_declarator(kind, name))
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            header.push_str(&self::generate_function(
+                ascii_name,
+                &parameters,
+                return_type,
+                attributes,
+            ));
+        }
+
+        _ => (),
+    });
+
+    header.push_str("#ifdef __cplusplus\n}\n#endif\n\n");
+    header.push_str(&format!("#endif /* {} */\n", guard));
+
+    header
+}
+
+fn generate_struct(name: &str, fields: &StructFields) -> String {
+    let mut structure: String = format!("typedef struct {} {{\n", name);
+
+    fields.1.iter().for_each(|field| {
+        structure.push_str(&format!(
+            "    {};\n",
+            self::generate_declarator(&field.1, field.0)
+        ));
+    });
+
+    structure.push_str(&format!("}} {};\n\n", name));
+
+    structure
+}
+
+/// Integer enums map to a C 'enum'. Any other field type falls back to '#define' constants.
+fn generate_enum(name: &str, fields: &EnumFields) -> String {
+    let values: Vec<(String, Option<String>)> = fields
+        .iter()
+        .map(|(field_name, value)| {
+            (
+                format!("{}_{}", name, field_name),
+                self::generate_literal(value),
+            )
+        })
+        .collect();
+
+    let is_integer_enum: bool = fields.iter().all(|(_, value)| {
+        value
+            .get_value_type()
+            .is_ok_and(|kind| kind.is_integer_type())
+    }) && values.iter().all(|(_, value)| value.is_some());
+
+    if is_integer_enum {
+        let mut enumeration: String = format!("typedef enum {} {{\n", name);
+
+        values.iter().for_each(|(field_name, value)| {
+            if let Some(value) = value {
+                enumeration.push_str(&format!("    {} = {},\n", field_name, value));
+            }
+        });
+
+        enumeration.push_str(&format!("}} {};\n\n", name));
+
+        return enumeration;
+    }
+
+    let mut constants: String = String::with_capacity(256);
+
+    values.iter().for_each(|(field_name, value)| match value {
+        Some(value) => constants.push_str(&format!("#define {} {}\n", field_name, value)),
+        None => constants.push_str(&format!(
+            "/* {} is not a literal and has no C equivalent. */\n",
+            field_name
+        )),
+    });
+
+    constants.push('\n');
+
+    constants
+}
+
+fn generate_function(
+    ascii_name: &str,
+    parameters: &[String],
+    return_type: &Type,
+    attributes: &ThrushAttributes,
+) -> String {
+    let mut function: String = String::with_capacity(128);
+
+    let mut symbol_name: &str = ascii_name;
+
+    attributes.iter().for_each(|attribute| match attribute {
+        LLVMAttribute::Extern(extern_name, ..) => {
+            symbol_name = extern_name;
+        }
+
+        LLVMAttribute::Convention(convention, ..) => {
+            if let Some((name, _)) = CALL_CONVENTIONS
+                .iter()
+                .find(|(name, conv)| **conv as u32 == *convention as u32 && **name != b"C")
+            {
+                function.push_str(&format!(
+                    "/* @convention(\"{}\") */\n",
+                    String::from_utf8_lossy(name)
+                ));
+            }
+        }
+
+        _ => (),
+    });
+
+    let mut parameters: Vec<String> = parameters.to_vec();
+
+    if attributes.has_ignore_attribute() {
+        parameters.push(String::from("..."));
+    }
+
+    if parameters.is_empty() {
+        parameters.push(String::from("void"));
+    }
+
+    function.push_str(&format!(
+        "{} {}({});\n\n",
+        self::generate_type(return_type),
+        symbol_name,
+        parameters.join(", ")
+    ));
+
+    function
+}
+
+fn generate_declarator(kind: &Type, name: &str) -> String {
+    match kind {
+        Type::FixedArray(inner, size) => {
+            format!("{}[{}]", self::generate_declarator(inner, name), size)
+        }
+
+        Type::Const(inner) => format!("const {}", self::generate_declarator(inner, name)),
+
+        // The pointer binds tighter than the array size: 'uint32_t (*name)[4]'.
+        Type::Mut(inner) | Type::Ptr(Some(inner)) if self::points_to_fixed_array(inner) => {
+            let name: String = if matches!(self::strip_const(inner), Type::FixedArray(..)) {
+                format!("(*{})", name)
+            } else {
+                format!("*{}", name)
+            };
+
+            self::generate_declarator(inner, &name)
+        }
+
+        _ => format!("{} {}", self::generate_type(kind), name),
+    }
+}
+
+fn points_to_fixed_array(kind: &Type) -> bool {
+    match kind {
+        Type::FixedArray(..) => true,
+        Type::Const(inner) | Type::Mut(inner) | Type::Ptr(Some(inner)) => {
+            self::points_to_fixed_array(inner)
+        }
+        _ => false,
+    }
+}
+
+fn strip_const(kind: &Type) -> &Type {
+    match kind {
+        Type::Const(inner) => self::strip_const(inner),
+        _ => kind,
+    }
+}
+
+fn generate_type(kind: &Type) -> String {
+    match kind {
+        Type::S8 => String::from("int8_t"),
+        Type::S16 => String::from("int16_t"),
+        Type::S32 => String::from("int32_t"),
+        Type::S64 => String::from("int64_t"),
+        Type::U8 => String::from("uint8_t"),
+        Type::U16 => String::from("uint16_t"),
+        Type::U32 => String::from("uint32_t"),
+        Type::U64 => String::from("uint64_t"),
+        Type::F32 => String::from("float"),
+        Type::F64 => String::from("double"),
+        Type::Bool => String::from("bool"),
+        Type::Char => String::from("char"),
+        Type::Str => String::from("thrush_str"),
+        Type::Array(..) => String::from("thrush_array"),
+        Type::Struct(name, ..) => name.clone(),
+        Type::Const(inner) => format!("const {}", self::generate_type(inner)),
+        // Without a name to declare, a pointer to a fixed array has no plain C spelling.
+        Type::Mut(inner) | Type::Ptr(Some(inner)) if self::points_to_fixed_array(inner) => {
+            String::from("void * /* pointer to a fixed array */")
+        }
+        Type::Mut(inner) | Type::Ptr(Some(inner)) | Type::FixedArray(inner, ..) => {
+            format!("{} *", self::generate_type(inner))
+        }
+        Type::Ptr(None) | Type::Addr => String::from("void *"),
+        Type::Void => String::from("void"),
+    }
+}
+
+fn generate_literal(value: &Ast) -> Option<String> {
+    match value {
+        Ast::Integer { value, signed, .. } if *signed => Some(format!("-{}", value)),
+        Ast::Integer { value, .. } => Some(value.to_string()),
+        Ast::Float { value, signed, .. } if *signed => Some(format!("-{:?}", value)),
+        Ast::Float { value, .. } => Some(format!("{:?}", value)),
+        Ast::Boolean { value, .. } | Ast::Char { byte: value, .. } => Some(value.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,176 @@
+use std::path::Path;
+
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+use crate::{
+    core::compiler::{emitters::dot, thrushc::TheThrushCompiler},
+    frontend::types::{
+        ast::Ast,
+        parser::stmts::{traits::ThrushAttributesExtensions, types::ThrushAttributes},
+    },
+};
+
+/// A function of the call graph and the functions its body calls, in call order.
+struct CallGraphNode<'ctx> {
+    name: &'ctx str,
+    is_extern: bool,
+    is_declaration: bool,
+    calls: Vec<&'ctx str>,
+}
+
+/// Writes the call graph of a file from its 'Ast::Call' nodes.
+///
+/// '@extern' functions and declarations without a body are dashed, and the functions
+/// that can reach themselves are red together with the calls that close the cycle.
+pub fn emit_callgraph_dot(
+    compiler: &TheThrushCompiler,
+    ast: &[Ast],
+    build_dir: &Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let nodes: Vec<CallGraphNode> = ast.iter().filter_map(self::new_node).collect();
+
+    let calls: HashMap<&str, &[&str]> = nodes
+        .iter()
+        .map(|node| (node.name, node.calls.as_slice()))
+        .collect();
+
+    let mut graph: String = format!(
+        "digraph \"{}\" {{\n    node [shape=box, fontname=\"monospace\"];\n",
+        dot::escape(file_name)
+    );
+
+    nodes.iter().for_each(|node| {
+        let mut style: Vec<&str> = Vec::with_capacity(2);
+
+        if node.is_extern || node.is_declaration {
+            style.push("style=dashed");
+        }
+
+        if self::reaches(&calls, node.name, node.name) {
+            style.push("color=red");
+        }
+
+        let label: String = if node.is_extern {
+            format!("{}\n@extern", node.name)
+        } else {
+            node.name.to_string()
+        };
+
+        graph.push_str(&format!(
+            "    \"{}\" [label=\"{}\"{}{}];\n",
+            dot::escape(node.name),
+            dot::escape(&label),
+            if style.is_empty() { "" } else { ", " },
+            style.join(", ")
+        ));
+    });
+
+    nodes.iter().for_each(|node| {
+        let mut seen: HashSet<&str> = HashSet::with_capacity(node.calls.len());
+
+        node.calls
+            .iter()
+            .filter(|callee| seen.insert(callee))
+            .for_each(|callee| {
+                let count: usize = node.calls.iter().filter(|call| call == &callee).count();
+                let is_recursive: bool = self::reaches(&calls, callee, node.name);
+
+                let mut attributes: Vec<String> = Vec::with_capacity(2);
+
+                if count > 1 {
+                    attributes.push(format!("label=\"{}\"", count));
+                }
+
+                if is_recursive {
+                    attributes.push(String::from("color=red"));
+                }
+
+                graph.push_str(&format!(
+                    "    \"{}\" -> \"{}\"{};\n",
+                    dot::escape(node.name),
+                    dot::escape(callee),
+                    if attributes.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" [{}]", attributes.join(", "))
+                    }
+                ));
+            });
+    });
+
+    graph.push_str("}\n");
+
+    dot::write_graph(compiler, &graph, build_dir, "callgraph-dot", file_name)
+}
+
+fn new_node<'ctx>(ast: &'ctx Ast<'ctx>) -> Option<CallGraphNode<'ctx>> {
+    let (name, attributes, body): (&str, Option<&ThrushAttributes>, Option<&Ast>) = match ast {
+        Ast::Function {
+            name,
+            attributes,
+            body,
+            ..
+        } => (
+            name,
+            Some(attributes),
+            if body.is_null() { None } else { Some(body) },
+        ),
+
+        Ast::AssemblerFunction {
+            name, attributes, ..
+        } => (name, Some(attributes), None),
+
+        Ast::EntryPoint { body, .. } => ("main", None, Some(body)),
+
+        _ => return None,
+    };
+
+    let mut calls: Vec<&str> = Vec::with_capacity(10);
+
+    if let Some(body) = body {
+        self::collect_calls(body, &mut calls);
+    }
+
+    Some(CallGraphNode {
+        name,
+        is_extern: attributes.is_some_and(|attributes| attributes.has_extern_attribute()),
+        is_declaration: body.is_none() && !matches!(ast, Ast::AssemblerFunction { .. }),
+        calls,
+    })
+}
+
+fn collect_calls<'ctx>(ast: &'ctx Ast<'ctx>, calls: &mut Vec<&'ctx str>) {
+    if let Ast::Call { name, .. } = ast {
+        calls.push(name);
+    }
+
+    ast.get_children()
+        .into_iter()
+        .for_each(|child| self::collect_calls(child, calls));
+}
+
+/// Whether 'to' can be called, directly or not, from 'from'.
+fn reaches(calls: &HashMap<&str, &[&str]>, from: &str, to: &str) -> bool {
+    let mut visited: HashSet<&str> = HashSet::with_capacity(calls.len());
+    let mut pending: Vec<&str> = calls
+        .get(from)
+        .map_or_else(Vec::new, |callees| callees.to_vec());
+
+    while let Some(callee) = pending.pop() {
+        if callee == to {
+            return true;
+        }
+
+        if visited.insert(callee) {
+            pending.extend(
+                calls
+                    .get(callee)
+                    .into_iter()
+                    .flat_map(|callees| callees.iter()),
+            );
+        }
+    }
+
+    false
+}
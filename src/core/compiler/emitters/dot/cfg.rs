@@ -0,0 +1,150 @@
+use std::path::Path;
+
+use inkwell::{
+    basic_block::BasicBlock,
+    module::Module,
+    values::{AsValueRef, FunctionValue, InstructionOpcode, InstructionValue},
+};
+
+use crate::core::compiler::{emitters::dot, thrushc::TheThrushCompiler};
+
+/// Writes the control-flow graph of every function of the module, one file per function.
+///
+/// Every block is labeled with the source range of its instructions, taken from their
+/// debug locations, so the module must be generated with debug info.
+pub fn emit_cfg_dot(
+    compiler: &TheThrushCompiler,
+    llvm_module: &Module,
+    build_dir: &Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    llvm_module
+        .get_functions()
+        .filter(|function| function.count_basic_blocks() > 0)
+        .try_for_each(|function| {
+            let function_name: String = function.get_name().to_string_lossy().to_string();
+
+            dot::write_graph(
+                compiler,
+                &self::generate_cfg(function, &function_name),
+                build_dir,
+                "cfg-dot",
+                &format!("{}.{}", file_name, function_name),
+            )
+        })
+}
+
+fn generate_cfg(function: FunctionValue, function_name: &str) -> String {
+    let mut graph: String = format!(
+        "digraph \"{}\" {{\n    node [shape=box, fontname=\"monospace\"];\n",
+        dot::escape(function_name)
+    );
+
+    let blocks: Vec<BasicBlock> = function.get_basic_blocks();
+
+    blocks.iter().for_each(|block| {
+        let instructions: Vec<InstructionValue> = self::get_instructions(*block);
+
+        let mut label: String = format!("{}:\n", self::get_block_name(*block));
+
+        if let Some((start, end)) = self::get_source_range(&instructions) {
+            label.push_str(&format!(
+                "source {}:{} - {}:{}\n",
+                start.0, start.1, end.0, end.1
+            ));
+        }
+
+        label.push_str(&format!("{} instructions\n", instructions.len()));
+
+        if let Some(terminator) = block.get_terminator() {
+            label.push_str(&format!("{:?}\n", terminator.get_opcode()));
+        }
+
+        graph.push_str(&format!(
+            "    \"{}\" [label=\"{}\"];\n",
+            dot::escape(&self::get_block_name(*block)),
+            dot::escape(&label)
+        ));
+    });
+
+    blocks.iter().for_each(|block| {
+        let Some(terminator) = block.get_terminator() else {
+            return;
+        };
+
+        self::get_successors(terminator)
+            .into_iter()
+            .for_each(|(successor, edge_label)| {
+                graph.push_str(&format!(
+                    "    \"{}\" -> \"{}\"{};\n",
+                    dot::escape(&self::get_block_name(*block)),
+                    dot::escape(&self::get_block_name(successor)),
+                    edge_label.map_or_else(String::new, |label| format!(" [label=\"{}\"]", label))
+                ));
+            });
+    });
+
+    graph.push_str("}\n");
+
+    graph
+}
+
+fn get_block_name(block: BasicBlock) -> String {
+    block.get_name().to_string_lossy().to_string()
+}
+
+fn get_instructions(block: BasicBlock) -> Vec<InstructionValue> {
+    let mut instructions: Vec<InstructionValue> = Vec::with_capacity(16);
+    let mut instruction: Option<InstructionValue> = block.get_first_instruction();
+
+    while let Some(current) = instruction {
+        instructions.push(current);
+        instruction = current.get_next_instruction();
+    }
+
+    instructions
+}
+
+/// The first and last '(line, column)' of the instructions that have a debug location.
+fn get_source_range(instructions: &[InstructionValue]) -> Option<((u32, u32), (u32, u32))> {
+    let locations: Vec<(u32, u32)> = instructions
+        .iter()
+        .map(|instruction| unsafe {
+            (
+                llvm_sys::core::LLVMGetDebugLocLine(instruction.as_value_ref()),
+                llvm_sys::core::LLVMGetDebugLocColumn(instruction.as_value_ref()),
+            )
+        })
+        .filter(|(line, _)| *line > 0)
+        .collect();
+
+    Some((*locations.iter().min()?, *locations.iter().max()?))
+}
+
+/// The blocks a terminator can jump to, with the branch each one is taken on.
+fn get_successors(terminator: InstructionValue) -> Vec<(BasicBlock, Option<&'static str>)> {
+    let successors: Vec<BasicBlock> = (0..terminator.get_num_operands())
+        .filter_map(|index| terminator.get_operand(index)?.right())
+        .collect();
+
+    match terminator.get_opcode() {
+        // The operands of a conditional 'br' are the condition, the false and the true blocks.
+        InstructionOpcode::Br if terminator.is_conditional() && successors.len() == 2 => {
+            vec![
+                (successors[1], Some("true")),
+                (successors[0], Some("false")),
+            ]
+        }
+
+        InstructionOpcode::Switch => successors
+            .into_iter()
+            .enumerate()
+            .map(|(index, successor)| (successor, (index == 0).then_some("default")))
+            .collect(),
+
+        _ => successors
+            .into_iter()
+            .map(|successor| (successor, None))
+            .collect(),
+    }
+}
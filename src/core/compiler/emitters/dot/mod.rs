@@ -0,0 +1,43 @@
+//! The '-emit cfg-dot' and '-emit callgraph-dot' Graphviz graphs.
+
+pub mod callgraph;
+pub mod cfg;
+
+use std::path::{Path, PathBuf};
+
+use crate::core::{
+    compiler::{options::CompilerOptions, thrushc::TheThrushCompiler},
+    utils::rand,
+};
+
+/// Writes a graph into '<build-dir>/emit/<directory>'.
+fn write_graph(
+    compiler: &TheThrushCompiler,
+    graph: &str,
+    build_dir: &Path,
+    directory: &str,
+    graph_name: &str,
+) -> Result<(), std::io::Error> {
+    let compiler_options: &CompilerOptions = compiler.get_options();
+
+    let base_path: PathBuf = build_dir.join("emit").join(directory);
+
+    if !base_path.exists() {
+        let _ = std::fs::create_dir_all(&base_path);
+    }
+
+    let graph_file_name: String = if compiler_options.ofuscate_archive_names() {
+        format!("{}_{}.dot", rand::generate_random_string(), graph_name)
+    } else {
+        format!("{}.dot", graph_name)
+    };
+
+    std::fs::write(base_path.join(graph_file_name), graph)
+}
+
+/// Escapes a text for a quoted Graphviz id or label.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\l")
+}
@@ -0,0 +1,902 @@
+use std::{path::Path, rc::Rc};
+
+use crate::{
+    backend::llvm::compiler::{attributes::LLVMAttribute, builtins::Builtin},
+    core::compiler::{
+        emitters::json::{self, JSON_SCHEMA_VERSION, Json},
+        thrushc::TheThrushCompiler,
+    },
+    frontend::{
+        types::{
+            ast::{Ast, types::AstEitherExpression},
+            parser::stmts::{sites::AllocationSite, types::ThrushAttributes},
+        },
+        typesystem::types::Type,
+    },
+};
+
+pub fn emit_ast_json(
+    compiler: &TheThrushCompiler,
+    ast: &[Ast],
+    build_dir: &Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let document: Json = Json::Object(vec![
+        ("schema", Json::number(JSON_SCHEMA_VERSION)),
+        ("file", Json::string(file_name)),
+        ("ast", self::nodes(ast)),
+    ]);
+
+    json::write_document(compiler, document, build_dir, "ast-json", file_name)
+}
+
+/* ######################################################################
+
+
+    NODES - START
+
+
+########################################################################*/
+
+fn nodes(ast: &[Ast]) -> Json {
+    Json::Array(ast.iter().map(self::node).collect())
+}
+
+fn optional_node(ast: Option<&Rc<Ast>>) -> Json {
+    ast.map_or(Json::Null, |ast| self::node(ast))
+}
+
+fn node(ast: &Ast) -> Json {
+    let (name, mut fields): (&str, Vec<(&'static str, Json)>) = match ast {
+        Ast::Str { bytes, kind, .. } => (
+            "Str",
+            vec![
+                ("value", Json::string(String::from_utf8_lossy(bytes))),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Char { kind, byte, .. } => (
+            "Char",
+            vec![("value", Json::number(byte)), ("kind", self::kind(kind))],
+        ),
+
+        Ast::Boolean { kind, value, .. } => (
+            "Boolean",
+            vec![
+                ("value", Json::Bool(*value != 0)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Integer {
+            kind,
+            value,
+            signed,
+            ..
+        } => (
+            "Integer",
+            vec![
+                ("value", Json::number(value)),
+                ("signed", Json::Bool(*signed)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Float {
+            kind,
+            value,
+            signed,
+            ..
+        } => (
+            "Float",
+            vec![
+                ("value", Json::float(*value)),
+                ("signed", Json::Bool(*signed)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::GlobalAssembler { asm, .. } => ("GlobalAssembler", vec![("asm", Json::string(asm))]),
+
+        Ast::FixedArray { items, kind, .. } => (
+            "FixedArray",
+            vec![("items", self::nodes(items)), ("kind", self::kind(kind))],
+        ),
+
+        Ast::Array { items, kind, .. } => (
+            "Array",
+            vec![("items", self::nodes(items)), ("kind", self::kind(kind))],
+        ),
+
+        Ast::Index {
+            source,
+            indexes,
+            kind,
+            metadata,
+            ..
+        } => (
+            "Index",
+            vec![
+                ("source", self::source(source)),
+                ("indexes", self::nodes(indexes)),
+                ("kind", self::kind(kind)),
+                (
+                    "metadata",
+                    Json::Object(vec![("is_mutable", Json::Bool(metadata.is_mutable()))]),
+                ),
+            ],
+        ),
+
+        Ast::NullPtr { .. } => ("NullPtr", Vec::new()),
+
+        Ast::Struct {
+            name,
+            fields,
+            kind,
+            attributes,
+            docs,
+            ..
+        } => (
+            "Struct",
+            vec![
+                ("name", Json::string(name)),
+                (
+                    "fields",
+                    Json::Array(
+                        fields
+                            .1
+                            .iter()
+                            .map(|(name, kind, position, span)| {
+                                Json::Object(vec![
+                                    ("name", Json::string(name)),
+                                    ("kind", self::kind(kind)),
+                                    ("position", Json::number(position)),
+                                    ("span", Json::span(*span)),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+                ("kind", self::kind(kind)),
+                ("attributes", self::attributes(attributes)),
+                ("docs", self::docs(docs)),
+            ],
+        ),
+
+        Ast::Constructor {
+            name, args, kind, ..
+        } => (
+            "Constructor",
+            vec![
+                ("name", Json::string(name)),
+                (
+                    "args",
+                    Json::Array(
+                        args.iter()
+                            .map(|(name, value, kind, position)| {
+                                Json::Object(vec![
+                                    ("name", Json::string(name)),
+                                    ("value", self::node(value)),
+                                    ("kind", self::kind(kind)),
+                                    ("position", Json::number(position)),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Property {
+            source,
+            indexes,
+            metadata,
+            kind,
+            ..
+        } => (
+            "Property",
+            vec![
+                ("source", self::source(source)),
+                (
+                    "indexes",
+                    Json::Array(
+                        indexes
+                            .iter()
+                            .map(|(kind, position)| {
+                                Json::Object(vec![
+                                    ("kind", self::kind(kind)),
+                                    ("position", Json::number(position)),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+                ("kind", self::kind(kind)),
+                (
+                    "metadata",
+                    Json::Object(vec![("is_allocated", Json::Bool(metadata.is_allocated()))]),
+                ),
+            ],
+        ),
+
+        Ast::If {
+            condition,
+            block,
+            elseif,
+            anyway,
+            ..
+        } => (
+            "If",
+            vec![
+                ("condition", self::node(condition)),
+                ("block", self::node(block)),
+                ("elseif", self::nodes(elseif)),
+                ("anyway", self::optional_node(anyway.as_ref())),
+            ],
+        ),
+
+        Ast::Elif {
+            condition, block, ..
+        } => (
+            "Elif",
+            vec![
+                ("condition", self::node(condition)),
+                ("block", self::node(block)),
+            ],
+        ),
+
+        Ast::Else { block, .. } => ("Else", vec![("block", self::node(block))]),
+
+        Ast::For {
+            local,
+            cond,
+            actions,
+            block,
+            ..
+        } => (
+            "For",
+            vec![
+                ("local", self::node(local)),
+                ("cond", self::node(cond)),
+                ("actions", self::node(actions)),
+                ("block", self::node(block)),
+            ],
+        ),
+
+        Ast::While { cond, block, .. } => (
+            "While",
+            vec![("cond", self::node(cond)), ("block", self::node(block))],
+        ),
+
+        Ast::Loop { block, .. } => ("Loop", vec![("block", self::node(block))]),
+
+        Ast::Continue { .. } => ("Continue", Vec::new()),
+        Ast::Break { .. } => ("Break", Vec::new()),
+
+        Ast::Block { stmts, .. } => ("Block", vec![("stmts", self::nodes(stmts))]),
+
+        Ast::Enum {
+            name,
+            fields,
+            attributes,
+            docs,
+            ..
+        } => (
+            "Enum",
+            vec![
+                ("name", Json::string(name)),
+                (
+                    "fields",
+                    Json::Array(
+                        fields
+                            .iter()
+                            .map(|(name, value)| {
+                                Json::Object(vec![
+                                    ("name", Json::string(name)),
+                                    ("value", self::node(value)),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+                ("attributes", self::attributes(attributes)),
+                ("docs", self::docs(docs)),
+            ],
+        ),
+
+        Ast::EnumValue {
+            name, value, kind, ..
+        } => (
+            "EnumValue",
+            vec![
+                ("name", Json::string(name)),
+                ("value", self::node(value)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::EntryPoint { body, .. } => ("EntryPoint", vec![("body", self::node(body))]),
+
+        Ast::AssemblerFunction {
+            name,
+            ascii_name,
+            parameters,
+            assembler,
+            constraints,
+            return_type,
+            attributes,
+            ..
+        } => (
+            "AssemblerFunction",
+            vec![
+                ("name", Json::string(name)),
+                ("ascii_name", Json::string(ascii_name)),
+                ("parameters", self::nodes(parameters)),
+                ("assembler", Json::string(assembler)),
+                ("constraints", Json::string(constraints)),
+                ("return_type", self::kind(return_type)),
+                ("attributes", self::attributes(attributes)),
+            ],
+        ),
+
+        Ast::AssemblerFunctionParameter {
+            name,
+            kind,
+            position,
+            ..
+        } => (
+            "AssemblerFunctionParameter",
+            vec![
+                ("name", Json::string(name)),
+                ("kind", self::kind(kind)),
+                ("position", Json::number(position)),
+            ],
+        ),
+
+        Ast::Function {
+            name,
+            ascii_name,
+            parameters,
+            body,
+            return_type,
+            attributes,
+            docs,
+            ..
+        } => (
+            "Function",
+            vec![
+                ("name", Json::string(name)),
+                ("ascii_name", Json::string(ascii_name)),
+                ("parameters", self::nodes(parameters)),
+                ("return_type", self::kind(return_type)),
+                ("attributes", self::attributes(attributes)),
+                ("docs", self::docs(docs)),
+                (
+                    "body",
+                    if body.is_null() {
+                        Json::Null
+                    } else {
+                        self::node(body)
+                    },
+                ),
+            ],
+        ),
+
+        Ast::FunctionParameter {
+            name,
+            ascii_name,
+            kind,
+            position,
+            metadata,
+            ..
+        } => (
+            "FunctionParameter",
+            vec![
+                ("name", Json::string(name)),
+                ("ascii_name", Json::string(ascii_name)),
+                ("kind", self::kind(kind)),
+                ("position", Json::number(position)),
+                (
+                    "metadata",
+                    Json::Object(vec![("is_mutable", Json::Bool(metadata.is_mutable()))]),
+                ),
+            ],
+        ),
+
+        Ast::Return {
+            expression, kind, ..
+        } => (
+            "Return",
+            vec![
+                ("expression", self::optional_node(expression.as_ref())),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Static {
+            name,
+            ascii_name,
+            kind,
+            value,
+            attributes,
+            docs,
+            metadata,
+            ..
+        } => (
+            "Static",
+            vec![
+                ("name", Json::string(name)),
+                ("ascii_name", Json::string(ascii_name)),
+                ("kind", self::kind(kind)),
+                ("value", self::node(value)),
+                ("attributes", self::attributes(attributes)),
+                ("docs", self::docs(docs)),
+                (
+                    "metadata",
+                    Json::Object(vec![
+                        ("is_global", Json::Bool(metadata.is_global())),
+                        ("is_mutable", Json::Bool(metadata.is_mutable())),
+                    ]),
+                ),
+            ],
+        ),
+
+        Ast::Const {
+            name,
+            ascii_name,
+            kind,
+            value,
+            attributes,
+            docs,
+            metadata,
+            ..
+        } => (
+            "Const",
+            vec![
+                ("name", Json::string(name)),
+                ("ascii_name", Json::string(ascii_name)),
+                ("kind", self::kind(kind)),
+                ("value", self::node(value)),
+                ("attributes", self::attributes(attributes)),
+                ("docs", self::docs(docs)),
+                (
+                    "metadata",
+                    Json::Object(vec![("is_global", Json::Bool(metadata.is_global()))]),
+                ),
+            ],
+        ),
+
+        Ast::Local {
+            name,
+            ascii_name,
+            kind,
+            value,
+            attributes,
+            metadata,
+            ..
+        } => (
+            "Local",
+            vec![
+                ("name", Json::string(name)),
+                ("ascii_name", Json::string(ascii_name)),
+                ("kind", self::kind(kind)),
+                (
+                    "value",
+                    if metadata.is_undefined() {
+                        Json::Null
+                    } else {
+                        self::node(value)
+                    },
+                ),
+                ("attributes", self::attributes(attributes)),
+                (
+                    "metadata",
+                    Json::Object(vec![
+                        ("is_undefined", Json::Bool(metadata.is_undefined())),
+                        ("is_mutable", Json::Bool(metadata.is_mutable())),
+                    ]),
+                ),
+            ],
+        ),
+
+        Ast::Reference {
+            name,
+            kind,
+            metadata,
+            ..
+        } => (
+            "Reference",
+            vec![
+                ("name", Json::string(name)),
+                ("kind", self::kind(kind)),
+                (
+                    "metadata",
+                    Json::Object(vec![
+                        ("is_allocated", Json::Bool(metadata.is_allocated())),
+                        ("is_mutable", Json::Bool(metadata.is_mutable())),
+                        ("is_constant", Json::Bool(metadata.is_constant())),
+                    ]),
+                ),
+            ],
+        ),
+
+        Ast::Mut {
+            source,
+            value,
+            kind,
+            ..
+        } => (
+            "Mut",
+            vec![
+                ("source", self::node(source)),
+                ("value", self::node(value)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::LLI {
+            name, kind, value, ..
+        } => (
+            "LLI",
+            vec![
+                ("name", Json::string(name)),
+                ("kind", self::kind(kind)),
+                ("value", self::node(value)),
+            ],
+        ),
+
+        Ast::Alloc {
+            alloc,
+            site_allocation,
+            attributes,
+            ..
+        } => (
+            "Alloc",
+            vec![
+                ("alloc", self::kind(alloc)),
+                (
+                    "site",
+                    Json::string(match site_allocation {
+                        AllocationSite::Stack => "stack",
+                        AllocationSite::Heap => "heap",
+                        AllocationSite::Static => "static",
+                    }),
+                ),
+                ("attributes", self::attributes(attributes)),
+            ],
+        ),
+
+        Ast::Address {
+            source,
+            indexes,
+            kind,
+            ..
+        } => (
+            "Address",
+            vec![
+                ("source", self::source(source)),
+                ("indexes", self::nodes(indexes)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Write {
+            source,
+            write_value,
+            write_type,
+            ..
+        } => (
+            "Write",
+            vec![
+                ("source", self::source(source)),
+                ("value", self::node(write_value)),
+                ("kind", self::kind(write_type)),
+            ],
+        ),
+
+        Ast::Load { source, kind, .. } => (
+            "Load",
+            vec![("source", self::source(source)), ("kind", self::kind(kind))],
+        ),
+
+        Ast::Deref { value, kind, .. } => (
+            "Deref",
+            vec![("value", self::node(value)), ("kind", self::kind(kind))],
+        ),
+
+        Ast::As {
+            from,
+            cast,
+            metadata,
+            ..
+        } => (
+            "As",
+            vec![
+                ("from", self::node(from)),
+                ("cast", self::kind(cast)),
+                (
+                    "metadata",
+                    Json::Object(vec![
+                        ("is_constant", Json::Bool(metadata.is_constant())),
+                        ("is_allocated", Json::Bool(metadata.is_allocated())),
+                    ]),
+                ),
+            ],
+        ),
+
+        Ast::Call {
+            name, args, kind, ..
+        } => (
+            "Call",
+            vec![
+                ("name", Json::string(name)),
+                ("args", self::nodes(args)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::AsmValue {
+            assembler,
+            constraints,
+            args,
+            kind,
+            attributes,
+            ..
+        } => (
+            "AsmValue",
+            vec![
+                ("assembler", Json::string(assembler)),
+                ("constraints", Json::string(constraints)),
+                ("args", self::nodes(args)),
+                ("kind", self::kind(kind)),
+                ("attributes", self::attributes(attributes)),
+            ],
+        ),
+
+        Ast::BinaryOp {
+            left,
+            operator,
+            right,
+            kind,
+            ..
+        } => (
+            "BinaryOp",
+            vec![
+                ("operator", Json::string(operator)),
+                ("left", self::node(left)),
+                ("right", self::node(right)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::UnaryOp {
+            operator,
+            kind,
+            expression,
+            is_pre,
+            ..
+        } => (
+            "UnaryOp",
+            vec![
+                ("operator", Json::string(operator)),
+                ("is_pre", Json::Bool(*is_pre)),
+                ("expression", self::node(expression)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Group {
+            expression, kind, ..
+        } => (
+            "Group",
+            vec![
+                ("expression", self::node(expression)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::SizeOf { sizeof, kind, .. } => (
+            "SizeOf",
+            vec![("sizeof", self::kind(sizeof)), ("kind", self::kind(kind))],
+        ),
+
+        Ast::Builtin { builtin, kind, .. } => (
+            "Builtin",
+            vec![
+                ("builtin", self::builtin(builtin)),
+                ("kind", self::kind(kind)),
+            ],
+        ),
+
+        Ast::Pass { .. } => ("Pass", Vec::new()),
+        Ast::Null { .. } => ("Null", Vec::new()),
+    };
+
+    let mut object: Vec<(&'static str, Json)> = Vec::with_capacity(fields.len() + 2);
+
+    object.push(("node", Json::string(name)));
+    object.push(("span", Json::span(ast.get_span())));
+    object.append(&mut fields);
+
+    Json::Object(object)
+}
+
+fn source(source: &AstEitherExpression) -> Json {
+    Json::Object(vec![
+        (
+            "reference",
+            source.0.as_ref().map_or(Json::Null, |(name, reference)| {
+                Json::Object(vec![
+                    ("name", Json::string(name)),
+                    ("node", self::node(reference)),
+                ])
+            }),
+        ),
+        ("expression", self::optional_node(source.1.as_ref())),
+    ])
+}
+
+fn builtin(builtin: &Builtin) -> Json {
+    let (name, mut fields): (&str, Vec<(&'static str, Json)>) = match builtin {
+        Builtin::Halloc { alloc } => ("halloc", vec![("alloc", self::kind(alloc))]),
+
+        Builtin::MemCpy {
+            source,
+            destination,
+            size,
+        } => (
+            "memcpy",
+            vec![
+                ("source", self::node(source)),
+                ("destination", self::node(destination)),
+                ("size", self::node(size)),
+            ],
+        ),
+
+        Builtin::MemMove {
+            source,
+            destination,
+            size,
+        } => (
+            "memmove",
+            vec![
+                ("source", self::node(source)),
+                ("destination", self::node(destination)),
+                ("size", self::node(size)),
+            ],
+        ),
+
+        Builtin::MemSet {
+            destination,
+            new_size,
+            size,
+        } => (
+            "memset",
+            vec![
+                ("destination", self::node(destination)),
+                ("new_size", self::node(new_size)),
+                ("size", self::node(size)),
+            ],
+        ),
+
+        Builtin::AlignOf { align_of } => ("alignof", vec![("align_of", self::kind(align_of))]),
+
+        Builtin::Assert { condition, .. } => ("assert", vec![("condition", self::node(condition))]),
+
+        Builtin::AssertEq { left, right, .. } => (
+            "assert_eq",
+            vec![("left", self::node(left)), ("right", self::node(right))],
+        ),
+    };
+
+    let mut object: Vec<(&'static str, Json)> = vec![("name", Json::string(name))];
+
+    object.append(&mut fields);
+
+    Json::Object(object)
+}
+
+/* ######################################################################
+
+
+    NODES - END
+
+
+########################################################################*/
+
+fn kind(kind: &Type) -> Json {
+    let primitive = |name: &str| Json::Object(vec![("kind", Json::string(name))]);
+
+    match kind {
+        Type::S8 => primitive("s8"),
+        Type::S16 => primitive("s16"),
+        Type::S32 => primitive("s32"),
+        Type::S64 => primitive("s64"),
+        Type::U8 => primitive("u8"),
+        Type::U16 => primitive("u16"),
+        Type::U32 => primitive("u32"),
+        Type::U64 => primitive("u64"),
+        Type::F32 => primitive("f32"),
+        Type::F64 => primitive("f64"),
+        Type::Bool => primitive("bool"),
+        Type::Char => primitive("char"),
+        Type::Str => primitive("str"),
+        Type::Addr => primitive("addr"),
+        Type::Void => primitive("void"),
+
+        Type::Const(inner) => Json::Object(vec![
+            ("kind", Json::string("const")),
+            ("of", self::kind(inner)),
+        ]),
+
+        Type::Mut(inner) => Json::Object(vec![
+            ("kind", Json::string("mut")),
+            ("of", self::kind(inner)),
+        ]),
+
+        Type::Ptr(inner) => Json::Object(vec![
+            ("kind", Json::string("ptr")),
+            (
+                "of",
+                inner.as_ref().map_or(Json::Null, |inner| self::kind(inner)),
+            ),
+        ]),
+
+        Type::Struct(name, fields) => Json::Object(vec![
+            ("kind", Json::string("struct")),
+            ("name", Json::string(name)),
+            (
+                "fields",
+                Json::Array(fields.iter().map(|field| self::kind(field)).collect()),
+            ),
+        ]),
+
+        Type::FixedArray(inner, size) => Json::Object(vec![
+            ("kind", Json::string("fixed_array")),
+            ("of", self::kind(inner)),
+            ("size", Json::number(size)),
+        ]),
+
+        Type::Array(inner) => Json::Object(vec![
+            ("kind", Json::string("array")),
+            ("of", self::kind(inner)),
+        ]),
+    }
+}
+
+fn attributes(attributes: &ThrushAttributes) -> Json {
+    Json::Array(
+        attributes
+            .iter()
+            .map(|attribute| {
+                let display: String = attribute.to_string();
+
+                let name: &str = display
+                    .trim_start_matches('@')
+                    .split('(')
+                    .next()
+                    .unwrap_or_default();
+
+                let argument: Json = match attribute {
+                    LLVMAttribute::Extern(argument, ..)
+                    | LLVMAttribute::AsmSyntax(argument, ..)
+                    | LLVMAttribute::Allow(argument, ..) => Json::string(argument),
+                    LLVMAttribute::Convention(convention, ..) => Json::string(convention),
+                    _ => Json::Null,
+                };
+
+                Json::Object(vec![
+                    ("name", Json::string(name)),
+                    ("argument", argument),
+                    ("span", Json::span(attribute.get_span())),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn docs(docs: &[&str]) -> Json {
+    Json::Array(docs.iter().map(Json::string).collect())
+}
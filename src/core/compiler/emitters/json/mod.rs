@@ -0,0 +1,182 @@
+//! The '-emit ast-json' and '-emit tokens-json' dumps.
+//!
+//! Both are written into '<build-dir>/emit/ast-json' and '<build-dir>/emit/tokens-json'
+//! as one JSON document per file:
+//!
+//! ```text
+//! { "schema": 1, "file": "main.thrush", "ast": [Node, ..] }
+//! { "schema": 1, "file": "main.thrush", "tokens": [Token, ..] }
+//! ```
+//!
+//! - `Span` is `{ "file": 0, "line": 1, "start": 0, "end": 4 }`, with byte offsets.
+//! - `Token` is `{ "kind": "Identifier", "lexeme": "x", "ascii": "x", "span": Span }`,
+//!   where `kind` is the name of the token type.
+//! - `Type` is `{ "kind": "s32" }` for the primitive types (`s8` .. `u64`, `f32`, `f64`,
+//!   `bool`, `char`, `str`, `addr`, `void`), `{ "kind": "ptr", "of": Type | null }`,
+//!   `{ "kind": "mut" | "const" | "array", "of": Type }`,
+//!   `{ "kind": "fixed_array", "of": Type, "size": 4 }` and
+//!   `{ "kind": "struct", "name": "Point", "fields": [Type, ..] }`.
+//! - `Attribute` is `{ "name": "extern", "argument": "puts" | null, "span": Span }`.
+//! - `Node` is `{ "node": "Local", "span": Span, .. }` with one key per field of the
+//!   node: nested nodes, lists of nodes, types (`kind`, `return_type`, `cast`, ..),
+//!   `attributes` and `metadata`, whose keys are the flags of the node (`is_mutable`,
+//!   `is_undefined`, `is_constant`, `is_allocated`, ..).
+//!
+//! The `schema` number changes whenever a key is renamed or removed.
+
+pub mod ast;
+pub mod tokens;
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    core::{
+        compiler::{options::CompilerOptions, thrushc::TheThrushCompiler},
+        utils::rand,
+    },
+    frontend::lexer::span::Span,
+};
+
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    pub fn number(value: impl ToString) -> Json {
+        Json::Number(value.to_string())
+    }
+
+    pub fn float(value: f64) -> Json {
+        if !value.is_finite() {
+            return Json::Null;
+        }
+
+        Json::Number(format!("{:?}", value))
+    }
+
+    pub fn string(value: impl ToString) -> Json {
+        Json::Str(value.to_string())
+    }
+
+    pub fn span(span: Span) -> Json {
+        Json::Object(vec![
+            ("file", Json::number(span.get_file())),
+            ("line", Json::number(span.get_line())),
+            ("start", Json::number(span.get_span_start())),
+            ("end", Json::number(span.get_span_end())),
+        ])
+    }
+}
+
+impl Json {
+    pub fn to_pretty_string(&self) -> String {
+        let mut output: String = String::with_capacity(4096);
+
+        self.write(&mut output, 0);
+        output.push('\n');
+
+        output
+    }
+
+    fn write(&self, output: &mut String, depth: usize) {
+        match self {
+            Json::Null => output.push_str("null"),
+            Json::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+            Json::Number(value) => output.push_str(value),
+            Json::Str(value) => self::write_string(output, value),
+
+            Json::Array(items) if items.is_empty() => output.push_str("[]"),
+            Json::Array(items) => {
+                output.push('[');
+
+                items.iter().enumerate().for_each(|(index, item)| {
+                    if index > 0 {
+                        output.push(',');
+                    }
+
+                    self::write_indent(output, depth + 1);
+                    item.write(output, depth + 1);
+                });
+
+                self::write_indent(output, depth);
+                output.push(']');
+            }
+
+            Json::Object(fields) if fields.is_empty() => output.push_str("{}"),
+            Json::Object(fields) => {
+                output.push('{');
+
+                fields.iter().enumerate().for_each(|(index, (key, value))| {
+                    if index > 0 {
+                        output.push(',');
+                    }
+
+                    self::write_indent(output, depth + 1);
+                    self::write_string(output, key);
+                    output.push_str(": ");
+                    value.write(output, depth + 1);
+                });
+
+                self::write_indent(output, depth);
+                output.push('}');
+            }
+        }
+    }
+}
+
+fn write_indent(output: &mut String, depth: usize) {
+    output.push('\n');
+    (0..depth).for_each(|_| output.push_str("  "));
+}
+
+fn write_string(output: &mut String, value: &str) {
+    output.push('"');
+
+    value.chars().for_each(|c| match c {
+        '"' => output.push_str("\\\""),
+        '\\' => output.push_str("\\\\"),
+        '\n' => output.push_str("\\n"),
+        '\r' => output.push_str("\\r"),
+        '\t' => output.push_str("\\t"),
+        c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+        c => output.push(c),
+    });
+
+    output.push('"');
+}
+
+/// Writes a document into '<build-dir>/emit/<directory>'.
+fn write_document(
+    compiler: &TheThrushCompiler,
+    document: Json,
+    build_dir: &Path,
+    directory: &str,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let compiler_options: &CompilerOptions = compiler.get_options();
+
+    let base_path: PathBuf = build_dir.join("emit").join(directory);
+
+    if !base_path.exists() {
+        let _ = std::fs::create_dir_all(&base_path);
+    }
+
+    let document_file_name: String = if compiler_options.ofuscate_archive_names() {
+        format!("{}_{}.json", rand::generate_random_string(), file_name)
+    } else {
+        format!("{}.json", file_name)
+    };
+
+    std::fs::write(
+        base_path.join(document_file_name),
+        document.to_pretty_string(),
+    )
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use crate::{
+    core::compiler::{
+        emitters::json::{self, JSON_SCHEMA_VERSION, Json},
+        thrushc::TheThrushCompiler,
+    },
+    frontend::types::lexer::types::Tokens,
+};
+
+pub fn emit_tokens_json(
+    compiler: &TheThrushCompiler,
+    tokens: &Tokens,
+    build_dir: &Path,
+    file_name: &str,
+) -> Result<(), std::io::Error> {
+    let document: Json = Json::Object(vec![
+        ("schema", Json::number(JSON_SCHEMA_VERSION)),
+        ("file", Json::string(file_name)),
+        (
+            "tokens",
+            Json::Array(
+                tokens
+                    .iter()
+                    .map(|token| {
+                        Json::Object(vec![
+                            ("kind", Json::string(format!("{:?}", token.kind))),
+                            ("lexeme", Json::string(&token.lexeme)),
+                            ("ascii", Json::string(&token.ascii_lexeme)),
+                            ("span", Json::span(token.span)),
+                        ])
+                    })
+                    .collect(),
+            ),
+        ),
+    ]);
+
+    json::write_document(compiler, document, build_dir, "tokens-json", file_name)
+}
@@ -1,5 +1,8 @@
 pub mod assembler;
+pub mod cheader;
 pub mod cleaner;
+pub mod dot;
+pub mod json;
 pub mod llvmbitcode;
 pub mod llvmir;
 pub mod obj;
@@ -0,0 +1,238 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::core::{
+    compiler::{
+        constants::INCREMENTAL_CACHE_DIR,
+        options::{CompilerFile, CompilerOptions},
+        sourcemap::SourceFile,
+    },
+    console::logging::{self, LoggingType},
+    utils::sha256,
+};
+
+/// Keeps the object of every compiled file in the build directory, with a record of
+/// what it was compiled from: the compiler version, the options that change code
+/// generation, the source and the C headers it imported. The warnings of a file are
+/// kept next to its object and printed again when it is reused.
+#[derive(Debug, Clone)]
+pub struct IncrementalCache {
+    dir: PathBuf,
+    options_hash: String,
+}
+
+#[derive(Debug)]
+pub struct CachedUnit {
+    pub object: PathBuf,
+    pub exported_symbols: Vec<String>,
+    pub headers: Vec<PathBuf>,
+    pub warnings: String,
+}
+
+#[derive(Debug)]
+pub enum RebuildReason {
+    NotCached,
+    CompilerChanged,
+    OptionsChanged,
+    SourceChanged,
+    HeaderChanged(PathBuf),
+    ObjectMissing,
+}
+
+impl Display for RebuildReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RebuildReason::NotCached => write!(f, "it was never compiled before"),
+            RebuildReason::CompilerChanged => write!(f, "the compiler version changed"),
+            RebuildReason::OptionsChanged => write!(f, "the code generation options changed"),
+            RebuildReason::SourceChanged => write!(f, "the source changed"),
+            RebuildReason::HeaderChanged(header) => {
+                write!(f, "the C header '{}' changed", header.display())
+            }
+            RebuildReason::ObjectMissing => write!(f, "its cached object is missing"),
+        }
+    }
+}
+
+impl IncrementalCache {
+    /// Without its directory the cache is disabled, with a warning.
+    pub fn new(options: &CompilerOptions) -> Option<Self> {
+        let dir: PathBuf = options.get_build_dir().join(INCREMENTAL_CACHE_DIR);
+
+        if let Err(error) = fs::create_dir_all(&dir) {
+            logging::log(
+                LoggingType::Warning,
+                &format!(
+                    "The incremental cache is disabled, since '{}' could not be created: {}.\n",
+                    dir.display(),
+                    error
+                ),
+            );
+
+            return None;
+        }
+
+        let fingerprint: String = format!(
+            "{}|{:?}|{:?}",
+            options.get_llvm_backend_options().get_codegen_fingerprint(),
+            options.get_include_dirs(),
+            options.get_lints_configuration()
+        );
+
+        Some(Self {
+            dir,
+            options_hash: sha256::digest(fingerprint.as_bytes()),
+        })
+    }
+}
+
+impl IncrementalCache {
+    /// Returns the object of a previous compilation of the file, or why it is stale.
+    pub fn lookup(
+        &self,
+        file: &CompilerFile,
+        source: &SourceFile,
+    ) -> Result<CachedUnit, RebuildReason> {
+        let Ok(record) = fs::read_to_string(self.get_record_path(file)) else {
+            return Err(RebuildReason::NotCached);
+        };
+
+        let mut exported_symbols: Vec<String> = Vec::with_capacity(50);
+        let mut headers: Vec<PathBuf> = Vec::with_capacity(10);
+
+        let mut version_seen: bool = false;
+        let mut options_seen: bool = false;
+        let mut source_seen: bool = false;
+
+        for line in record.lines() {
+            let (field, value) = line.split_once(' ').unwrap_or((line, ""));
+
+            match field {
+                "version" if value != env!("CARGO_PKG_VERSION") => {
+                    return Err(RebuildReason::CompilerChanged);
+                }
+
+                "options" if value != self.options_hash => {
+                    return Err(RebuildReason::OptionsChanged);
+                }
+
+                "source" if value != sha256::digest(source.get_code().as_bytes()) => {
+                    return Err(RebuildReason::SourceChanged);
+                }
+
+                "version" => version_seen = true,
+                "options" => options_seen = true,
+                "source" => source_seen = true,
+
+                "header" => {
+                    let (hash, header) = value.split_once(' ').unwrap_or((value, ""));
+                    let header: &Path = Path::new(header);
+
+                    if !fs::read(header).is_ok_and(|code| sha256::digest(&code) == hash) {
+                        return Err(RebuildReason::HeaderChanged(header.to_path_buf()));
+                    }
+
+                    headers.push(header.to_path_buf());
+                }
+
+                "symbol" => exported_symbols.push(value.to_string()),
+
+                _ => (),
+            }
+        }
+
+        // A truncated record can't tell whether the unit is up to date.
+        if !(version_seen && options_seen && source_seen) {
+            return Err(RebuildReason::NotCached);
+        }
+
+        let object: PathBuf = self.get_object_path(file);
+
+        if !object.is_file() {
+            return Err(RebuildReason::ObjectMissing);
+        }
+
+        Ok(CachedUnit {
+            object,
+            exported_symbols,
+            headers,
+            warnings: fs::read_to_string(self.get_warnings_path(file)).unwrap_or_default(),
+        })
+    }
+
+    /// Records the compilation of the file, once its object was written.
+    pub fn store(
+        &self,
+        file: &CompilerFile,
+        source: &SourceFile,
+        headers: &[PathBuf],
+        exported_symbols: &[String],
+        warnings: &str,
+    ) {
+        let mut record: String = format!(
+            "version {}\noptions {}\nsource {}\n",
+            env!("CARGO_PKG_VERSION"),
+            self.options_hash,
+            sha256::digest(source.get_code().as_bytes())
+        );
+
+        let record_path: PathBuf = self.get_record_path(file);
+
+        for header in headers {
+            // Without the hash of every header, the unit can't be reused safely.
+            let Ok(code) = fs::read(header) else {
+                let _ = fs::remove_file(&record_path);
+                return;
+            };
+
+            record.push_str(&format!(
+                "header {} {}\n",
+                sha256::digest(&code),
+                header.display()
+            ));
+        }
+
+        exported_symbols.iter().for_each(|symbol| {
+            record.push_str(&format!("symbol {}\n", symbol));
+        });
+
+        let temporal_path: PathBuf = record_path.with_extension("tmp");
+        let warnings_path: PathBuf = self.get_warnings_path(file);
+
+        let warnings_written: bool = if warnings.is_empty() {
+            fs::remove_file(&warnings_path).is_ok() || !warnings_path.exists()
+        } else {
+            fs::write(&warnings_path, warnings).is_ok()
+        };
+
+        // A record that can't be written only costs a rebuild next time.
+        if warnings_written && fs::write(&temporal_path, record).is_ok() {
+            let _ = fs::rename(&temporal_path, &record_path);
+        }
+    }
+}
+
+impl IncrementalCache {
+    pub fn get_object_path(&self, file: &CompilerFile) -> PathBuf {
+        self.dir.join(format!("{}.o", self::get_unit_name(file)))
+    }
+
+    fn get_record_path(&self, file: &CompilerFile) -> PathBuf {
+        self.dir.join(format!("{}.unit", self::get_unit_name(file)))
+    }
+
+    fn get_warnings_path(&self, file: &CompilerFile) -> PathBuf {
+        self.dir
+            .join(format!("{}.warnings", self::get_unit_name(file)))
+    }
+}
+
+/// Files with the same name in different directories get different entries.
+fn get_unit_name(file: &CompilerFile) -> String {
+    let path_hash: String = sha256::digest(file.path.to_string_lossy().as_bytes());
+
+    format!("{}_{}", &path_hash[..16], file.name)
+}
@@ -22,3 +22,13 @@ impl JITConfiguration {
         self.libraries.push(value);
     }
 }
+
+impl JITConfiguration {
+    pub fn get_libc_path(&self) -> Option<&PathBuf> {
+        self.libc_path.as_ref()
+    }
+
+    pub fn get_libraries(&self) -> &[PathBuf] {
+        &self.libraries
+    }
+}
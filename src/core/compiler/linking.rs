@@ -1,14 +1,27 @@
 use std::path::PathBuf;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrateType {
+    Executable,
+    StaticLibrary,
+    SharedLibrary,
+    Object,
+}
+
 #[derive(Debug)]
 pub struct LinkingCompilersConfiguration {
     use_clang: bool,
     use_gcc: bool,
+    use_ld: bool,
     compiler_args: Vec<String>,
     custom_gcc: Option<PathBuf>,
     custom_clang: Option<PathBuf>,
+    custom_ld: Option<PathBuf>,
+    link_dry_run: bool,
     debug_clang_commands: bool,
     debug_gcc_commands: bool,
+    crate_type: CrateType,
+    output: Option<PathBuf>,
 }
 
 impl LinkingCompilersConfiguration {
@@ -16,11 +29,16 @@ impl LinkingCompilersConfiguration {
         Self {
             use_clang: false,
             use_gcc: false,
+            use_ld: false,
             compiler_args: Vec::with_capacity(50),
             custom_gcc: None,
             custom_clang: None,
+            custom_ld: None,
+            link_dry_run: false,
             debug_clang_commands: false,
             debug_gcc_commands: false,
+            crate_type: CrateType::Executable,
+            output: None,
         }
     }
 
@@ -51,6 +69,26 @@ impl LinkingCompilersConfiguration {
     pub fn get_use_gcc(&self) -> bool {
         self.use_gcc
     }
+
+    pub fn get_use_ld(&self) -> bool {
+        self.use_ld
+    }
+
+    pub fn get_custom_ld(&self) -> Option<&PathBuf> {
+        self.custom_ld.as_ref()
+    }
+
+    pub fn get_link_dry_run(&self) -> bool {
+        self.link_dry_run
+    }
+
+    pub fn get_crate_type(&self) -> CrateType {
+        self.crate_type
+    }
+
+    pub fn get_output(&self) -> Option<&PathBuf> {
+        self.output.as_ref()
+    }
 }
 
 impl LinkingCompilersConfiguration {
@@ -62,6 +100,18 @@ impl LinkingCompilersConfiguration {
         self.use_gcc = value;
     }
 
+    pub fn set_use_ld(&mut self, value: bool) {
+        self.use_ld = value;
+    }
+
+    pub fn set_custom_ld(&mut self, value: PathBuf) {
+        self.custom_ld = Some(value);
+    }
+
+    pub fn set_link_dry_run(&mut self, value: bool) {
+        self.link_dry_run = value;
+    }
+
     pub fn set_custom_clang(&mut self, value: PathBuf) {
         self.custom_clang = Some(value);
     }
@@ -77,6 +127,37 @@ impl LinkingCompilersConfiguration {
     pub fn set_debug_gcc_commands(&mut self, value: bool) {
         self.debug_gcc_commands = value;
     }
+
+    pub fn set_crate_type(&mut self, value: CrateType) {
+        self.crate_type = value;
+    }
+
+    pub fn set_output(&mut self, value: PathBuf) {
+        self.output = Some(value);
+    }
+
+    /// Forgets the linker chosen so far, with its custom path.
+    pub fn reset_linker(&mut self) {
+        self.use_clang = false;
+        self.use_gcc = false;
+        self.use_ld = false;
+
+        self.custom_clang = None;
+        self.custom_gcc = None;
+        self.custom_ld = None;
+    }
+}
+
+impl CrateType {
+    /// Default output name for a library or object built from the given file stem.
+    pub fn get_default_output(self, stem: &str) -> Option<PathBuf> {
+        match self {
+            CrateType::Executable => None,
+            CrateType::StaticLibrary => Some(PathBuf::from(format!("lib{}.a", stem))),
+            CrateType::SharedLibrary => Some(PathBuf::from(format!("lib{}.so", stem))),
+            CrateType::Object => Some(PathBuf::from(format!("{}.o", stem))),
+        }
+    }
 }
 
 impl LinkingCompilersConfiguration {
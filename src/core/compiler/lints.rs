@@ -0,0 +1,51 @@
+use crate::core::diagnostic::codes::DiagnosticCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+#[derive(Debug)]
+pub struct LintsConfiguration {
+    levels: Vec<(String, LintLevel)>,
+    warnings_as_errors: bool,
+}
+
+impl LintsConfiguration {
+    pub fn new() -> Self {
+        Self {
+            levels: Vec::with_capacity(10),
+            warnings_as_errors: false,
+        }
+    }
+
+    /// Resolves the level of a warning from the command line flags. Later flags
+    /// override earlier ones, so `-A unused -W unused_local` only warns on locals.
+    pub fn get_level(&self, code: DiagnosticCode) -> LintLevel {
+        let level: LintLevel = self
+            .levels
+            .iter()
+            .rev()
+            .find(|(lint, _)| code.matches_lint(lint))
+            .map(|(_, level)| *level)
+            .unwrap_or(LintLevel::Warn);
+
+        if self.warnings_as_errors && level == LintLevel::Warn {
+            return LintLevel::Deny;
+        }
+
+        level
+    }
+}
+
+impl LintsConfiguration {
+    pub fn set_level(&mut self, lint: String, level: LintLevel) {
+        self.levels.push((lint, level));
+    }
+
+    pub fn set_warnings_as_errors(&mut self, value: bool) {
+        self.warnings_as_errors = value;
+    }
+}
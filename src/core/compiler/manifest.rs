@@ -0,0 +1,404 @@
+use std::path::{Path, PathBuf};
+
+use ahash::AHashMap as HashMap;
+
+use crate::core::compiler::constants::PROJECT_MANIFEST_FILE;
+
+const PROJECT_KEYS: [&str; 6] = [
+    "name",
+    "sources",
+    "entry",
+    "build-dir",
+    "output",
+    "crate-type",
+];
+
+const BUILD_KEYS: [&str; 9] = [
+    "target",
+    "cpu",
+    "opt",
+    "debug",
+    "emit",
+    "jobs",
+    "linker",
+    "linker-path",
+    "linker-args",
+];
+
+/// A 'thrush.toml' project manifest.
+///
+/// Only the part of TOML that manifests need is understood: tables, and keys holding
+/// strings, integers, booleans or arrays of them.
+#[derive(Debug)]
+pub struct ProjectManifest {
+    root: PathBuf,
+    tables: HashMap<String, HashMap<String, ManifestValue>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManifestValue {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<ManifestValue>),
+}
+
+/// Where a setting is looked up. Build settings of a profile fall back to '[build]'.
+#[derive(Debug, Clone, Copy)]
+pub enum ManifestSection<'a> {
+    Project,
+    Build(&'a str),
+}
+
+impl ProjectManifest {
+    /// Looks for a manifest in the directory and its ancestors.
+    pub fn find(from: &Path) -> Result<Self, String> {
+        let Some(manifest_path) = from
+            .ancestors()
+            .map(|dir| dir.join(PROJECT_MANIFEST_FILE))
+            .find(|path| path.is_file())
+        else {
+            return Err(format!(
+                "Could not find '{}' in '{}' or any parent directory.",
+                PROJECT_MANIFEST_FILE,
+                from.display()
+            ));
+        };
+
+        let source: String = std::fs::read_to_string(&manifest_path)
+            .map_err(|error| format!("Could not read '{}': {}.", manifest_path.display(), error))?;
+
+        let root: PathBuf = manifest_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let tables: HashMap<String, HashMap<String, ManifestValue>> = self::parse(&source)
+            .map_err(|(line, error)| format!("{}:{}: {}", manifest_path.display(), line, error))?;
+
+        Ok(Self { root, tables })
+    }
+}
+
+impl ProjectManifest {
+    pub fn has_profile(&self, profile: &str) -> bool {
+        self.tables.contains_key(&format!("profile.{}", profile))
+    }
+
+    /// Relative paths of the manifest are relative to the directory that holds it.
+    pub fn resolve_path(&self, path: &str) -> PathBuf {
+        self.root.join(path)
+    }
+
+    pub fn get_string(&self, section: ManifestSection, key: &str) -> Result<Option<&str>, String> {
+        match self.get_value(section, key) {
+            Some(ManifestValue::String(value)) => Ok(Some(value)),
+            Some(_) => Err(self::type_error(key, "a string")),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_strings(&self, section: ManifestSection, key: &str) -> Result<Vec<&str>, String> {
+        match self.get_value(section, key) {
+            Some(ManifestValue::Array(values)) => values
+                .iter()
+                .map(|value| match value {
+                    ManifestValue::String(value) => Ok(value.as_str()),
+                    _ => Err(self::type_error(key, "an array of strings")),
+                })
+                .collect(),
+            Some(_) => Err(self::type_error(key, "an array of strings")),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    pub fn get_bool(&self, section: ManifestSection, key: &str) -> Result<Option<bool>, String> {
+        match self.get_value(section, key) {
+            Some(ManifestValue::Boolean(value)) => Ok(Some(*value)),
+            Some(_) => Err(self::type_error(key, "a boolean")),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_integer(&self, section: ManifestSection, key: &str) -> Result<Option<i64>, String> {
+        match self.get_value(section, key) {
+            Some(ManifestValue::Integer(value)) => Ok(Some(*value)),
+            Some(_) => Err(self::type_error(key, "an integer")),
+            None => Ok(None),
+        }
+    }
+
+    fn get_value(&self, section: ManifestSection, key: &str) -> Option<&ManifestValue> {
+        match section {
+            ManifestSection::Project => self.tables.get("project")?.get(key),
+            ManifestSection::Build(profile) => self
+                .tables
+                .get(&format!("profile.{}", profile))
+                .and_then(|table| table.get(key))
+                .or_else(|| self.tables.get("build")?.get(key)),
+        }
+    }
+}
+
+fn type_error(key: &str, expected: &str) -> String {
+    format!(
+        "Expected {} for '{}' in '{}'.",
+        expected, key, PROJECT_MANIFEST_FILE
+    )
+}
+
+/* ######################################################################
+
+
+    MANIFEST PARSER - START
+
+
+########################################################################*/
+
+type ParseError = (usize, String);
+
+fn parse(source: &str) -> Result<HashMap<String, HashMap<String, ManifestValue>>, ParseError> {
+    let mut tables: HashMap<String, HashMap<String, ManifestValue>> = HashMap::with_capacity(10);
+    let mut current_table: Option<String> = None;
+
+    let mut lines = source.lines().enumerate();
+
+    while let Some((idx, line)) = lines.next() {
+        let line_number: usize = idx + 1;
+        let mut line: String = self::strip_comment(line).trim().to_string();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let Some(name) = header.strip_suffix(']') else {
+                return Err((line_number, "Unclosed table header.".into()));
+            };
+
+            let name: String = name.trim().to_string();
+
+            if !self::is_known_table(&name) {
+                return Err((
+                    line_number,
+                    format!(
+                        "Unknown table '[{}]'. Expected '[project]', '[build]' or '[profile.NAME]'.",
+                        name
+                    ),
+                ));
+            }
+
+            if tables.contains_key(&name) {
+                return Err((line_number, format!("Table '[{}]' is defined twice.", name)));
+            }
+
+            tables.insert(name.clone(), HashMap::with_capacity(10));
+            current_table = Some(name);
+
+            continue;
+        }
+
+        let Some(table_name) = current_table.as_ref() else {
+            return Err((
+                line_number,
+                "Keys must be inside a table such as '[project]'.".into(),
+            ));
+        };
+
+        // Arrays may span several lines.
+        while self::bracket_depth(&line) > 0 {
+            let Some((_, next_line)) = lines.next() else {
+                return Err((line_number, "Unclosed array.".into()));
+            };
+
+            line.push(' ');
+            line.push_str(self::strip_comment(next_line).trim());
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err((line_number, "Expected 'key = value'.".into()));
+        };
+
+        let key: &str = key.trim();
+
+        let allowed_keys: &[&str] = if table_name == "project" {
+            &PROJECT_KEYS
+        } else {
+            &BUILD_KEYS
+        };
+
+        if !allowed_keys.contains(&key) {
+            return Err((
+                line_number,
+                format!("Unknown key '{}' in '[{}]'.", key, table_name),
+            ));
+        }
+
+        let mut chars: std::iter::Peekable<std::str::Chars> = value.trim().chars().peekable();
+
+        let value: ManifestValue =
+            self::parse_value(&mut chars).map_err(|error| (line_number, error))?;
+
+        if chars.any(|c| !c.is_whitespace()) {
+            return Err((
+                line_number,
+                format!("Unexpected characters after the value of '{}'.", key),
+            ));
+        }
+
+        let table: &mut HashMap<String, ManifestValue> =
+            tables.entry(table_name.clone()).or_default();
+
+        if table.insert(key.to_string(), value).is_some() {
+            return Err((
+                line_number,
+                format!("Key '{}' is defined twice in '[{}]'.", key, table_name),
+            ));
+        }
+    }
+
+    Ok(tables)
+}
+
+fn parse_value(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<ManifestValue, String> {
+    self::skip_whitespace(chars);
+
+    match chars.peek() {
+        Some('"') | Some('\'') => self::parse_string(chars).map(ManifestValue::String),
+
+        Some('[') => {
+            chars.next();
+
+            let mut values: Vec<ManifestValue> = Vec::with_capacity(10);
+
+            loop {
+                self::skip_whitespace(chars);
+
+                if chars.next_if_eq(&']').is_some() {
+                    return Ok(ManifestValue::Array(values));
+                }
+
+                values.push(self::parse_value(chars)?);
+
+                self::skip_whitespace(chars);
+
+                match chars.next() {
+                    Some(',') => continue,
+                    Some(']') => return Ok(ManifestValue::Array(values)),
+                    _ => return Err("Expected ',' or ']' in an array.".into()),
+                }
+            }
+        }
+
+        Some(_) => {
+            let mut word: String = String::with_capacity(20);
+
+            while let Some(c) =
+                chars.next_if(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '_'))
+            {
+                word.push(c);
+            }
+
+            match word.as_str() {
+                "true" => Ok(ManifestValue::Boolean(true)),
+                "false" => Ok(ManifestValue::Boolean(false)),
+                number => number
+                    .replace('_', "")
+                    .parse::<i64>()
+                    .map(ManifestValue::Integer)
+                    .map_err(|_| format!("Invalid value '{}'.", number)),
+            }
+        }
+
+        None => Err("Expected a value.".into()),
+    }
+}
+
+fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    let Some(quote) = chars.next() else {
+        return Err("Expected a string.".into());
+    };
+
+    let mut value: String = String::with_capacity(50);
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c == quote => return Ok(value),
+
+            '\\' if quote == '"' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('\\') => value.push('\\'),
+                Some('"') => value.push('"'),
+                Some(other) => return Err(format!("Unknown escape sequence '\\{}'.", other)),
+                None => break,
+            },
+
+            c => value.push(c),
+        }
+    }
+
+    Err("Unclosed string.".into())
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+/// Removes a '#' comment, unless the '#' is inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    let mut escaped: bool = false;
+
+    for (idx, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(open), c) if c == open && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..idx],
+            _ => (),
+        }
+
+        escaped = false;
+    }
+
+    line
+}
+
+/// How many arrays are still open at the end of the line.
+fn bracket_depth(line: &str) -> isize {
+    let mut depth: isize = 0;
+    let mut quote: Option<char> = None;
+
+    let value: &str = line.split_once('=').map_or("", |(_, value)| value);
+
+    for c in value.chars() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            _ => (),
+        }
+    }
+
+    depth
+}
+
+fn is_known_table(name: &str) -> bool {
+    matches!(name, "project" | "build")
+        || name
+            .strip_prefix("profile.")
+            .is_some_and(|profile| !profile.is_empty())
+}
+
+/* ######################################################################
+
+
+    MANIFEST PARSER - END
+
+
+########################################################################*/
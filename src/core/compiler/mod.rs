@@ -1,9 +1,14 @@
 pub mod backends;
 pub mod constants;
+pub mod coverage;
 pub mod emitters;
+pub mod incremental;
 pub mod jit;
 pub mod linking;
+pub mod lints;
+pub mod manifest;
 pub mod options;
 pub mod passes;
 pub mod reader;
+pub mod sourcemap;
 pub mod thrushc;
@@ -2,7 +2,7 @@
 
 use {
     crate::{
-        core::compiler::backends::llvm::LLVMBackend,
+        core::compiler::{backends::llvm::LLVMBackend, lints::LintsConfiguration},
         frontend::types::{ast::Ast, lexer::types::Tokens},
     },
     inkwell::OptimizationLevel,
@@ -15,6 +15,17 @@ pub struct CompilerOptions {
     llvm_backend: LLVMBackend,
     files: Vec<CompilerFile>,
     build_dir: PathBuf,
+    include_dirs: Vec<PathBuf>,
+    jobs: usize,
+    run_args: Option<Vec<String>>,
+    incremental: bool,
+    explain_rebuild: bool,
+    test_mode: bool,
+    repl_mode: bool,
+    doc_format: Option<DocFormat>,
+    emit_depfile: bool,
+    depfile: Option<PathBuf>,
+    lints: LintsConfiguration,
 
     emit: Vec<Emitable>,
 
@@ -42,7 +53,18 @@ pub enum Emitable {
     RawAssembly,
     Assembly,
     AST,
+    ASTJson,
     Tokens,
+    TokensJson,
+    CHeader,
+    CFGDot,
+    CallGraphDot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DocFormat {
+    Markdown,
+    Html,
 }
 
 #[derive(Debug)]
@@ -92,6 +114,17 @@ impl CompilerOptions {
             files: Vec::with_capacity(1000),
             emit: Vec::with_capacity(10),
             build_dir: PathBuf::new(),
+            include_dirs: Vec::with_capacity(10),
+            jobs: 1,
+            run_args: None,
+            incremental: true,
+            explain_rebuild: false,
+            test_mode: false,
+            repl_mode: false,
+            doc_format: None,
+            emit_depfile: false,
+            depfile: None,
+            lints: LintsConfiguration::new(),
             clean_tokens: false,
             clean_assembler: false,
             clean_object: false,
@@ -121,6 +154,46 @@ impl CompilerOptions {
         self.build_dir = build_dir;
     }
 
+    pub fn add_include_dir(&mut self, include_dir: PathBuf) {
+        self.include_dirs.push(include_dir);
+    }
+
+    pub fn set_jobs(&mut self, jobs: usize) {
+        self.jobs = jobs;
+    }
+
+    pub fn set_run_args(&mut self, run_args: Vec<String>) {
+        self.run_args = Some(run_args);
+    }
+
+    pub fn set_incremental(&mut self, incremental: bool) {
+        self.incremental = incremental;
+    }
+
+    pub fn set_explain_rebuild(&mut self, explain_rebuild: bool) {
+        self.explain_rebuild = explain_rebuild;
+    }
+
+    pub fn set_test_mode(&mut self, test_mode: bool) {
+        self.test_mode = test_mode;
+    }
+
+    pub fn set_repl_mode(&mut self, repl_mode: bool) {
+        self.repl_mode = repl_mode;
+    }
+
+    pub fn set_doc_format(&mut self, doc_format: DocFormat) {
+        self.doc_format = Some(doc_format);
+    }
+
+    pub fn set_emit_depfile(&mut self, emit_depfile: bool) {
+        self.emit_depfile = emit_depfile;
+    }
+
+    pub fn set_depfile(&mut self, depfile: PathBuf) {
+        self.depfile = Some(depfile);
+    }
+
     pub fn set_clean_tokens(&mut self) {
         self.clean_tokens = true;
     }
@@ -167,10 +240,62 @@ impl CompilerOptions {
         &mut self.llvm_backend
     }
 
+    pub fn get_lints_configuration(&self) -> &LintsConfiguration {
+        &self.lints
+    }
+
+    pub fn get_mut_lints_configuration(&mut self) -> &mut LintsConfiguration {
+        &mut self.lints
+    }
+
     pub fn get_build_dir(&self) -> &PathBuf {
         &self.build_dir
     }
 
+    pub fn get_include_dirs(&self) -> &[PathBuf] {
+        &self.include_dirs
+    }
+
+    pub fn get_jobs(&self) -> usize {
+        self.jobs
+    }
+
+    /// The arguments of the program to run once it is built, if it must run.
+    pub fn get_run_args(&self) -> Option<&[String]> {
+        self.run_args.as_deref()
+    }
+
+    pub fn get_incremental(&self) -> bool {
+        self.incremental
+    }
+
+    pub fn get_explain_rebuild(&self) -> bool {
+        self.explain_rebuild
+    }
+
+    /// Whether the '@test' functions are compiled, with a harness as the entry point.
+    pub fn get_test_mode(&self) -> bool {
+        self.test_mode
+    }
+
+    /// Whether 'thrushc repl' starts an interactive session instead of compiling files.
+    pub fn get_repl_mode(&self) -> bool {
+        self.repl_mode
+    }
+
+    /// The format of the site that 'thrushc doc' writes instead of compiling files.
+    pub fn get_doc_format(&self) -> Option<DocFormat> {
+        self.doc_format
+    }
+
+    pub fn get_emit_depfile(&self) -> bool {
+        self.emit_depfile
+    }
+
+    pub fn get_depfile(&self) -> Option<&PathBuf> {
+        self.depfile.as_ref()
+    }
+
     pub fn get_clean_tokens(&self) -> bool {
         self.clean_tokens
     }
@@ -41,3 +41,36 @@ impl LLVMModificatorPasses {
         passes
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LLVMSanitizer {
+    Address,
+    Undefined,
+}
+
+impl LLVMSanitizer {
+    /// The instrumentation pass, in the new pass manager syntax. Undefined behavior
+    /// checks are emitted by the code generator instead, so they have no pass.
+    pub fn get_pass(&self) -> Option<&'static str> {
+        match self {
+            LLVMSanitizer::Address => Some("asan"),
+            LLVMSanitizer::Undefined => None,
+        }
+    }
+
+    pub fn get_function_attribute(&self) -> Option<&'static str> {
+        match self {
+            LLVMSanitizer::Address => Some("sanitize_address"),
+            LLVMSanitizer::Undefined => None,
+        }
+    }
+
+    /// The flag that links the sanitizer runtime. Undefined behavior checks trap
+    /// in place and don't need one.
+    pub fn get_runtime_flag(&self) -> Option<&'static str> {
+        match self {
+            LLVMSanitizer::Address => Some("-fsanitize=address"),
+            LLVMSanitizer::Undefined => None,
+        }
+    }
+}
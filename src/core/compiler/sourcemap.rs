@@ -0,0 +1,129 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock, RwLockWriteGuard},
+};
+
+use crate::core::{
+    compiler::{options::CompilerFile, reader},
+    console::logging::{self, LoggingType},
+};
+
+pub type FileId = usize;
+
+#[derive(Debug)]
+pub struct SourceFile {
+    id: FileId,
+    path: PathBuf,
+    code: String,
+    lines: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: Arc<RwLock<Vec<Arc<SourceFile>>>>,
+}
+
+impl SourceFile {
+    pub fn new(id: FileId, path: PathBuf, code: String) -> Self {
+        let mut lines: Vec<usize> = Vec::with_capacity(code.len() / 32 + 1);
+
+        lines.push(0);
+
+        code.bytes().enumerate().for_each(|(idx, byte)| {
+            if byte == b'\n' {
+                lines.push(idx + 1);
+            }
+        });
+
+        Self {
+            id,
+            path,
+            code,
+            lines,
+        }
+    }
+}
+
+impl SourceFile {
+    pub fn get_id(&self) -> FileId {
+        self.id
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the zero-based line index that contains the byte offset.
+    pub fn get_line_index(&self, offset: usize) -> usize {
+        match self.lines.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line.saturating_sub(1),
+        }
+    }
+
+    pub fn get_line_start(&self, line: usize) -> usize {
+        self.lines.get(line).copied().unwrap_or(self.code.len())
+    }
+
+    /// Returns the text of the zero-based line, without its line terminator.
+    pub fn get_line_text(&self, line: usize) -> &str {
+        let start: usize = self.get_line_start(line);
+        let end: usize = self.get_line_start(line + 1);
+
+        self.code
+            .get(start..end)
+            .unwrap_or_default()
+            .trim_end_matches(['\n', '\r'])
+    }
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(&self, file: &CompilerFile) -> Arc<SourceFile> {
+        if let Some(source) = self.find(&file.path) {
+            return source;
+        }
+
+        let code: String = reader::get_file_source_code(&file.path);
+
+        self.add(file.path.clone(), code)
+    }
+
+    pub fn add(&self, path: PathBuf, code: String) -> Arc<SourceFile> {
+        let mut files: RwLockWriteGuard<Vec<Arc<SourceFile>>> =
+            self.files.write().unwrap_or_else(|_| {
+                logging::log(
+                    LoggingType::FrontEndPanic,
+                    "The source map was poisoned while registering a new source file.",
+                );
+
+                unreachable!()
+            });
+
+        let source: Arc<SourceFile> = Arc::new(SourceFile::new(files.len(), path, code));
+
+        files.push(source.clone());
+
+        source
+    }
+
+    pub fn get(&self, id: FileId) -> Option<Arc<SourceFile>> {
+        self.files.read().ok()?.get(id).cloned()
+    }
+
+    fn find(&self, path: &Path) -> Option<Arc<SourceFile>> {
+        self.files
+            .read()
+            .ok()?
+            .iter()
+            .find(|source| source.get_path() == path)
+            .cloned()
+    }
+}
@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use crate::core::{
+    compiler::{options::CompilerOptions, thrushc::TheThrushCompiler},
+    console::logging,
+};
+
+/// Writes a Makefile rule for every object, and one for the linked output when there
+/// is one, listing the source and the imported files they were built from.
+pub fn write(compiler: &TheThrushCompiler, linked_output: Option<&PathBuf>) {
+    let options: &CompilerOptions = compiler.get_options();
+
+    let depfile: PathBuf = match (options.get_depfile(), linked_output) {
+        (Some(depfile), _) => depfile.clone(),
+        (None, Some(output)) => output.with_extension("d"),
+        (None, None) => options.get_build_dir().join("thrushc.d"),
+    };
+
+    let mut rules: String = String::with_capacity(1024);
+
+    if let Some(output) = linked_output {
+        let mut dependencies: Vec<&PathBuf> =
+            compiler.get_dependencies().iter().flatten().collect();
+
+        dependencies.sort();
+        dependencies.dedup();
+
+        rules.push_str(&self::make_rule(output, &dependencies));
+    }
+
+    compiler
+        .get_compiled_files()
+        .iter()
+        .zip(compiler.get_dependencies())
+        .for_each(|(object, dependencies)| {
+            let dependencies: Vec<&PathBuf> = dependencies.iter().collect();
+            rules.push_str(&self::make_rule(object, &dependencies));
+        });
+
+    if std::fs::write(&depfile, rules).is_err() {
+        logging::log(
+            logging::LoggingType::Error,
+            &format!("Dependency file '{}' cannot be written.", depfile.display()),
+        );
+    }
+}
+
+fn make_rule(target: &Path, dependencies: &[&PathBuf]) -> String {
+    let mut rule: String = format!("{}:", self::escape(target));
+
+    dependencies.iter().for_each(|dependency| {
+        rule.push_str(" \\\n  ");
+        rule.push_str(&self::escape(dependency));
+    });
+
+    rule.push_str("\n\n");
+
+    rule
+}
+
+/// Escapes the characters that Make and Ninja would read as separators or variables.
+fn escape(path: &Path) -> String {
+    let mut escaped: String = String::with_capacity(path.as_os_str().len());
+
+    path.to_string_lossy().chars().for_each(|c| match c {
+        ' ' => escaped.push_str("\\ "),
+        '#' => escaped.push_str("\\#"),
+        '$' => escaped.push_str("$$"),
+        c => escaped.push(c),
+    });
+
+    escaped
+}
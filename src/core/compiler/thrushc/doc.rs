@@ -0,0 +1,701 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    process,
+    sync::Arc,
+};
+
+use ahash::AHashMap as HashMap;
+use colored::Colorize;
+
+use crate::{
+    core::{
+        compiler::{
+            constants::PROJECT_DOC_OUTPUT,
+            options::{CompilerFile, CompilerOptions, DocFormat},
+            sourcemap::{SourceFile, SourceMap},
+        },
+        console::logging::{self, LoggingType},
+    },
+    frontend::{
+        cimport,
+        lexer::Lexer,
+        parser::{Parser, ParserContext},
+        types::{
+            ast::Ast,
+            lexer::types::Tokens,
+            parser::stmts::types::{ThrushAttributes, ThrushDocs},
+        },
+        typesystem::types::Type,
+    },
+};
+
+/// The documented declarations of a source file.
+struct DocPage {
+    name: String,
+    items: Vec<DocItem>,
+}
+
+/// A function, structure, enum, constant or static of a source file.
+struct DocItem {
+    kind: DocItemKind,
+    name: String,
+    docs: Vec<String>,
+    attributes: Vec<String>,
+    members: Vec<DocMember>,
+    item_type: Type,
+}
+
+/// A parameter of a function, a field of a structure or a field of an enum.
+struct DocMember {
+    name: String,
+    kind: Type,
+    is_mutable: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DocItemKind {
+    Function,
+    Struct,
+    Enum,
+    Const,
+    Static,
+}
+
+impl DocItemKind {
+    fn as_keyword(self) -> &'static str {
+        match self {
+            DocItemKind::Function => "fn",
+            DocItemKind::Struct => "struct",
+            DocItemKind::Enum => "enum",
+            DocItemKind::Const => "const",
+            DocItemKind::Static => "static",
+        }
+    }
+
+    fn as_title(self) -> &'static str {
+        match self {
+            DocItemKind::Function => "Functions",
+            DocItemKind::Struct => "Structures",
+            DocItemKind::Enum => "Enums",
+            DocItemKind::Const => "Constants",
+            DocItemKind::Static => "Statics",
+        }
+    }
+}
+
+const DOC_ITEM_KINDS: [DocItemKind; 5] = [
+    DocItemKind::Struct,
+    DocItemKind::Enum,
+    DocItemKind::Function,
+    DocItemKind::Const,
+    DocItemKind::Static,
+];
+
+/// The page that documents every structure, so that types can link to it.
+type DocLinks = HashMap<String, String>;
+
+/// Writes the documentation of every file into '<build-dir>/doc' and exits.
+pub fn generate(options: &CompilerOptions, format: DocFormat) -> ! {
+    let source_map: SourceMap = SourceMap::new();
+
+    let mut pages: Vec<DocPage> = Vec::with_capacity(options.get_files().len());
+    let mut errors: usize = 0;
+
+    let base: PathBuf = self::get_common_directory(options.get_files());
+
+    for file in options.get_files() {
+        match self::document_file(options, &source_map, file, &base) {
+            Some(page) => pages.push(page),
+            None => errors += 1,
+        }
+    }
+
+    if errors > 0 {
+        logging::log(
+            LoggingType::Error,
+            &format!(
+                "The documentation was not written: {} file(s) failed to parse.",
+                errors
+            ),
+        );
+
+        process::exit(1);
+    }
+
+    let output: PathBuf = options.get_build_dir().join(PROJECT_DOC_OUTPUT);
+
+    if let Err(error) = fs::create_dir_all(&output) {
+        logging::log(
+            LoggingType::Panic,
+            &format!("Could not create '{}': {}.", output.display(), error),
+        );
+    }
+
+    let extension: &str = match format {
+        DocFormat::Markdown => "md",
+        DocFormat::Html => "html",
+    };
+
+    let mut links: DocLinks = DocLinks::with_capacity(100);
+
+    pages.iter().for_each(|page| {
+        page.items
+            .iter()
+            .filter(|item| item.kind == DocItemKind::Struct)
+            .for_each(|item| {
+                links.insert(item.name.clone(), format!("{}.{}", page.name, extension));
+            });
+    });
+
+    let index: String = match format {
+        DocFormat::Markdown => self::render_markdown_index(&pages, extension),
+        DocFormat::Html => self::render_html_index(&pages, extension),
+    };
+
+    self::write_page(&output.join(format!("index.{}", extension)), &index);
+
+    pages.iter().for_each(|page| {
+        let content: String = match format {
+            DocFormat::Markdown => self::render_markdown_page(page, &links),
+            DocFormat::Html => self::render_html_page(page, &links),
+        };
+
+        self::write_page(
+            &output.join(format!("{}.{}", page.name, extension)),
+            &content,
+        );
+    });
+
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "{} {}\n",
+            "Documentation".custom_color((141, 141, 142)).bold(),
+            output.display()
+        ),
+    );
+
+    process::exit(0);
+}
+
+fn document_file(
+    options: &CompilerOptions,
+    source_map: &SourceMap,
+    file: &CompilerFile,
+    base: &Path,
+) -> Option<DocPage> {
+    let source: Arc<SourceFile> = source_map.load(file);
+
+    let (tokens, lexer_errors): (Tokens, usize) = Lexer::lex(&source, source_map);
+
+    let (tokens, cimport_errors, _): (Tokens, usize, Vec<PathBuf>) = cimport::expand(
+        tokens,
+        source_map,
+        options.get_include_dirs(),
+        options.get_lints_configuration(),
+    );
+
+    let (parser_context, parser_throwed_errors): (ParserContext, bool) =
+        Parser::parse(&tokens, source_map);
+
+    if lexer_errors + cimport_errors > 0 || parser_throwed_errors {
+        return None;
+    }
+
+    let name: String = self::get_page_name(file, base);
+
+    // The declarations expanded from '@cimport' headers belong to other files.
+    let items: Vec<DocItem> = parser_context
+        .get_ast()
+        .iter()
+        .filter(|ast| ast.get_span().get_file() == source.get_id())
+        .filter_map(self::document_item)
+        .collect();
+
+    Some(DocPage { name, items })
+}
+
+/// The directory that holds every documented file.
+fn get_common_directory(files: &[CompilerFile]) -> PathBuf {
+    let mut directories = files.iter().filter_map(|file| file.path.parent());
+
+    let Some(first) = directories.next() else {
+        return PathBuf::new();
+    };
+
+    directories.fold(first.to_path_buf(), |common, directory| {
+        common
+            .ancestors()
+            .find(|ancestor| directory.starts_with(ancestor))
+            .map_or_else(PathBuf::new, Path::to_path_buf)
+    })
+}
+
+/// The path of the file from the common directory, so that 'a/util.thrush' and
+/// 'b/util.thrush' become the pages 'a.util' and 'b.util'.
+fn get_page_name(file: &CompilerFile, base: &Path) -> String {
+    let relative: PathBuf = file
+        .path
+        .strip_prefix(base)
+        .unwrap_or(&file.path)
+        .with_extension("");
+
+    let name: Vec<String> = relative
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect();
+
+    let name: String = if name.is_empty() {
+        file.name.clone()
+    } else {
+        name.join(".")
+    };
+
+    // The index page takes this name, so a source named after it gets another.
+    if name.eq_ignore_ascii_case("index") {
+        return format!("{}_", name);
+    }
+
+    name
+}
+
+fn document_item(ast: &Ast) -> Option<DocItem> {
+    match ast {
+        Ast::Function {
+            name,
+            parameters,
+            return_type,
+            attributes,
+            docs,
+            ..
+        } => Some(self::new_item(
+            DocItemKind::Function,
+            name,
+            docs,
+            attributes,
+            parameters
+                .iter()
+                .filter_map(|parameter| match parameter {
+                    Ast::FunctionParameter {
+                        name,
+                        kind,
+                        metadata,
+                        ..
+                    } => Some(DocMember {
+                        name: name.to_string(),
+                        kind: kind.clone(),
+                        is_mutable: metadata.is_mutable(),
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            return_type.clone(),
+        )),
+
+        Ast::Struct {
+            name,
+            fields,
+            attributes,
+            docs,
+            ..
+        } => Some(self::new_item(
+            DocItemKind::Struct,
+            name,
+            docs,
+            attributes,
+            fields
+                .1
+                .iter()
+                .map(|(name, kind, ..)| DocMember {
+                    name: name.to_string(),
+                    kind: kind.clone(),
+                    is_mutable: false,
+                })
+                .collect(),
+            Type::Void,
+        )),
+
+        Ast::Enum {
+            name,
+            fields,
+            attributes,
+            docs,
+            ..
+        } => Some(self::new_item(
+            DocItemKind::Enum,
+            name,
+            docs,
+            attributes,
+            fields
+                .iter()
+                .map(|(name, value)| DocMember {
+                    name: name.to_string(),
+                    kind: value.get_value_type().cloned().unwrap_or_default(),
+                    is_mutable: false,
+                })
+                .collect(),
+            Type::Void,
+        )),
+
+        Ast::Const {
+            name,
+            kind,
+            attributes,
+            docs,
+            ..
+        } => Some(self::new_item(
+            DocItemKind::Const,
+            name,
+            docs,
+            attributes,
+            Vec::new(),
+            kind.clone(),
+        )),
+
+        Ast::Static {
+            name,
+            kind,
+            attributes,
+            docs,
+            ..
+        } => Some(self::new_item(
+            DocItemKind::Static,
+            name,
+            docs,
+            attributes,
+            Vec::new(),
+            kind.clone(),
+        )),
+
+        _ => None,
+    }
+}
+
+fn new_item(
+    kind: DocItemKind,
+    name: &str,
+    docs: &ThrushDocs,
+    attributes: &ThrushAttributes,
+    members: Vec<DocMember>,
+    item_type: Type,
+) -> DocItem {
+    DocItem {
+        kind,
+        name: name.to_string(),
+        docs: docs.iter().map(|line| line.to_string()).collect(),
+        attributes: attributes
+            .iter()
+            .map(|attribute| attribute.to_string())
+            .collect(),
+        members,
+        item_type,
+    }
+}
+
+fn write_page(path: &Path, content: &str) {
+    if let Err(error) = fs::write(path, content) {
+        logging::log(
+            LoggingType::Panic,
+            &format!("Could not write '{}': {}.", path.display(), error),
+        );
+    }
+}
+
+/* ######################################################################
+
+
+    SIGNATURES - START
+
+
+########################################################################*/
+
+/// The declaration as it is written in the source, without its body.
+fn get_signature(item: &DocItem) -> String {
+    let attributes: String = item
+        .attributes
+        .iter()
+        .map(|attribute| format!(" {}", attribute))
+        .collect();
+
+    match item.kind {
+        DocItemKind::Function => {
+            let parameters: Vec<String> = item
+                .members
+                .iter()
+                .map(|member| {
+                    format!(
+                        "{}{}: {}",
+                        if member.is_mutable { "mut " } else { "" },
+                        member.name,
+                        self::format_type(&member.kind, &mut |name| name.to_string())
+                    )
+                })
+                .collect();
+
+            format!(
+                "fn {}({}) {}{}",
+                item.name,
+                parameters.join(", "),
+                self::format_type(&item.item_type, &mut |name| name.to_string()),
+                attributes
+            )
+        }
+
+        DocItemKind::Struct | DocItemKind::Enum => {
+            format!("{} {}{}", item.kind.as_keyword(), item.name, attributes)
+        }
+
+        DocItemKind::Const | DocItemKind::Static => format!(
+            "{} {}: {}{}",
+            item.kind.as_keyword(),
+            item.name,
+            self::format_type(&item.item_type, &mut |name| name.to_string()),
+            attributes
+        ),
+    }
+}
+
+/// Formats a type as it is written in the source, with every structure passed through 'link'.
+fn format_type(kind: &Type, link: &mut dyn FnMut(&str) -> String) -> String {
+    match kind {
+        Type::Struct(name, ..) => link(name),
+        Type::Mut(inner) => format!("mut {}", self::format_type(inner, link)),
+        Type::Const(inner) => format!("const {}", self::format_type(inner, link)),
+        Type::Ptr(Some(inner)) => format!("ptr[{}]", self::format_type(inner, link)),
+        Type::FixedArray(inner, size) => format!("[{}; {}]", self::format_type(inner, link), size),
+        Type::Array(inner) => format!("[{}]", self::format_type(inner, link)),
+        kind => kind.to_string(),
+    }
+}
+
+/* ######################################################################
+
+
+    SIGNATURES - END
+
+
+########################################################################*/
+
+/* ######################################################################
+
+
+    MARKDOWN - START
+
+
+########################################################################*/
+
+fn render_markdown_index(pages: &[DocPage], extension: &str) -> String {
+    let mut content: String = String::from("# Documentation\n\n");
+
+    pages.iter().for_each(|page| {
+        content.push_str(&format!(
+            "- [{}]({}.{}) ({} items)\n",
+            page.name,
+            page.name,
+            extension,
+            page.items.len()
+        ));
+    });
+
+    content
+}
+
+fn render_markdown_page(page: &DocPage, links: &DocLinks) -> String {
+    let mut content: String = format!("# {}\n\n[Index](index.md)\n", page.name);
+
+    let mut link = |name: &str| match links.get(name) {
+        Some(file) => format!("[{}]({}#{})", name, file, name),
+        None => format!("`{}`", name),
+    };
+
+    DOC_ITEM_KINDS.iter().for_each(|kind| {
+        let items: Vec<&DocItem> = page
+            .items
+            .iter()
+            .filter(|item| item.kind == *kind)
+            .collect();
+
+        if items.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!("\n## {}\n", kind.as_title()));
+
+        items.iter().for_each(|item| {
+            content.push_str(&format!(
+                "\n<a id=\"{}\"></a>\n\n### {}\n\n```thrush\n{}\n```\n",
+                item.name,
+                item.name,
+                self::get_signature(item)
+            ));
+
+            if !item.docs.is_empty() {
+                content.push_str(&format!("\n{}\n", item.docs.join("\n")));
+            }
+
+            if !item.members.is_empty() {
+                content.push('\n');
+
+                item.members.iter().for_each(|member| {
+                    content.push_str(&format!(
+                        "- `{}`: {}\n",
+                        member.name,
+                        self::format_type(&member.kind, &mut link)
+                    ));
+                });
+            }
+
+            match item.kind {
+                DocItemKind::Function | DocItemKind::Const | DocItemKind::Static => {
+                    content.push_str(&format!(
+                        "\n**Type:** {}\n",
+                        self::format_type(&item.item_type, &mut link)
+                    ));
+                }
+
+                DocItemKind::Struct | DocItemKind::Enum => (),
+            }
+        });
+    });
+
+    content
+}
+
+/* ######################################################################
+
+
+    MARKDOWN - END
+
+
+########################################################################*/
+
+/* ######################################################################
+
+
+    HTML - START
+
+
+########################################################################*/
+
+const HTML_STYLE: &str = "body{font-family:sans-serif;max-width:60em;margin:auto;padding:1em}\
+pre{background:#f4f4f4;padding:.5em}code{font-family:monospace}";
+
+fn render_html_index(pages: &[DocPage], extension: &str) -> String {
+    let mut content: String = String::from("<h1>Documentation</h1>\n<ul>\n");
+
+    pages.iter().for_each(|page| {
+        content.push_str(&format!(
+            "<li><a href=\"{}.{}\">{}</a> ({} items)</li>\n",
+            self::escape_html(&page.name),
+            extension,
+            self::escape_html(&page.name),
+            page.items.len()
+        ));
+    });
+
+    content.push_str("</ul>\n");
+
+    self::wrap_html("Documentation", &content)
+}
+
+fn render_html_page(page: &DocPage, links: &DocLinks) -> String {
+    let mut content: String = format!(
+        "<h1>{}</h1>\n<p><a href=\"index.html\">Index</a></p>\n",
+        self::escape_html(&page.name)
+    );
+
+    let mut link = |name: &str| match links.get(name) {
+        Some(file) => format!(
+            "<a href=\"{}#{}\">{}</a>",
+            self::escape_html(file),
+            self::escape_html(name),
+            self::escape_html(name)
+        ),
+        None => self::escape_html(name),
+    };
+
+    DOC_ITEM_KINDS.iter().for_each(|kind| {
+        let items: Vec<&DocItem> = page
+            .items
+            .iter()
+            .filter(|item| item.kind == *kind)
+            .collect();
+
+        if items.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!("<h2>{}</h2>\n", kind.as_title()));
+
+        items.iter().for_each(|item| {
+            content.push_str(&format!(
+                "<h3 id=\"{}\">{}</h3>\n<pre><code>{}</code></pre>\n",
+                self::escape_html(&item.name),
+                self::escape_html(&item.name),
+                self::escape_html(&self::get_signature(item))
+            ));
+
+            if !item.docs.is_empty() {
+                content.push_str(&format!(
+                    "<p>{}</p>\n",
+                    self::escape_html(&item.docs.join("\n"))
+                ));
+            }
+
+            if !item.members.is_empty() {
+                content.push_str("<ul>\n");
+
+                item.members.iter().for_each(|member| {
+                    content.push_str(&format!(
+                        "<li><code>{}</code>: <code>{}</code></li>\n",
+                        self::escape_html(&member.name),
+                        self::format_type(&member.kind, &mut link)
+                    ));
+                });
+
+                content.push_str("</ul>\n");
+            }
+
+            match item.kind {
+                DocItemKind::Function | DocItemKind::Const | DocItemKind::Static => {
+                    content.push_str(&format!(
+                        "<p><b>Type:</b> <code>{}</code></p>\n",
+                        self::format_type(&item.item_type, &mut link)
+                    ));
+                }
+
+                DocItemKind::Struct | DocItemKind::Enum => (),
+            }
+        });
+    });
+
+    self::wrap_html(&page.name, &content)
+}
+
+fn wrap_html(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        self::escape_html(title),
+        HTML_STYLE,
+        body
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* ######################################################################
+
+
+    HTML - END
+
+
+########################################################################*/
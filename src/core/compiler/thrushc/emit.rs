@@ -99,6 +99,17 @@ pub fn llvm_before_optimization(
 
     emitters::cleaner::auto_clean(compiler_options);
 
+    if compiler_options.contains_emitable(Emitable::CFGDot) {
+        if let Err(error) =
+            emitters::dot::cfg::emit_cfg_dot(compiler, llvm_module, build_dir, &file.name)
+        {
+            logging::log(LoggingType::Error, &error.to_string());
+            interrupt::archive_compilation_unit(compiler, archive_time, file)?;
+        }
+
+        return Ok(true);
+    }
+
     if compiler_options.contains_emitable(Emitable::RawLLVMIR) {
         if let Err(error) =
             emitters::llvmir::emit_llvm_ir(compiler, llvm_module, build_dir, &file.name, true)
@@ -175,5 +186,51 @@ pub fn after_frontend(
         }
     }
 
-    false
+    match emited {
+        Emited::Tokens(tokens) if compiler_options.contains_emitable(Emitable::TokensJson) => {
+            if let Err(error) =
+                emitters::json::tokens::emit_tokens_json(compiler, tokens, build_dir, &file.name)
+            {
+                logging::log(LoggingType::Error, &error.to_string());
+                return false;
+            }
+
+            true
+        }
+
+        Emited::Ast(stmts) if compiler_options.contains_emitable(Emitable::ASTJson) => {
+            if let Err(error) =
+                emitters::json::ast::emit_ast_json(compiler, stmts, build_dir, &file.name)
+            {
+                logging::log(LoggingType::Error, &error.to_string());
+                return false;
+            }
+
+            true
+        }
+
+        Emited::Ast(stmts) if compiler_options.contains_emitable(Emitable::CallGraphDot) => {
+            if let Err(error) =
+                emitters::dot::callgraph::emit_callgraph_dot(compiler, stmts, build_dir, &file.name)
+            {
+                logging::log(LoggingType::Error, &error.to_string());
+                return false;
+            }
+
+            true
+        }
+
+        Emited::Ast(stmts) if compiler_options.contains_emitable(Emitable::CHeader) => {
+            if let Err(error) =
+                emitters::cheader::emit_c_header(compiler, stmts, build_dir, &file.name)
+            {
+                logging::log(LoggingType::Error, &error.to_string());
+                return false;
+            }
+
+            true
+        }
+
+        _ => false,
+    }
 }
@@ -1,11 +1,12 @@
 use std::{
+    fs,
     path::{Path, PathBuf},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use colored::Colorize;
 use inkwell::{
-    module::Module,
+    module::{Linkage, Module},
     targets::{FileType, TargetMachine},
 };
 
@@ -47,6 +48,14 @@ pub fn obj_compilation(
         file_name
     ));
 
+    self::obj_write(llvm_module, target_machine, obj_file_path)
+}
+
+pub fn obj_write(
+    llvm_module: &Module,
+    target_machine: &TargetMachine,
+    obj_file_path: PathBuf,
+) -> PathBuf {
     target_machine
         .write_to_file(llvm_module, FileType::Object, &obj_file_path)
         .unwrap_or_else(|_| {
@@ -60,3 +69,60 @@ pub fn obj_compilation(
 
     obj_file_path
 }
+
+/// Names of the symbols an object defines for other objects, used to index static libraries.
+pub fn exported_symbols(llvm_module: &Module) -> Vec<String> {
+    let is_exported = |linkage: Linkage| {
+        !matches!(
+            linkage,
+            Linkage::Private | Linkage::Internal | Linkage::LinkerPrivate
+        )
+    };
+
+    let functions = llvm_module
+        .get_functions()
+        .filter(|function| function.count_basic_blocks() > 0 && is_exported(function.get_linkage()))
+        .map(|function| function.get_name().to_string_lossy().to_string());
+
+    let globals = llvm_module
+        .get_globals()
+        .filter(|global| global.get_initializer().is_some() && is_exported(global.get_linkage()))
+        .map(|global| global.get_name().to_string_lossy().to_string());
+
+    functions.chain(globals).collect()
+}
+
+pub fn fresh_compilation(
+    compiler: &mut TheThrushCompiler,
+    archive_time: Instant,
+    file: &CompilerFile,
+) -> Result<(), ()> {
+    compiler.thrushc_time += archive_time.elapsed();
+
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "{} {} {}\n",
+            "Compilation".custom_color((141, 141, 142)).bold(),
+            "FRESH".bright_green().bold(),
+            &file.path.to_string_lossy()
+        ),
+    );
+
+    Ok(())
+}
+
+pub fn obj_output(obj_file_path: &Path, output: &Path) -> Result<Duration, ()> {
+    let start_time: Instant = Instant::now();
+
+    if fs::copy(obj_file_path, output).is_err() {
+        logging::log(
+            logging::LoggingType::Error,
+            &format!("Object file '{}' cannot be written.", output.display()),
+        );
+
+        return Err(());
+    }
+
+    Ok(start_time.elapsed())
+}
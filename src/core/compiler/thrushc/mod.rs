@@ -1,9 +1,20 @@
+mod depfile;
+pub mod doc;
 mod emit;
 mod finisher;
 mod interrupt;
+pub mod repl;
 
 use std::{
+    collections::BTreeMap,
+    fs,
     path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
     time::{Duration, Instant},
 };
 
@@ -19,45 +30,96 @@ use inkwell::{
 
 use crate::{
     backend::{
-        linking::compilers::{clang::Clang, gcc::GCC},
-        llvm::{self, compiler::context::LLVMCodeGenContext},
+        linking::{
+            archiver::Archiver,
+            compilers::{clang::Clang, gcc::GCC},
+            linkers::ld::LD,
+        },
+        llvm::{
+            self,
+            compiler::{
+                context::LLVMCodeGenContext,
+                coverage::LLVMCoverageContext,
+                debug::LLVMDebugContext,
+                harness::{self, TestFunction},
+            },
+        },
     },
     core::{
         compiler::{
             backends::llvm::LLVMBackend,
-            linking::LinkingCompilersConfiguration,
+            incremental::IncrementalCache,
+            linking::{CrateType, LinkingCompilersConfiguration},
             options::{CompilerFile, CompilerOptions, Emited, ThrushOptimization},
-            reader,
+            passes::LLVMSanitizer,
+            sourcemap::{SourceFile, SourceMap},
         },
-        console::logging::{self, LoggingType},
+        console::logging::{self, CapturedOutput, LoggingType, Recording},
         diagnostic::diagnostician::Diagnostician,
     },
     frontend::{
-        lexer::{Lexer, token::Token},
+        cimport,
+        lexer::Lexer,
         parser::{Parser, ParserContext},
         semantic::SemanticAnalyzer,
-        types::ast::Ast,
+        types::{ast::Ast, lexer::types::Tokens},
     },
 };
 
+/// The position of the file, whether it failed, and the compiler that compiled it.
+type CompiledUnit<'thrushc> = (usize, bool, TheThrushCompiler<'thrushc>);
+
 #[derive(Debug)]
 pub struct TheThrushCompiler<'thrushc> {
     compiled: Vec<PathBuf>,
+    exported_symbols: Vec<Vec<String>>,
+    dependencies: Vec<Vec<PathBuf>>,
+    tests: Vec<TestFunction>,
     uncompiled: &'thrushc [CompilerFile],
 
     options: &'thrushc CompilerOptions,
+    incremental: Option<IncrementalCache>,
+    source_map: SourceMap,
     linking_time: Duration,
     thrushc_time: Duration,
+    errors: usize,
+    linked: bool,
 }
 
 impl<'thrushc> TheThrushCompiler<'thrushc> {
     pub fn new(files: &'thrushc [CompilerFile], options: &'thrushc CompilerOptions) -> Self {
+        // Test builds compile code that normal builds leave out.
+        let incremental: Option<IncrementalCache> =
+            if options.get_incremental() && !options.get_was_emited() && !options.get_test_mode() {
+                IncrementalCache::new(options)
+            } else {
+                None
+            };
+
+        Self::with_shared(files, options, SourceMap::new(), incremental)
+    }
+
+    /// A compiler that shares the source map and the incremental cache of another, as
+    /// every file of a parallel build does.
+    fn with_shared(
+        files: &'thrushc [CompilerFile],
+        options: &'thrushc CompilerOptions,
+        source_map: SourceMap,
+        incremental: Option<IncrementalCache>,
+    ) -> Self {
         Self {
             compiled: Vec::with_capacity(files.len()),
+            exported_symbols: Vec::with_capacity(files.len()),
+            dependencies: Vec::with_capacity(files.len()),
+            tests: Vec::with_capacity(100),
             uncompiled: files,
             options,
+            incremental,
+            source_map,
             linking_time: Duration::default(),
             thrushc_time: Duration::default(),
+            errors: 0,
+            linked: false,
         }
     }
 
@@ -78,9 +140,47 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
             );
         }
 
-        self.uncompiled.iter().for_each(|file| {
-            interrumped = self.compile_with_llvm(file).is_err();
-        });
+        // A harness left by an earlier test build would report its old results.
+        let harness_output: Option<&PathBuf> = self
+            .options
+            .get_llvm_backend_options()
+            .get_linking_compilers_configuration()
+            .get_output()
+            .filter(|_| self.options.get_test_mode());
+
+        if let Some(output) = harness_output {
+            let _ = fs::remove_file(output);
+        }
+
+        let jobs: usize = self.options.get_jobs().min(self.uncompiled.len());
+
+        if jobs > 1 {
+            interrumped = self.compile_in_parallel(jobs);
+        } else {
+            self.uncompiled.iter().for_each(|file| {
+                interrumped |= self.compile_with_llvm(file).is_err();
+            });
+        }
+
+        if self.options.get_test_mode()
+            && !interrumped
+            && self.errors == 0
+            && !self.options.get_was_emited()
+        {
+            interrumped |= self.compile_test_harness().is_err();
+        }
+
+        if self.errors > 0 {
+            logging::write(
+                logging::OutputIn::Stderr,
+                &format!(
+                    "\n{} {} {} error(s) emitted.\n",
+                    "Compilation".custom_color((141, 141, 142)).bold(),
+                    "ABORTED".bright_red().bold(),
+                    self.errors
+                ),
+            );
+        }
 
         let llvm_backend: &LLVMBackend = self.options.get_llvm_backend_options();
 
@@ -103,84 +203,213 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
         let linking_compiler_configuration: &LinkingCompilersConfiguration =
             llvm_backend.get_linking_compilers_configuration();
 
-        if linking_compiler_configuration.get_use_clang() {
-            match Clang::new(
+        let target_triple: TargetTriple = llvm_backend.create_target_triple();
+
+        let linked: Result<Duration, ()> = match (
+            linking_compiler_configuration.get_crate_type(),
+            linking_compiler_configuration.get_output(),
+        ) {
+            (CrateType::StaticLibrary, Some(output)) => {
+                Archiver::new(self.get_compiled_files(), &self.exported_symbols, output).archive()
+            }
+
+            (CrateType::Object, Some(output)) if self.get_compiled_files().len() == 1 => {
+                finisher::obj_output(&self.get_compiled_files()[0], output)
+            }
+
+            _ if linking_compiler_configuration.get_use_ld() => LD::new(
                 self.get_compiled_files(),
                 linking_compiler_configuration,
-                llvm_backend.get_target_triple(),
+                &target_triple,
+                llvm_backend.get_reloc_mode(),
+                llvm_backend.get_sanitizers(),
             )
-            .link()
-            {
-                Ok(clang_time) => {
-                    self.linking_time += clang_time;
-
-                    logging::write(
-                        logging::OutputIn::Stdout,
-                        &format!(
-                            "{} {}\n",
-                            "Linking".custom_color((141, 141, 142)).bold(),
-                            "FINISHED".bright_green().bold()
-                        ),
-                    );
-                }
-                Err(_) => {
-                    logging::write(
-                        logging::OutputIn::Stderr,
-                        &format!(
-                            "\r{} {}\n",
-                            "Linking".custom_color((141, 141, 142)).bold(),
-                            "FAILED".bright_red().bold()
-                        ),
-                    );
-                }
-            }
-        } else if linking_compiler_configuration.get_use_gcc() {
-            match GCC::new(self.get_compiled_files(), linking_compiler_configuration).link() {
-                Ok(gcc_time) => {
-                    self.linking_time += gcc_time;
-
-                    logging::write(
-                        logging::OutputIn::Stdout,
-                        &format!(
-                            "{} {}\n",
-                            "Linking".custom_color((141, 141, 142)).bold(),
-                            "FINISHED".bright_green().bold()
-                        ),
-                    );
-                }
-                Err(_) => {
-                    logging::write(
-                        logging::OutputIn::Stderr,
-                        &format!(
-                            "\r{} {}\n",
-                            "Linking".custom_color((141, 141, 142)).bold(),
-                            "FAILED".bright_red().bold()
-                        ),
-                    );
-                }
+            .link(),
+
+            _ if linking_compiler_configuration.get_use_clang() => Clang::new(
+                self.get_compiled_files(),
+                linking_compiler_configuration,
+                &target_triple,
+                llvm_backend.get_sanitizers(),
+            )
+            .link(),
+
+            _ if linking_compiler_configuration.get_use_gcc() => GCC::new(
+                self.get_compiled_files(),
+                linking_compiler_configuration,
+                llvm_backend.get_sanitizers(),
+            )
+            .link(),
+
+            _ => {
+                logging::log(
+                    LoggingType::Error,
+                    "No compiler for linking was specified, use -clang, -gcc or -ld or see --help.",
+                );
+
+                Err(())
             }
-        } else {
-            logging::log(
-                LoggingType::Error,
-                "No compiler for linking was specified, use -clang or -gcc or see --help.",
-            );
+        };
 
-            logging::write(
-                logging::OutputIn::Stderr,
-                &format!(
-                    "\r{} {}\n",
-                    "Linking".custom_color((141, 141, 142)).bold(),
-                    "FAILED".bright_red().bold()
-                ),
+        if self.options.get_emit_depfile() {
+            depfile::write(
+                self,
+                linking_compiler_configuration
+                    .get_output()
+                    .filter(|_| linked.is_ok()),
             );
         }
 
+        match linked {
+            Ok(linking_time) => {
+                self.linking_time += linking_time;
+
+                // A dry run only prints the linker command.
+                self.linked = !linking_compiler_configuration.get_link_dry_run();
+
+                logging::write(
+                    logging::OutputIn::Stdout,
+                    &format!(
+                        "{} {}\n",
+                        "Linking".custom_color((141, 141, 142)).bold(),
+                        "FINISHED".bright_green().bold()
+                    ),
+                );
+            }
+            Err(_) => {
+                self.errors += 1;
+
+                logging::write(
+                    logging::OutputIn::Stderr,
+                    &format!(
+                        "\r{} {}\n",
+                        "Linking".custom_color((141, 141, 142)).bold(),
+                        "FAILED".bright_red().bold()
+                    ),
+                );
+            }
+        }
+
         (self.thrushc_time.as_millis(), self.linking_time.as_millis())
     }
 
+    /// Compiles the files on a pool of 'jobs' threads. Every file gets its own compiler,
+    /// and its output is printed once the output of every file before it was printed.
+    fn compile_in_parallel(&mut self, jobs: usize) -> bool {
+        let files: &'thrushc [CompilerFile] = self.uncompiled;
+        let options: &'thrushc CompilerOptions = self.options;
+        let source_map: SourceMap = self.source_map.clone();
+        let incremental: Option<IncrementalCache> = self.incremental.clone();
+
+        let next_file: AtomicUsize = AtomicUsize::new(0);
+
+        let (sender, receiver): (Sender<CompiledUnit>, Receiver<CompiledUnit>) = mpsc::channel();
+
+        let mut interrumped: bool = false;
+
+        logging::reset_ordered_output();
+
+        // The files compile at the same time, so their times can't be added up.
+        let parallel_time: Instant = Instant::now();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs {
+                let sender: Sender<CompiledUnit> = sender.clone();
+
+                let next_file: &AtomicUsize = &next_file;
+                let source_map: &SourceMap = &source_map;
+                let incremental: &Option<IncrementalCache> = &incremental;
+
+                scope.spawn(move || {
+                    loop {
+                        let idx: usize = next_file.fetch_add(1, Ordering::Relaxed);
+
+                        let Some(file) = files.get(idx) else {
+                            break;
+                        };
+
+                        let mut unit: TheThrushCompiler = TheThrushCompiler::with_shared(
+                            std::slice::from_ref(file),
+                            options,
+                            source_map.clone(),
+                            incremental.clone(),
+                        );
+
+                        let (result, output): (Result<(), ()>, CapturedOutput) =
+                            logging::capture(|| unit.compile_with_llvm(file));
+
+                        // Printed here rather than by the receiver, so a fatal error of
+                        // another file still finds it.
+                        logging::replay_in_order(idx, output);
+
+                        if sender.send((idx, result.is_err(), unit)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+
+            drop(sender);
+
+            let mut pending: BTreeMap<usize, CompiledUnit> = BTreeMap::new();
+            let mut next_to_merge: usize = 0;
+
+            for compiled_unit in receiver {
+                pending.insert(compiled_unit.0, compiled_unit);
+
+                while let Some((_, failed, unit)) = pending.remove(&next_to_merge) {
+                    interrumped |= failed;
+
+                    self.compiled.extend(unit.compiled);
+                    self.exported_symbols.extend(unit.exported_symbols);
+                    self.dependencies.extend(unit.dependencies);
+                    self.tests.extend(unit.tests);
+                    self.errors += unit.errors;
+
+                    next_to_merge += 1;
+                }
+            }
+        });
+
+        self.thrushc_time += parallel_time.elapsed();
+
+        interrumped
+    }
+
     fn compile_with_llvm(&mut self, file: &'thrushc CompilerFile) -> Result<(), ()> {
         let archive_time: Instant = Instant::now();
 
+        let source_map: SourceMap = self.source_map.clone();
+        let source: Arc<SourceFile> = source_map.load(file);
+
+        if let Some(incremental) = self.incremental.as_ref() {
+            match incremental.lookup(file, &source) {
+                Ok(cached) => {
+                    logging::write(logging::OutputIn::Stderr, &cached.warnings);
+
+                    self.add_compiled_file(
+                        cached.object,
+                        cached.exported_symbols,
+                        self::get_dependencies(file, cached.headers),
+                    );
+
+                    return finisher::fresh_compilation(self, archive_time, file);
+                }
+
+                Err(reason) if self.options.get_explain_rebuild() => logging::write(
+                    logging::OutputIn::Stdout,
+                    &format!(
+                        "{} {} {}\n",
+                        "Rebuilding".custom_color((141, 141, 142)).bold(),
+                        &file.path.to_string_lossy(),
+                        format!("because {}.", reason).custom_color((141, 141, 142))
+                    ),
+                ),
+
+                Err(_) => (),
+            }
+        }
+
         logging::write(
             logging::OutputIn::Stdout,
             &format!(
@@ -191,24 +420,28 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
             ),
         );
 
-        let source_code: String = reader::get_file_source_code(&file.path);
+        // The warnings of the file are kept with its cached object.
+        let recording: Recording = logging::record();
 
         let llvm_backend: &LLVMBackend = self.options.get_llvm_backend_options();
         let build_dir: &PathBuf = self.options.get_build_dir();
 
-        let tokens: Vec<Token> = match Lexer::lex(&source_code, file) {
-            Ok(tokens) => tokens,
-            Err(error) => {
-                logging::log(logging::LoggingType::FrontEndPanic, &error.display());
-                unreachable!()
-            }
-        };
+        let (tokens, lexer_errors): (Tokens, usize) = Lexer::lex(&source, &source_map);
+
+        let (tokens, cimport_errors, headers): (Tokens, usize, Vec<PathBuf>) = cimport::expand(
+            tokens,
+            &source_map,
+            self.options.get_include_dirs(),
+            self.options.get_lints_configuration(),
+        );
+
+        let lexer_errors: usize = lexer_errors + cimport_errors;
 
         if emit::after_frontend(self, build_dir, file, Emited::Tokens(&tokens)) {
             return finisher::archive_compilation(self, archive_time, file);
         }
 
-        let parser: (ParserContext, bool) = Parser::parse(&tokens, file);
+        let parser: (ParserContext, bool) = Parser::parse(&tokens, &source_map);
 
         let parser_result: (ParserContext, bool) = parser;
         let parser_throwed_errors: bool = parser_result.1;
@@ -217,68 +450,83 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
 
         let ast: &[Ast] = parser_context.get_ast();
 
+        let lexer_throwed_errors: bool = lexer_errors > 0;
+
+        let mut semantic_analyzer: SemanticAnalyzer =
+            SemanticAnalyzer::new(ast, &source_map, self.options.get_lints_configuration());
+
         let semantic_analysis_throwed_errors: bool =
-            SemanticAnalyzer::new(ast, file).check(parser_throwed_errors);
+            semantic_analyzer.check(lexer_throwed_errors || parser_throwed_errors);
 
-        if parser_throwed_errors || semantic_analysis_throwed_errors {
-            return finisher::archive_compilation(self, archive_time, file);
+        self.errors +=
+            lexer_errors + parser_context.get_errors_count() + semantic_analyzer.get_errors_count();
+
+        if lexer_throwed_errors || parser_throwed_errors || semantic_analysis_throwed_errors {
+            return interrupt::archive_compilation_unit(self, archive_time, file);
         }
 
         if emit::after_frontend(self, build_dir, file, Emited::Ast(ast)) {
             return finisher::archive_compilation(self, archive_time, file);
         }
 
+        if self.options.get_test_mode() {
+            self.add_tests(file, &source, ast);
+        }
+
         let llvm_context: Context = Context::create();
         let llvm_builder: Builder = llvm_context.create_builder();
         let llvm_module: Module = llvm_context.create_module(&file.name);
 
-        let target_triple: &TargetTriple = llvm_backend.get_target_triple();
-        let target_cpu: &str = llvm_backend.get_target_cpu();
         let thrush_opt: ThrushOptimization = llvm_backend.get_optimization();
         let llvm_opt: OptimizationLevel = thrush_opt.to_llvm_opt();
 
-        llvm_module.set_triple(target_triple);
+        let target_machine: TargetMachine = self::create_target_machine(llvm_backend);
 
-        let target: Target = Target::from_triple(target_triple).unwrap_or_else(|_| {
-            logging::log(
-                logging::LoggingType::BackendPanic,
-                "Cannot generate a target from LLVM target triple.",
-            );
-
-            unreachable!()
-        });
-
-        let target_machine: TargetMachine = target
-            .create_target_machine(
-                target_triple,
-                target_cpu,
-                "",
-                llvm_opt,
-                llvm_backend.get_reloc_mode(),
-                llvm_backend.get_code_model(),
-            )
-            .unwrap_or_else(|| {
-                logging::log(
-                    logging::LoggingType::FrontEndPanic,
-                    "Cannot generate a target machine from target.",
-                );
+        llvm_module.set_triple(&llvm_backend.create_target_triple());
+        llvm_module.set_data_layout(&target_machine.get_target_data().get_data_layout());
 
-                unreachable!()
-            });
+        let llvm_debug_context: Option<LLVMDebugContext> = if llvm_backend.get_debug_info() {
+            Some(LLVMDebugContext::new(
+                &llvm_module,
+                source.clone(),
+                ast,
+                !thrush_opt.is_none_opt(),
+            ))
+        } else {
+            None
+        };
 
-        llvm_module.set_data_layout(&target_machine.get_target_data().get_data_layout());
+        let llvm_coverage_context: Option<LLVMCoverageContext> = if llvm_backend.get_coverage() {
+            Some(LLVMCoverageContext::new(source.clone()))
+        } else {
+            None
+        };
 
         let mut llvm_codegen_context: LLVMCodeGenContext = LLVMCodeGenContext::new(
             &llvm_module,
             &llvm_context,
             &llvm_builder,
             target_machine.get_target_data(),
-            Diagnostician::new(file),
+            llvm_debug_context,
+            llvm_coverage_context,
+            Diagnostician::new(&source_map),
         );
 
+        llvm_codegen_context.set_ub_checks(
+            llvm_backend
+                .get_sanitizers()
+                .contains(&LLVMSanitizer::Undefined),
+        );
+
+        llvm_codegen_context.set_bounds_checks(llvm_backend.get_bounds_checks());
+        llvm_codegen_context.set_test_mode(self.options.get_test_mode());
+
         llvm::compiler::LLVMCompiler::compile(&mut llvm_codegen_context, ast);
 
-        self.validate_codegen(&llvm_module, file)?;
+        if self.validate_codegen(&llvm_module, file).is_err() {
+            self.errors += 1;
+            return Err(());
+        }
 
         if emit::llvm_before_optimization(
             self,
@@ -305,6 +553,7 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
             llvm_opt,
             llvm_backend.get_opt_passes(),
             llvm_backend.get_modificator_passes(),
+            llvm_backend.get_sanitizers(),
         )
         .optimize();
 
@@ -319,10 +568,35 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
             return finisher::archive_compilation(self, archive_time, file);
         }
 
-        let compiled_file: PathBuf =
-            finisher::obj_compilation(&llvm_module, &target_machine, build_dir, &file.name);
+        let exported_symbols: Vec<String> = finisher::exported_symbols(&llvm_module);
+
+        let compiled_file: PathBuf = match self.incremental.as_ref() {
+            Some(incremental) => {
+                let compiled_file: PathBuf = finisher::obj_write(
+                    &llvm_module,
+                    &target_machine,
+                    incremental.get_object_path(file),
+                );
 
-        self.add_compiled_file(compiled_file);
+                incremental.store(
+                    file,
+                    &source,
+                    &headers,
+                    &exported_symbols,
+                    &recording.finish(),
+                );
+
+                compiled_file
+            }
+
+            None => finisher::obj_compilation(&llvm_module, &target_machine, build_dir, &file.name),
+        };
+
+        self.add_compiled_file(
+            compiled_file,
+            exported_symbols,
+            self::get_dependencies(file, headers),
+        );
 
         logging::write(
             logging::OutputIn::Stdout,
@@ -337,6 +611,60 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
         Ok(())
     }
 
+    /// Compiles the entry point of a test build, which runs every '@test' function.
+    fn compile_test_harness(&mut self) -> Result<(), ()> {
+        let llvm_backend: &LLVMBackend = self.options.get_llvm_backend_options();
+
+        let target_triple: &str = llvm_backend.get_target_triple();
+
+        if !harness::is_supported_target(target_triple) {
+            logging::log(
+                LoggingType::Error,
+                &format!(
+                    "'thrushc test' runs every test in a child process with 'fork' and 'waitpid', which the target '{}' doesn't have.",
+                    target_triple
+                ),
+            );
+
+            self.errors += 1;
+
+            return Err(());
+        }
+
+        let llvm_context: Context = Context::create();
+        let llvm_builder: Builder = llvm_context.create_builder();
+        let llvm_module: Module = llvm_context.create_module("thrush_tests");
+
+        let target_machine: TargetMachine = self::create_target_machine(llvm_backend);
+
+        llvm_module.set_triple(&llvm_backend.create_target_triple());
+        llvm_module.set_data_layout(&target_machine.get_target_data().get_data_layout());
+
+        harness::compile(&llvm_module, &llvm_context, &llvm_builder, &self.tests);
+
+        if let Err(codegen_error) = llvm_module.verify() {
+            logging::log(
+                LoggingType::BackendPanic,
+                codegen_error.to_string().trim_end(),
+            );
+
+            self.errors += 1;
+
+            return Err(());
+        }
+
+        let compiled_file: PathBuf = finisher::obj_compilation(
+            &llvm_module,
+            &target_machine,
+            self.options.get_build_dir(),
+            "thrush_tests",
+        );
+
+        self.add_compiled_file(compiled_file, Vec::new(), Vec::new());
+
+        Ok(())
+    }
+
     fn validate_codegen(&self, llvm_module: &Module, file: &CompilerFile) -> Result<(), ()> {
         if let Err(codegen_error) = llvm_module.verify() {
             logging::log(
@@ -362,8 +690,30 @@ impl<'thrushc> TheThrushCompiler<'thrushc> {
 }
 
 impl TheThrushCompiler<'_> {
-    pub fn add_compiled_file(&mut self, path: PathBuf) {
+    fn add_tests(&mut self, file: &CompilerFile, source: &SourceFile, ast: &[Ast]) {
+        ast.iter().for_each(|decl| match decl {
+            Ast::Function {
+                name, ascii_name, ..
+            } if decl.is_test_function() => {
+                self.tests.push((
+                    harness::get_test_symbol(source.get_path(), ascii_name),
+                    format!("{}::{}", file.name, name),
+                ));
+            }
+
+            _ => (),
+        });
+    }
+
+    pub fn add_compiled_file(
+        &mut self,
+        path: PathBuf,
+        exported_symbols: Vec<String>,
+        dependencies: Vec<PathBuf>,
+    ) {
         self.compiled.push(path);
+        self.exported_symbols.push(exported_symbols);
+        self.dependencies.push(dependencies);
     }
 }
 
@@ -372,7 +722,59 @@ impl TheThrushCompiler<'_> {
         &self.compiled
     }
 
+    pub fn get_dependencies(&self) -> &[Vec<PathBuf>] {
+        &self.dependencies
+    }
+
     pub fn get_options(&self) -> &CompilerOptions {
         self.options
     }
+
+    pub fn get_errors_count(&self) -> usize {
+        self.errors
+    }
+
+    /// Whether this build linked its output.
+    pub fn get_linked(&self) -> bool {
+        self.linked
+    }
+}
+
+fn create_target_machine(llvm_backend: &LLVMBackend) -> TargetMachine {
+    let target_triple: TargetTriple = llvm_backend.create_target_triple();
+
+    let target: Target = Target::from_triple(&target_triple).unwrap_or_else(|_| {
+        logging::log(
+            logging::LoggingType::BackendPanic,
+            "Cannot generate a target from LLVM target triple.",
+        );
+
+        unreachable!()
+    });
+
+    target
+        .create_target_machine(
+            &target_triple,
+            llvm_backend.get_target_cpu(),
+            "",
+            llvm_backend.get_optimization().to_llvm_opt(),
+            llvm_backend.get_reloc_mode(),
+            llvm_backend.get_code_model(),
+        )
+        .unwrap_or_else(|| {
+            logging::log(
+                logging::LoggingType::FrontEndPanic,
+                "Cannot generate a target machine from target.",
+            );
+
+            unreachable!()
+        })
+}
+
+/// The files an object depends on: its source first, then the C headers it imported.
+fn get_dependencies(file: &CompilerFile, mut headers: Vec<PathBuf>) -> Vec<PathBuf> {
+    headers.sort();
+    headers.insert(0, file.path.clone());
+
+    headers
 }
@@ -0,0 +1,618 @@
+use std::{
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+};
+
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use colored::Colorize;
+
+use inkwell::{
+    OptimizationLevel,
+    builder::Builder,
+    context::Context,
+    execution_engine::ExecutionEngine,
+    module::Module,
+    support,
+    targets::{InitializationConfig, Target, TargetMachine},
+};
+
+use crate::{
+    backend::llvm::{
+        self,
+        compiler::{
+            context::LLVMCodeGenContext,
+            repl::{self, ReplSymbols},
+        },
+    },
+    core::{
+        compiler::{
+            backends::llvm::LLVMBackend,
+            constants::{REPL_ENTRY_PREFIX, REPL_SOURCE_NAME},
+            jit::JITConfiguration,
+            options::CompilerOptions,
+            sourcemap::{SourceFile, SourceMap},
+            thrushc::finisher,
+        },
+        console::logging::{self, LoggingType},
+        diagnostic::diagnostician::Diagnostician,
+    },
+    frontend::{
+        cimport,
+        lexer::{Lexer, token::Token, tokentype::TokenType},
+        parser::{Parser, ParserContext, symbols::SymbolsTable},
+        semantic::SemanticAnalyzer,
+        types::{
+            ast::{Ast, metadata::local::LocalMetadata},
+            lexer::types::Tokens,
+        },
+        typesystem::types::Type,
+    },
+};
+
+/// A 'thrushc repl' session.
+///
+/// Every snippet is compiled as the body of a new function into its own module of the
+/// JIT. Only the snippet is parsed and checked: the declarations entered so far stay
+/// parsed, together with the symbols of the parser. The locals of a snippet live in
+/// globals of the JIT and are declared again at the top of the following snippets.
+///
+/// The tokens of every snippet are leaked, since the declarations parsed from them
+/// live as long as the session.
+struct ReplSession<'repl> {
+    options: &'repl CompilerOptions,
+    source_map: &'static SourceMap,
+    symbols: SymbolsTable<'static>,
+    declarations: Vec<Ast<'static>>,
+    bindings: Vec<ReplBinding>,
+    defined: HashSet<String>,
+    snippets: usize,
+}
+
+/// A local of an earlier snippet: how it is declared again, and the global holding it.
+struct ReplBinding {
+    name: &'static str,
+    ascii_name: &'static str,
+    local: Ast<'static>,
+    symbol: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Snippet {
+    Declaration,
+    Statements,
+    Expression,
+}
+
+/// Starts the session; it ends at ':quit' or at the end of the input.
+pub fn start(options: &CompilerOptions) -> ! {
+    Target::initialize_all(&InitializationConfig::default());
+
+    let llvm_context: Context = Context::create();
+    let llvm_module: Module = llvm_context.create_module("thrush_repl");
+
+    let jit: ExecutionEngine = llvm_module
+        .create_jit_execution_engine(OptimizationLevel::None)
+        .unwrap_or_else(|error| {
+            logging::log(
+                LoggingType::Panic,
+                &format!("Cannot start the JIT: {}.", error.to_string_lossy()),
+            );
+
+            unreachable!()
+        });
+
+    self::load_libraries(options.get_llvm_backend_options().get_jit_config());
+
+    let mut session: ReplSession = ReplSession {
+        options,
+        source_map: Box::leak(Box::new(SourceMap::new())),
+        symbols: SymbolsTable::default(),
+        declarations: Vec::with_capacity(100),
+        bindings: Vec::with_capacity(100),
+        defined: HashSet::with_capacity(100),
+        snippets: 0,
+    };
+
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "{} {}\n{}\n",
+            "Thrush REPL".custom_color((141, 141, 142)).bold(),
+            env!("CARGO_PKG_VERSION").custom_color((141, 141, 142)),
+            "Enter declarations, statements or expressions. ':quit' ends the session."
+                .custom_color((141, 141, 142))
+        ),
+    );
+
+    let mut lines = io::stdin().lock().lines();
+
+    while let Some(input) = self::read_input(&mut lines) {
+        match input.trim() {
+            "" => continue,
+            ":quit" | ":q" => break,
+            input => session.eval(&jit, &llvm_context, input),
+        }
+    }
+
+    std::process::exit(0);
+}
+
+/// Reads lines until the braces of the input are balanced.
+fn read_input(lines: &mut impl Iterator<Item = io::Result<String>>) -> Option<String> {
+    let mut input: String = String::with_capacity(256);
+    let mut depth: isize = 0;
+
+    loop {
+        let prompt: &str = if input.is_empty() {
+            "thrush> "
+        } else {
+            "   ...> "
+        };
+
+        logging::write(logging::OutputIn::Stdout, prompt);
+        let _ = io::stdout().flush();
+
+        let line: String = match lines.next() {
+            Some(Ok(line)) => line,
+            _ if input.is_empty() => return None,
+            _ => return Some(input),
+        };
+
+        depth += line
+            .chars()
+            .map(|c| match c {
+                '{' => 1,
+                '}' => -1,
+                _ => 0,
+            })
+            .sum::<isize>();
+
+        input.push_str(&line);
+        input.push('\n');
+
+        if depth <= 0 {
+            return Some(input);
+        }
+    }
+}
+
+/// The C library and the libraries given with '-jit-c' and '-jit-lib'.
+fn load_libraries(jit_config: Option<&JITConfiguration>) {
+    support::load_visible_symbols();
+
+    let Some(jit_config) = jit_config else {
+        return;
+    };
+
+    jit_config
+        .get_libc_path()
+        .into_iter()
+        .chain(jit_config.get_libraries())
+        .for_each(|library| {
+            if support::load_library_permanently(library).is_err() {
+                logging::log(
+                    LoggingType::Error,
+                    &format!("The library '{}' cannot be loaded.", library.display()),
+                );
+            }
+        });
+}
+
+impl ReplSession<'_> {
+    fn eval(&mut self, jit: &ExecutionEngine, llvm_context: &Context, input: &str) {
+        let Some(snippet) = self.classify(input) else {
+            return;
+        };
+
+        self.snippets += 1;
+
+        let entry: String = format!("{}{}", REPL_ENTRY_PREFIX, self.snippets);
+
+        match snippet {
+            Snippet::Declaration => {
+                let _ = self.compile(jit, llvm_context, input.to_string(), &entry, snippet, true);
+            }
+
+            Snippet::Statements => {
+                let body: String = format!("{{\n{}\n}}", input);
+                let source: String = self::build_source(&entry, "void", &body);
+
+                if self
+                    .compile(jit, llvm_context, source, &entry, snippet, true)
+                    .is_ok()
+                {
+                    self::call::<()>(jit, &entry);
+                }
+            }
+
+            Snippet::Expression => {
+                let expr: &str = input.trim();
+
+                let source: String = self::build_source(&entry, "void", &format!("{};", expr));
+
+                let Ok(Some(kind)) =
+                    self.compile(jit, llvm_context, source.clone(), &entry, snippet, false)
+                else {
+                    return;
+                };
+
+                // Values that can't be printed still run, for their side effects.
+                if !self::is_printable(&kind) {
+                    if self
+                        .compile(jit, llvm_context, source, &entry, snippet, true)
+                        .is_ok()
+                    {
+                        self::call::<()>(jit, &entry);
+                        self::print_value(None, &kind);
+                    }
+
+                    return;
+                }
+
+                let source: String =
+                    self::build_source(&entry, &kind.to_string(), &format!("return {};", expr));
+
+                if self
+                    .compile(jit, llvm_context, source, &entry, snippet, true)
+                    .is_ok()
+                {
+                    let value: String = self::call_printable(jit, &entry, &kind);
+                    self::print_value(Some(value), &kind);
+                }
+            }
+        }
+    }
+
+    /// Lexes the input on its own to tell what it is.
+    fn classify(&self, input: &str) -> Option<Snippet> {
+        let source: Arc<SourceFile> = self
+            .source_map
+            .add(PathBuf::from(REPL_SOURCE_NAME), input.to_string());
+
+        let (tokens, lexer_errors): (Tokens, usize) = Lexer::lex(&source, self.source_map);
+
+        if lexer_errors > 0 {
+            return None;
+        }
+
+        let tokens: Vec<&Token> = tokens
+            .iter()
+            .filter(|token| !matches!(token.kind, TokenType::Eof | TokenType::DocComment))
+            .collect();
+
+        let (first, last): (&Token, &Token) = match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return None,
+        };
+
+        let is_main: bool =
+            first.kind == TokenType::Fn && tokens.get(1).is_some_and(|name| name.lexeme == "main");
+
+        if is_main {
+            logging::log(
+                LoggingType::Error,
+                "The REPL has no 'main'. Enter its statements directly.",
+            );
+
+            return None;
+        }
+
+        let is_declaration: bool = matches!(
+            first.kind,
+            TokenType::Fn
+                | TokenType::AsmFn
+                | TokenType::GlobalAsm
+                | TokenType::Struct
+                | TokenType::Enum
+                | TokenType::Type
+                | TokenType::Const
+                | TokenType::Static
+                | TokenType::CImport
+        );
+
+        Some(match last.kind {
+            _ if is_declaration => Snippet::Declaration,
+            TokenType::SemiColon | TokenType::RBrace => Snippet::Statements,
+            _ => Snippet::Expression,
+        })
+    }
+
+    /// The symbols of the declarations entered so far and, for the statements and
+    /// expressions, the locals of the earlier snippets in a scope around the entry.
+    fn get_parser_symbols(&self, snippet: Snippet) -> SymbolsTable<'static> {
+        let mut symbols: SymbolsTable = self.symbols.clone();
+
+        if snippet == Snippet::Declaration {
+            return symbols;
+        }
+
+        symbols.begin_scope();
+
+        self.bindings.iter().for_each(|binding| {
+            if let Ast::Local {
+                name,
+                kind,
+                metadata,
+                span,
+                ..
+            } = &binding.local
+            {
+                let _ = symbols.new_local(name, (kind.clone(), *metadata, *span), *span);
+            }
+        });
+
+        symbols
+    }
+
+    /// Declares again the locals of the earlier snippets at the top of the entry.
+    fn replay_bindings(&self, ast: &mut [Ast<'static>], entry: &str) {
+        ast.iter_mut().for_each(|decl| match decl {
+            Ast::Function {
+                ascii_name, body, ..
+            } if *ascii_name == entry => {
+                let Ast::Block { stmts, span } = &**body else {
+                    return;
+                };
+
+                let stmts: Vec<Ast> = self
+                    .bindings
+                    .iter()
+                    .map(|binding| binding.local.clone())
+                    .chain(stmts.iter().cloned())
+                    .collect();
+
+                *body = Rc::new(Ast::Block { stmts, span: *span });
+            }
+
+            _ => (),
+        });
+    }
+
+    /// Checks the snippet and, with 'codegen', adds it to the JIT. Returns the type of
+    /// the last statement of the snippet.
+    fn compile(
+        &mut self,
+        jit: &ExecutionEngine,
+        llvm_context: &Context,
+        code: String,
+        entry: &str,
+        snippet: Snippet,
+        codegen: bool,
+    ) -> Result<Option<Type>, ()> {
+        let source_map: &'static SourceMap = self.source_map;
+
+        let source: Arc<SourceFile> = source_map.add(PathBuf::from(REPL_SOURCE_NAME), code);
+
+        let (tokens, lexer_errors): (Tokens, usize) = Lexer::lex(&source, source_map);
+
+        let (tokens, cimport_errors, _): (Tokens, usize, Vec<PathBuf>) = cimport::expand(
+            tokens,
+            source_map,
+            self.options.get_include_dirs(),
+            self.options.get_lints_configuration(),
+        );
+
+        let tokens: &'static [Token] = Box::leak(tokens.into_boxed_slice());
+
+        let (parser_context, parser_throwed_errors): (ParserContext<'static>, bool) =
+            Parser::parse_with_symbols(tokens, source_map, self.get_parser_symbols(snippet));
+
+        let mut ast: Vec<Ast<'static>> = parser_context.get_ast().to_vec();
+
+        self.replay_bindings(&mut ast, entry);
+
+        let lexer_throwed_errors: bool = lexer_errors + cimport_errors > 0;
+
+        let mut semantic_analyzer: SemanticAnalyzer = SemanticAnalyzer::with_declarations(
+            &ast,
+            &self.declarations,
+            entry,
+            source_map,
+            self.options.get_lints_configuration(),
+        );
+
+        let semantic_analysis_throwed_errors: bool =
+            semantic_analyzer.check(lexer_throwed_errors || parser_throwed_errors);
+
+        if lexer_throwed_errors || parser_throwed_errors || semantic_analysis_throwed_errors {
+            return Err(());
+        }
+
+        let body: &[Ast] = self::get_entry_body(&ast, entry);
+
+        let last_type: Option<Type> = body
+            .last()
+            .and_then(|stmt| stmt.get_value_type().ok())
+            .cloned();
+
+        if !codegen {
+            return Ok(last_type);
+        }
+
+        // The declarations entered before are only declared again in this module.
+        let program: Vec<Ast> = self
+            .declarations
+            .iter()
+            .chain(ast.iter())
+            .cloned()
+            .collect();
+
+        let llvm_backend: &LLVMBackend = self.options.get_llvm_backend_options();
+
+        let llvm_builder: Builder = llvm_context.create_builder();
+        let llvm_module: Module = llvm_context.create_module(entry);
+
+        let target_machine: TargetMachine = super::create_target_machine(llvm_backend);
+
+        llvm_module.set_triple(&TargetMachine::get_default_triple());
+
+        llvm_module.set_data_layout(&jit.get_target_data().get_data_layout());
+
+        let bindings: HashMap<String, String> = self
+            .bindings
+            .iter()
+            .map(|binding| (binding.ascii_name.to_string(), binding.symbol.clone()))
+            .collect();
+
+        let mut llvm_codegen_context: LLVMCodeGenContext = LLVMCodeGenContext::new(
+            &llvm_module,
+            llvm_context,
+            &llvm_builder,
+            target_machine.get_target_data(),
+            None,
+            None,
+            Diagnostician::new(source_map),
+        );
+
+        llvm_codegen_context.set_bounds_checks(llvm_backend.get_bounds_checks());
+        llvm_codegen_context.set_repl_symbols(ReplSymbols::new(
+            entry.to_string(),
+            self.defined.clone(),
+            bindings,
+        ));
+
+        llvm::compiler::LLVMCompiler::compile(&mut llvm_codegen_context, &program);
+
+        if let Err(codegen_error) = llvm_module.verify() {
+            logging::log(LoggingType::Error, codegen_error.to_string().trim_end());
+            return Err(());
+        }
+
+        let new_bindings: Vec<ReplBinding> = match (snippet, body.last()) {
+            (Snippet::Statements, Some(Ast::Block { stmts, .. })) => {
+                self::collect_bindings(stmts, entry)
+            }
+            _ => Vec::new(),
+        };
+
+        if jit.add_module(&llvm_module).is_err() {
+            logging::log(
+                LoggingType::Error,
+                "The snippet cannot be added to the JIT.",
+            );
+
+            return Err(());
+        }
+
+        self.defined
+            .extend(finisher::exported_symbols(&llvm_module));
+
+        new_bindings.into_iter().for_each(|binding| {
+            self.bindings
+                .retain(|previous| previous.name != binding.name);
+            self.bindings.push(binding);
+        });
+
+        if snippet == Snippet::Declaration {
+            self.symbols = parser_context.get_symbols().clone();
+            self.declarations.extend(ast);
+        }
+
+        Ok(last_type)
+    }
+}
+
+fn build_source(entry: &str, return_type: &str, body: &str) -> String {
+    format!("fn {}() {} @public {{\n{}\n}}\n", entry, return_type, body)
+}
+
+fn get_entry_body<'ast, 'ctx>(ast: &'ast [Ast<'ctx>], entry: &str) -> &'ast [Ast<'ctx>] {
+    ast.iter()
+        .find_map(|decl| match decl {
+            Ast::Function {
+                ascii_name, body, ..
+            } if *ascii_name == entry => match &**body {
+                Ast::Block { stmts, .. } => Some(stmts.as_slice()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// The locals the snippet declared outside any nested block, to be declared again
+/// without their value in the following snippets.
+fn collect_bindings(stmts: &[Ast<'static>], entry: &str) -> Vec<ReplBinding> {
+    stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Ast::Local {
+                name,
+                ascii_name,
+                kind,
+                metadata,
+                span,
+                ..
+            } => Some(ReplBinding {
+                name,
+                ascii_name,
+                local: Ast::Local {
+                    name,
+                    ascii_name,
+                    kind: kind.clone(),
+                    value: Ast::Null { span: *span }.into(),
+                    attributes: Vec::new(),
+                    metadata: LocalMetadata::new(true, metadata.is_mutable()),
+                    span: *span,
+                },
+                symbol: repl::get_binding_symbol(entry, ascii_name),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_printable(kind: &Type) -> bool {
+    kind.is_integer_type() || kind.is_float_type() || kind.is_bool_type() || kind.is_char_type()
+}
+
+fn call<R>(jit: &ExecutionEngine, entry: &str) -> Option<R> {
+    // SAFETY: the entry was declared with no parameters and the return type of 'R'.
+    match unsafe { jit.get_function::<unsafe extern "C" fn() -> R>(entry) } {
+        Ok(function) => Some(unsafe { function.call() }),
+        Err(error) => {
+            logging::log(
+                LoggingType::Error,
+                &format!("The snippet cannot be run: {}.", error),
+            );
+
+            None
+        }
+    }
+}
+
+fn call_printable(jit: &ExecutionEngine, entry: &str, kind: &Type) -> String {
+    let value: Option<String> = match kind {
+        Type::S8 => self::call::<i8>(jit, entry).map(|value| value.to_string()),
+        Type::S16 => self::call::<i16>(jit, entry).map(|value| value.to_string()),
+        Type::S32 => self::call::<i32>(jit, entry).map(|value| value.to_string()),
+        Type::S64 => self::call::<i64>(jit, entry).map(|value| value.to_string()),
+        Type::U8 => self::call::<u8>(jit, entry).map(|value| value.to_string()),
+        Type::U16 => self::call::<u16>(jit, entry).map(|value| value.to_string()),
+        Type::U32 => self::call::<u32>(jit, entry).map(|value| value.to_string()),
+        Type::U64 => self::call::<u64>(jit, entry).map(|value| value.to_string()),
+        Type::F32 => self::call::<f32>(jit, entry).map(|value| value.to_string()),
+        Type::F64 => self::call::<f64>(jit, entry).map(|value| value.to_string()),
+        // An 'i1' only defines its lowest bit.
+        Type::Bool => self::call::<u8>(jit, entry).map(|value| (value & 1 == 1).to_string()),
+        Type::Char => self::call::<u8>(jit, entry).map(|value| format!("{:?}", char::from(value))),
+        _ => None,
+    };
+
+    value.unwrap_or_else(|| String::from("?"))
+}
+
+fn print_value(value: Option<String>, kind: &Type) {
+    let kind: String = format!(": {}", kind);
+
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "{}{}\n",
+            value.unwrap_or_default(),
+            kind.custom_color((141, 141, 142))
+        ),
+    );
+}
@@ -3,22 +3,32 @@
 use std::path::Path;
 use std::process::Command;
 
+use crate::backend::linking::compilers::{clang, decompressor};
+
 use crate::core::{
     compiler::{
         backends::llvm::LLVMBackend,
+        constants::{
+            COVERAGE_DEFAULT_FILE, COVERAGE_FILE_ENV, PROJECT_DEFAULT_BUILD_DIR,
+            PROJECT_DEFAULT_PROFILE, PROJECT_MANIFEST_FILE, PROJECT_TESTS_OUTPUT,
+        },
+        coverage,
         jit::JITConfiguration,
-        linking::LinkingCompilersConfiguration,
-        options::{CompilerOptions, Emitable, ThrushOptimization},
-        passes::LLVMModificatorPasses,
+        linking::{CrateType, LinkingCompilersConfiguration},
+        lints::LintLevel,
+        manifest::{ManifestSection, ProjectManifest},
+        options::{CompilerOptions, DocFormat, Emitable, ThrushOptimization},
+        passes::{LLVMModificatorPasses, LLVMSanitizer},
     },
     console::logging::{self, LoggingType},
+    diagnostic::codes::{DiagnosticCode, LINT_GROUP_UNUSED},
 };
 
 use super::utils;
 
 use {
     colored::Colorize,
-    inkwell::targets::{CodeModel, RelocMode, TargetMachine, TargetTriple},
+    inkwell::targets::{CodeModel, RelocMode, TargetMachine},
     std::{collections::HashMap, path::PathBuf, process},
 };
 
@@ -29,6 +39,7 @@ pub struct CLI {
     current: usize,
     position: CommandLinePosition,
     validation_cache: HashMap<String, bool>,
+    manifest_linker: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -71,6 +82,7 @@ impl CLI {
             current: 0,
             position: CommandLinePosition::default(),
             validation_cache: HashMap::with_capacity(100),
+            manifest_linker: false,
         };
 
         command_line.build();
@@ -84,7 +96,13 @@ impl CLI {
             args.remove(0);
         }
 
-        for arg in args.iter() {
+        for (idx, arg) in args.iter().enumerate() {
+            // Everything after '--' belongs to the program started by 'run'.
+            if arg == "--" {
+                processed.extend_from_slice(&args[idx..]);
+                break;
+            }
+
             let parsed = ParsedArg::new(arg);
             processed.push(parsed.key);
 
@@ -106,11 +124,20 @@ impl CLI {
             self.analyze(argument);
         }
 
+        // A REPL session compiles into the JIT, never into files.
+        if self.options.get_repl_mode() {
+            return;
+        }
+
         if !self.options.is_build_dir_setted() {
             self.report_error(
                 "Compiler build-dir is not setted or not exist. Try again with '-build-dir \"PATH\"'.",
             );
         }
+
+        self.validate_test();
+        self.validate_crate_type();
+        self.validate_run();
     }
 
     fn analyze(&mut self, argument: String) {
@@ -133,6 +160,143 @@ impl CLI {
                 self.options.set_use_llvm_backend(true);
             }
 
+            "explain" => {
+                self.advance();
+
+                let raw_code: &str = self.peek();
+
+                let code: DiagnosticCode = DiagnosticCode::parse(raw_code).unwrap_or_else(|| {
+                    self.report_error(&format!(
+                        "Unknown diagnostic code '{}'. Codes look like 'E0001' or 'W0004'.",
+                        raw_code
+                    ))
+                });
+
+                utils::print_diagnostic_explanation(code);
+                process::exit(0);
+            }
+
+            "coverage-report" => {
+                self.advance();
+
+                let coverage_file: PathBuf = if self.is_eof() {
+                    std::env::var_os(COVERAGE_FILE_ENV)
+                        .map(PathBuf::from)
+                        .unwrap_or_else(|| PathBuf::from(COVERAGE_DEFAULT_FILE))
+                } else {
+                    PathBuf::from(self.peek())
+                };
+
+                if let Err(error) = coverage::report(&coverage_file) {
+                    self.report_error(&error);
+                }
+
+                process::exit(0);
+            }
+
+            "toolchain" => {
+                self.advance();
+
+                let Some(embedded_clang) = clang::get_embedded_clang() else {
+                    self.report_error(
+                        "The embedded Clang toolchain is not available for the current operating system.",
+                    );
+                };
+
+                let result: Result<(), String> = match self.peek() {
+                    "status" => decompressor::status(&embedded_clang),
+                    "clean" => decompressor::clean(),
+                    "reinstall" => decompressor::install(&embedded_clang, true).map(|clang_path| {
+                        logging::write(
+                            logging::OutputIn::Stdout,
+                            &format!("Installed Clang at '{}'.\n", clang_path.display()),
+                        );
+                    }),
+                    command => self.report_error(&format!(
+                        "Unknown toolchain command '{}'. Use 'status', 'clean' or 'reinstall'.",
+                        command
+                    )),
+                };
+
+                if let Err(error) = result {
+                    self.report_error(&error);
+                }
+
+                process::exit(0);
+            }
+
+            "build" | "run" => {
+                self.advance();
+
+                let profile: String = self.parse_profile();
+
+                if arg == "run" {
+                    let run_args: Vec<String> = self.take_run_args();
+                    self.options.set_run_args(run_args);
+                }
+
+                let manifest: ProjectManifest = self
+                    .find_manifest()
+                    .unwrap_or_else(|error| self.report_error(&error));
+
+                self.apply_manifest(&manifest, &profile);
+            }
+
+            "test" => {
+                self.advance();
+
+                self.options.set_test_mode(true);
+
+                let profile: String = self.parse_profile();
+                let run_args: Vec<String> = self.take_run_args();
+
+                self.options.set_run_args(run_args);
+
+                if let Ok(manifest) = self.find_manifest() {
+                    self.apply_manifest(&manifest, &profile);
+
+                    let output: PathBuf = self.options.get_build_dir().join(PROJECT_TESTS_OUTPUT);
+
+                    self.options
+                        .get_mut_llvm_backend_options()
+                        .get_mut_linking_compilers_configuration()
+                        .set_output(output);
+                }
+            }
+
+            "doc" => {
+                self.advance();
+
+                let format: DocFormat = if !self.is_eof() && self.peek() == "--html" {
+                    self.advance();
+                    DocFormat::Html
+                } else {
+                    DocFormat::Markdown
+                };
+
+                self.options.set_doc_format(format);
+
+                if let Ok(manifest) = self.find_manifest() {
+                    self.apply_manifest(&manifest, PROJECT_DEFAULT_PROFILE);
+                }
+            }
+
+            "repl" => {
+                self.advance();
+
+                self.options.set_repl_mode(true);
+                self.options.set_use_llvm_backend(true);
+
+                // The locals of earlier snippets are declared again in every snippet.
+                self.options
+                    .get_mut_lints_configuration()
+                    .set_level(LINT_GROUP_UNUSED.into(), LintLevel::Allow);
+
+                self.options
+                    .get_mut_llvm_backend_options()
+                    .set_jit_config(JITConfiguration::new());
+            }
+
             "llvm-print-target-triples" => {
                 self.advance();
                 utils::print_llvm_supported_targets_triples();
@@ -162,6 +326,31 @@ impl CLI {
                 self.advance();
             }
 
+            "-I" if !self.position.at_any_other_compiler() => {
+                self.advance();
+                self.options.add_include_dir(self.peek().into());
+                self.advance();
+            }
+
+            "-j" if !self.position.at_any_other_compiler() => {
+                self.advance();
+
+                let jobs: usize = self
+                    .peek()
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|jobs| *jobs > 0)
+                    .unwrap_or_else(|| {
+                        self.report_error(&format!(
+                            "Invalid number of jobs '{}'. Expected a number greater than zero.",
+                            self.peek()
+                        ))
+                    });
+
+                self.options.set_jobs(jobs);
+                self.advance();
+            }
+
             "-start" => {
                 self.advance();
                 self.position = CommandLinePosition::ExternalCompiler;
@@ -175,7 +364,9 @@ impl CLI {
             "-clang" => {
                 self.advance();
                 self.validate_llvm_required(arg);
+                self.reset_manifest_linker();
                 self.validate_not_gcc_active();
+                self.validate_no_linker_active(arg);
 
                 self.options
                     .get_mut_llvm_backend_options()
@@ -204,7 +395,9 @@ impl CLI {
 
             "-gcc" => {
                 self.advance();
+                self.reset_manifest_linker();
                 self.validate_not_clang_active();
+                self.validate_no_linker_active(arg);
 
                 let custom_gcc: &str = self.peek();
                 let custom_gcc_path: PathBuf = PathBuf::from(custom_gcc);
@@ -225,6 +418,40 @@ impl CLI {
                 self.advance();
             }
 
+            "-ld" => {
+                self.advance();
+                self.validate_llvm_required(arg);
+                self.reset_manifest_linker();
+                self.validate_no_linker_active(arg);
+
+                let custom_ld: &str = self.peek();
+                let custom_ld_path: PathBuf = PathBuf::from(custom_ld);
+
+                if !self.validate_compiler_path(&custom_ld_path) {
+                    self.report_error("Indicated linker 'ld.lld' or 'ld' doesn't exist.");
+                }
+
+                let compiler_config: &mut LinkingCompilersConfiguration = self
+                    .options
+                    .get_mut_llvm_backend_options()
+                    .get_mut_linking_compilers_configuration();
+
+                compiler_config.set_custom_ld(custom_ld_path);
+                compiler_config.set_use_ld(true);
+
+                self.advance();
+            }
+
+            "--link-dry-run" => {
+                self.advance();
+                self.validate_llvm_required(arg);
+
+                self.options
+                    .get_mut_llvm_backend_options()
+                    .get_mut_linking_compilers_configuration()
+                    .set_link_dry_run(true);
+            }
+
             "-jit" => {
                 self.advance();
                 self.validate_llvm_required(arg);
@@ -310,35 +537,137 @@ impl CLI {
                 self.advance();
             }
 
+            "-g" => {
+                self.advance();
+                self.validate_llvm_required(arg);
+
+                self.options
+                    .get_mut_llvm_backend_options()
+                    .set_debug_info(true);
+            }
+
+            sanitize
+                if sanitize.starts_with("-fsanitize=")
+                    && !self.position.at_any_other_compiler() =>
+            {
+                self.advance();
+                self.validate_llvm_required("-fsanitize");
+
+                for sanitizer in sanitize.trim_start_matches("-fsanitize=").split(',') {
+                    let sanitizer: LLVMSanitizer = self.parse_sanitizer(sanitizer);
+
+                    self.options
+                        .get_mut_llvm_backend_options()
+                        .add_sanitizer(sanitizer);
+                }
+            }
+
+            "-fcoverage" => {
+                self.advance();
+                self.validate_llvm_required(arg);
+
+                self.options
+                    .get_mut_llvm_backend_options()
+                    .set_coverage(true);
+            }
+
+            "--bounds-checks" | "--no-bounds-checks" => {
+                self.advance();
+                self.validate_llvm_required(arg);
+
+                self.options
+                    .get_mut_llvm_backend_options()
+                    .set_bounds_checks(arg == "--bounds-checks");
+            }
+
+            "--crate-type" => {
+                self.advance();
+                self.validate_llvm_required(arg);
+
+                let crate_type: CrateType = self.parse_crate_type(self.peek());
+
+                self.options
+                    .get_mut_llvm_backend_options()
+                    .get_mut_linking_compilers_configuration()
+                    .set_crate_type(crate_type);
+
+                self.advance();
+            }
+
+            "-o" if !self.position.at_any_other_compiler() => {
+                self.advance();
+                self.validate_llvm_required(arg);
+
+                let output: PathBuf = PathBuf::from(self.peek());
+
+                self.options
+                    .get_mut_llvm_backend_options()
+                    .get_mut_linking_compilers_configuration()
+                    .set_output(output);
+
+                self.advance();
+            }
+
             "-emit" => {
                 self.advance();
                 self.validate_emit_llvm_required(arg);
 
                 let emitable: Emitable = self.parse_emit_option(self.peek());
 
+                // The blocks of the graph are labeled from the debug locations of their instructions.
+                if emitable == Emitable::CFGDot {
+                    self.options
+                        .get_mut_llvm_backend_options()
+                        .set_debug_info(true);
+                }
+
                 self.options.add_emit_option(emitable);
 
                 self.advance();
             }
 
+            "-W" | "-A" | "-D" => {
+                self.advance();
+
+                let level: LintLevel = match arg {
+                    "-A" => LintLevel::Allow,
+                    "-D" => LintLevel::Deny,
+                    _ => LintLevel::Warn,
+                };
+
+                let lint: String = self.parse_lint_name(self.peek());
+
+                self.options
+                    .get_mut_lints_configuration()
+                    .set_level(lint, level);
+
+                self.advance();
+            }
+
+            "-Werror" => {
+                self.advance();
+
+                self.options
+                    .get_mut_lints_configuration()
+                    .set_warnings_as_errors(true);
+            }
+
             "-target" => {
                 self.advance();
                 self.validate_llvm_required(arg);
 
-                let raw_target_triple: &str = self.peek();
+                let raw_target_triple: String = self.peek().to_string();
 
-                if !utils::is_supported_llvm_target_triple(raw_target_triple) {
+                if !utils::is_supported_llvm_target_triple(&raw_target_triple) {
                     self.report_error(&format!(
                         "Unknown LLVM target triple: '{}'.",
                         raw_target_triple
                     ));
                 }
 
-                let target_triple: TargetTriple = TargetTriple::create(raw_target_triple);
-
                 self.options
                     .get_mut_llvm_backend_options()
-                    .set_target_triple(target_triple);
+                    .set_target_triple(raw_target_triple);
 
                 self.advance();
             }
@@ -418,6 +747,34 @@ impl CLI {
                     .set_debug_gcc_commands(true);
             }
 
+            "-MD" if !self.position.at_any_other_compiler() => {
+                self.advance();
+                self.validate_llvm_required(arg);
+                self.options.set_emit_depfile(true);
+            }
+
+            "-MF" if !self.position.at_any_other_compiler() => {
+                self.advance();
+                self.validate_llvm_required(arg);
+
+                let depfile: PathBuf = PathBuf::from(self.peek());
+
+                self.options.set_emit_depfile(true);
+                self.options.set_depfile(depfile);
+
+                self.advance();
+            }
+
+            "--no-incremental" => {
+                self.advance();
+                self.options.set_incremental(false);
+            }
+
+            "--explain-rebuild" => {
+                self.advance();
+                self.options.set_explain_rebuild(true);
+            }
+
             "--clean-tokens" => {
                 self.advance();
                 self.options.set_clean_tokens();
@@ -460,6 +817,409 @@ impl CLI {
         }
     }
 
+    fn parse_profile(&mut self) -> String {
+        if self.is_eof() {
+            return PROJECT_DEFAULT_PROFILE.to_string();
+        }
+
+        match self.peek() {
+            "--release" => {
+                self.advance();
+                "release".to_string()
+            }
+
+            "--profile" => {
+                self.advance();
+
+                let profile: String = self.peek().to_string();
+
+                self.advance();
+
+                profile
+            }
+
+            _ => PROJECT_DEFAULT_PROFILE.to_string(),
+        }
+    }
+
+    fn take_run_args(&mut self) -> Vec<String> {
+        let Some(separator) = self.args[self.current..].iter().position(|arg| arg == "--") else {
+            return Vec::new();
+        };
+
+        let run_args: Vec<String> = self.args.split_off(self.current + separator + 1);
+
+        self.args.pop();
+
+        run_args
+    }
+
+    /// The manifest of the project around the current directory. 'build' and 'run' need
+    /// one; without it, 'test' and 'doc' take the files and flags from the command line.
+    fn find_manifest(&self) -> Result<ProjectManifest, String> {
+        std::env::current_dir()
+            .map_err(|error| format!("Could not get the current directory: {}.", error))
+            .and_then(|current_dir| ProjectManifest::find(&current_dir))
+    }
+
+    /// Fills the options from a project manifest, for the given profile. The flags that
+    /// follow 'build' or 'run' in the command line are still parsed, and win.
+    fn apply_manifest(&mut self, manifest: &ProjectManifest, profile: &str) {
+        let build: ManifestSection = ManifestSection::Build(profile);
+
+        match profile {
+            _ if manifest.has_profile(profile) => (),
+            "debug" => self
+                .options
+                .get_mut_llvm_backend_options()
+                .set_debug_info(true),
+            "release" => self
+                .options
+                .get_mut_llvm_backend_options()
+                .set_optimization(ThrushOptimization::Mcqueen),
+            _ => self.report_error(&format!(
+                "Unknown profile '{}'. Define it as '[profile.{}]' in '{}'.",
+                profile, profile, PROJECT_MANIFEST_FILE
+            )),
+        }
+
+        self.options.set_use_llvm_backend(true);
+
+        let name: String = self
+            .manifest_string(manifest, ManifestSection::Project, "name")
+            .unwrap_or_else(|| {
+                self.report_error(&format!(
+                    "Missing 'name' in the '[project]' table of '{}'.",
+                    PROJECT_MANIFEST_FILE
+                ))
+            });
+
+        let build_dir: PathBuf = manifest
+            .resolve_path(
+                &self
+                    .manifest_string(manifest, ManifestSection::Project, "build-dir")
+                    .unwrap_or_else(|| PROJECT_DEFAULT_BUILD_DIR.to_string()),
+            )
+            .join(profile);
+
+        if let Err(error) = std::fs::create_dir_all(&build_dir) {
+            self.report_error(&format!(
+                "Could not create the build directory '{}': {}.",
+                build_dir.display(),
+                error
+            ));
+        }
+
+        self.options.set_build_dir(build_dir.clone());
+
+        if let Some(entry) = self.manifest_string(manifest, ManifestSection::Project, "entry") {
+            let entry_path: PathBuf = manifest.resolve_path(&entry);
+
+            if !self.is_thrush_file(&entry_path.to_string_lossy()) {
+                self.report_error(&format!(
+                    "The entry point '{}' is not a '.thrush' file.",
+                    entry_path.display()
+                ));
+            }
+
+            self.handle_thrush_file(&entry_path.to_string_lossy());
+        }
+
+        let sources: Vec<String> =
+            self.manifest_strings(manifest, ManifestSection::Project, "sources");
+
+        if sources.is_empty() {
+            self.report_error(&format!(
+                "Missing 'sources' in the '[project]' table of '{}'.",
+                PROJECT_MANIFEST_FILE
+            ));
+        }
+
+        for source in sources {
+            let source_path: PathBuf = manifest.resolve_path(&source);
+
+            for file in self.collect_thrush_files(&source_path) {
+                self.handle_thrush_file(&file.to_string_lossy());
+            }
+        }
+
+        let crate_type: CrateType = self
+            .manifest_string(manifest, ManifestSection::Project, "crate-type")
+            .map_or(CrateType::Executable, |crate_type| {
+                self.parse_crate_type(&crate_type)
+            });
+
+        let output: PathBuf =
+            match self.manifest_string(manifest, ManifestSection::Project, "output") {
+                Some(output) => manifest.resolve_path(&output),
+                None => build_dir.join(
+                    crate_type
+                        .get_default_output(&name)
+                        .unwrap_or_else(|| PathBuf::from(&name)),
+                ),
+            };
+
+        let linking_config: &mut LinkingCompilersConfiguration = self
+            .options
+            .get_mut_llvm_backend_options()
+            .get_mut_linking_compilers_configuration();
+
+        linking_config.set_crate_type(crate_type);
+        linking_config.set_output(output);
+
+        if let Some(target) = self.manifest_string(manifest, build, "target") {
+            if !utils::is_supported_llvm_target_triple(&target) {
+                self.report_error(&format!("Unknown LLVM target triple: '{}'.", target));
+            }
+
+            self.options
+                .get_mut_llvm_backend_options()
+                .set_target_triple(target);
+        }
+
+        if let Some(cpu) = self.manifest_string(manifest, build, "cpu") {
+            if !self.validate_llvm_cpu(&cpu) {
+                self.report_error(&format!(
+                    "Unknown CPU target: '{}'. See 'llvm-print-supported-cpus' command.",
+                    cpu
+                ));
+            }
+
+            self.options
+                .get_mut_llvm_backend_options()
+                .set_target_cpu(cpu);
+        }
+
+        if let Some(opt) = self.manifest_string(manifest, build, "opt") {
+            let opt: ThrushOptimization = self.parse_optimization_level(&opt);
+
+            self.options
+                .get_mut_llvm_backend_options()
+                .set_optimization(opt);
+        }
+
+        if let Some(debug) = manifest
+            .get_bool(build, "debug")
+            .unwrap_or_else(|error| self.report_error(&error))
+        {
+            self.options
+                .get_mut_llvm_backend_options()
+                .set_debug_info(debug);
+        }
+
+        for emit in self.manifest_strings(manifest, build, "emit") {
+            let emitable: Emitable = self.parse_emit_option(&emit);
+            self.options.add_emit_option(emitable);
+        }
+
+        if let Some(jobs) = manifest
+            .get_integer(build, "jobs")
+            .unwrap_or_else(|error| self.report_error(&error))
+        {
+            let jobs: usize = usize::try_from(jobs)
+                .ok()
+                .filter(|jobs| *jobs > 0)
+                .unwrap_or_else(|| {
+                    self.report_error(&format!(
+                        "Invalid number of jobs '{}'. Expected a number greater than zero.",
+                        jobs
+                    ))
+                });
+
+            self.options.set_jobs(jobs);
+        }
+
+        self.apply_manifest_linker(manifest, build);
+    }
+
+    /// Without a 'linker' key the linker is left to the command line, which can also
+    /// replace the one of the manifest with '-clang', '-gcc' or '-ld'.
+    fn apply_manifest_linker(&mut self, manifest: &ProjectManifest, build: ManifestSection) {
+        let linker: Option<String> = self.manifest_string(manifest, build, "linker");
+
+        // A bare name is a command searched in the 'PATH', like '-gcc' and '-ld' take it.
+        let linker_path: Option<PathBuf> = self
+            .manifest_string(manifest, build, "linker-path")
+            .map(|path| match Path::new(&path).parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => manifest.resolve_path(&path),
+                _ => PathBuf::from(path),
+            });
+
+        let linker_args: Vec<String> = self.manifest_strings(manifest, build, "linker-args");
+
+        let linking_config: &mut LinkingCompilersConfiguration = self
+            .options
+            .get_mut_llvm_backend_options()
+            .get_mut_linking_compilers_configuration();
+
+        linker_args
+            .into_iter()
+            .for_each(|arg| linking_config.add_compiler_arg(arg));
+
+        let Some(linker) = linker else {
+            if linker_path.is_some() {
+                self.report_error("The manifest sets 'linker-path' without a 'linker'.");
+            }
+
+            return;
+        };
+
+        match linker_path.as_ref() {
+            Some(path) if !self.validate_compiler_path(path) => self.report_error(&format!(
+                "Indicated linker '{}' doesn't exist.",
+                path.display()
+            )),
+            _ => (),
+        }
+
+        self.manifest_linker = true;
+
+        let linking_config: &mut LinkingCompilersConfiguration = self
+            .options
+            .get_mut_llvm_backend_options()
+            .get_mut_linking_compilers_configuration();
+
+        match linker.as_str() {
+            "clang" => {
+                linking_config.set_use_clang(true);
+
+                if let Some(linker_path) = linker_path {
+                    linking_config.set_custom_clang(linker_path);
+                }
+            }
+
+            "gcc" => {
+                linking_config.set_use_gcc(true);
+                linking_config.set_custom_gcc(linker_path.unwrap_or_else(|| "gcc".into()));
+            }
+
+            "ld" => {
+                linking_config.set_use_ld(true);
+                linking_config.set_custom_ld(linker_path.unwrap_or_else(|| "ld.lld".into()));
+            }
+
+            any => self.report_error(&format!(
+                "Unknown linker '{}'. Use 'clang', 'gcc' or 'ld'.",
+                any
+            )),
+        }
+    }
+
+    /// A linker flag of the command line replaces the linker of the manifest.
+    fn reset_manifest_linker(&mut self) {
+        if !self.manifest_linker {
+            return;
+        }
+
+        self.manifest_linker = false;
+
+        self.options
+            .get_mut_llvm_backend_options()
+            .get_mut_linking_compilers_configuration()
+            .reset_linker();
+    }
+
+    fn manifest_string(
+        &self,
+        manifest: &ProjectManifest,
+        section: ManifestSection,
+        key: &str,
+    ) -> Option<String> {
+        manifest
+            .get_string(section, key)
+            .map(|value| value.map(str::to_string))
+            .unwrap_or_else(|error| self.report_error(&error))
+    }
+
+    fn manifest_strings(
+        &self,
+        manifest: &ProjectManifest,
+        section: ManifestSection,
+        key: &str,
+    ) -> Vec<String> {
+        manifest
+            .get_strings(section, key)
+            .map(|values| values.into_iter().map(str::to_string).collect())
+            .unwrap_or_else(|error| self.report_error(&error))
+    }
+
+    /// A source of the manifest is a '.thrush' file, or a directory searched for them.
+    fn collect_thrush_files(&self, path: &Path) -> Vec<PathBuf> {
+        if path.is_file() {
+            if !self.is_thrush_file(&path.to_string_lossy()) {
+                self.report_error(&format!(
+                    "The source '{}' is not a '.thrush' file.",
+                    path.display()
+                ));
+            }
+
+            return vec![path.to_path_buf()];
+        }
+
+        let Ok(entries) = std::fs::read_dir(path) else {
+            self.report_error(&format!("The source '{}' does not exist.", path.display()));
+        };
+
+        let mut entries: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect();
+
+        entries.sort();
+
+        entries
+            .into_iter()
+            .filter(|entry| entry.is_dir() || self.is_thrush_file(&entry.to_string_lossy()))
+            .flat_map(|entry| self.collect_thrush_files(&entry))
+            .collect()
+    }
+
+    /// Test builds always link an executable, the harness.
+    fn validate_test(&mut self) {
+        if !self.options.get_test_mode() {
+            return;
+        }
+
+        let output: PathBuf = self.options.get_build_dir().join(PROJECT_TESTS_OUTPUT);
+
+        let linking_config: &mut LinkingCompilersConfiguration = self
+            .options
+            .get_mut_llvm_backend_options()
+            .get_mut_linking_compilers_configuration();
+
+        linking_config.set_crate_type(CrateType::Executable);
+
+        if linking_config.get_output().is_none() {
+            linking_config.set_output(output);
+        }
+    }
+
+    fn validate_run(&self) {
+        if self.options.get_run_args().is_none() {
+            return;
+        }
+
+        let crate_type: CrateType = self
+            .options
+            .get_llvm_backend_options()
+            .get_linking_compilers_configuration()
+            .get_crate_type();
+
+        if crate_type != CrateType::Executable || self.options.get_was_emited() {
+            self.report_error(
+                "Only projects that link an executable can run. Check 'crate-type' and 'emit'.",
+            );
+        }
+
+        if self
+            .options
+            .get_llvm_backend_options()
+            .get_linking_compilers_configuration()
+            .get_link_dry_run()
+        {
+            self.report_error("A program can't run after '--link-dry-run', which links nothing.");
+        }
+    }
+
     fn validate_llvm_required(&self, arg: &str) {
         if !self.options.get_use_llvm() {
             self.report_error(&format!(
@@ -515,12 +1275,47 @@ impl CLI {
                 "asm",
             ];
 
-            if llvm_emit_options.contains(&self.peek()) {
-                self.report_error(&format!(
-                    "Can't use '{}' without '-llvm' flag previously.",
-                    arg
-                ));
-            }
+            if llvm_emit_options.contains(&self.peek()) {
+                self.report_error(&format!(
+                    "Can't use '{}' without '-llvm' flag previously.",
+                    arg
+                ));
+            }
+        }
+    }
+
+    fn validate_crate_type(&mut self) {
+        let llvm_backend: &LLVMBackend = self.options.get_llvm_backend_options();
+
+        let crate_type: CrateType = llvm_backend
+            .get_linking_compilers_configuration()
+            .get_crate_type();
+
+        if crate_type == CrateType::SharedLibrary && llvm_backend.get_reloc_mode() != RelocMode::PIC
+        {
+            self.report_error(
+                "Shared libraries require position independent code. Remove '--reloc' or use '--reloc pic'.",
+            );
+        }
+
+        if llvm_backend
+            .get_linking_compilers_configuration()
+            .get_output()
+            .is_some()
+        {
+            return;
+        }
+
+        let default_output: Option<PathBuf> = self.options.get_files().first().and_then(|file| {
+            let stem: String = file.path.file_stem()?.to_string_lossy().to_string();
+            crate_type.get_default_output(&stem)
+        });
+
+        if let Some(output) = default_output {
+            self.options
+                .get_mut_llvm_backend_options()
+                .get_mut_linking_compilers_configuration()
+                .set_output(output);
         }
     }
 
@@ -535,6 +1330,21 @@ impl CLI {
         }
     }
 
+    fn validate_no_linker_active(&self, arg: &str) {
+        let config: &LinkingCompilersConfiguration = self
+            .options
+            .get_llvm_backend_options()
+            .get_linking_compilers_configuration();
+
+        if config.get_use_ld() || (arg == "-ld" && (config.get_use_clang() || config.get_use_gcc()))
+        {
+            self.report_error(&format!(
+                "Can't use '{}' with another linker activated. Choose one of '-clang', '-gcc' or '-ld'.",
+                arg
+            ));
+        }
+    }
+
     fn validate_not_clang_active(&self) {
         if self
             .options
@@ -595,13 +1405,57 @@ impl CLI {
             "raw-asm" => Emitable::RawAssembly,
             "obj" => Emitable::Object,
             "ast" => Emitable::AST,
+            "ast-json" => Emitable::ASTJson,
             "tokens" => Emitable::Tokens,
+            "tokens-json" => Emitable::TokensJson,
+            "c-header" => Emitable::CHeader,
+            "cfg-dot" => Emitable::CFGDot,
+            "callgraph-dot" => Emitable::CallGraphDot,
             any => {
                 self.report_error(&format!("Unknown LLVM emit option: '{}'.", any));
             }
         }
     }
 
+    fn parse_crate_type(&self, crate_type: &str) -> CrateType {
+        match crate_type {
+            "exe" => CrateType::Executable,
+            "staticlib" => CrateType::StaticLibrary,
+            "sharedlib" => CrateType::SharedLibrary,
+            "obj" => CrateType::Object,
+            any => {
+                self.report_error(&format!(
+                    "Unknown crate type: '{}'. Use 'exe', 'staticlib', 'sharedlib' or 'obj'.",
+                    any
+                ));
+            }
+        }
+    }
+
+    fn parse_sanitizer(&self, sanitizer: &str) -> LLVMSanitizer {
+        match sanitizer {
+            "address" => LLVMSanitizer::Address,
+            "undefined" => LLVMSanitizer::Undefined,
+            any => {
+                self.report_error(&format!(
+                    "Unknown sanitizer: '{}'. Use 'address' or 'undefined'.",
+                    any
+                ));
+            }
+        }
+    }
+
+    fn parse_lint_name(&self, lint: &str) -> String {
+        if !DiagnosticCode::is_known_lint(lint) {
+            self.report_error(&format!(
+                "Unknown lint: '{}'. Use 'unused', 'warnings', a lint name like 'unused_local' or a warning code like 'W0004'.",
+                lint
+            ));
+        }
+
+        lint.to_string()
+    }
+
     fn parse_reloc_mode(&self, reloc_str: &str) -> RelocMode {
         match reloc_str {
             "dynamic-no-pic" => RelocMode::DynamicNoPic,
@@ -740,13 +1594,81 @@ impl CLI {
         logging::write(
             logging::OutputIn::Stderr,
             &format!(
-                "{} {} {}\n\n",
+                "{} {} {}\n",
                 "•".bold(),
                 "version".custom_color((141, 141, 142)).bold(),
                 "Show the version.",
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "build [--release|--profile NAME]"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                "Build the project described by the nearest 'thrush.toml'. The profile defaults to 'debug'.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "run [--release|--profile NAME] [-- ARGS..]"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                "Build the project and run it with the given arguments.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "test [--release|--profile NAME] [-- ARGS..]"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                "Build the '@test' functions with a harness that runs them and prints a summary.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "doc [--html]".custom_color((141, 141, 142)).bold(),
+                "Write a Markdown or HTML site from the '///' comments into the build directory.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "repl [-jit-c PATH] [-jit-lib PATH]"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                "Start an interactive session that compiles every snippet with the LLVM JIT. A snippet that aborts, like a failed assert, ends the session.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n\n",
+                "•".bold(),
+                "explain [E0001]".custom_color((141, 141, 142)).bold(),
+                "Show the detailed explanation of a diagnostic code.",
+            ),
+        );
+
         logging::write(logging::OutputIn::Stderr, "LLVM Commands:\n\n");
 
         logging::write(
@@ -785,6 +1707,30 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "coverage-report [FILE]"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                "Show the line coverage recorded by programs compiled with '-fcoverage'.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "toolchain status|clean|reinstall"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                "Inspect, remove or unpack again the embedded Clang toolchain.",
+            ),
+        );
+
         logging::write(logging::OutputIn::Stderr, "\nGeneral flags:\n\n");
 
         logging::write(
@@ -797,6 +1743,27 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "-j [N]".custom_color((141, 141, 142)).bold(),
+                "Compile up to N files at the same time.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} [{}] {}\n",
+                "•".bold(),
+                "-I".custom_color((141, 141, 142)).bold(),
+                "\"PATH\"",
+                "Add a directory to search for headers imported with '@cimport'.",
+            ),
+        );
+
         logging::write(
             logging::OutputIn::Stderr,
             &format!(
@@ -829,6 +1796,17 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} [{}] {}\n",
+                "•".bold(),
+                "-ld".custom_color((141, 141, 142)).bold(),
+                "\"/usr/bin/ld.lld\"",
+                "Link directly with 'ld.lld' or 'ld', resolving the crt objects, libc and dynamic linker for the Linux target.",
+            ),
+        );
+
         logging::write(
             logging::OutputIn::Stderr,
             &format!(
@@ -849,6 +1827,27 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} [{}] {}\n",
+                "•".bold(),
+                "-W|-A|-D".custom_color((141, 141, 142)).bold(),
+                "unused|unused_local|W0004|warnings",
+                "Warn, allow or deny a lint. A denied lint fails the build.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "-Werror".custom_color((141, 141, 142)).bold(),
+                "Treat every lint that is not explicitly allowed as an error.",
+            ),
+        );
+
         logging::write(logging::OutputIn::Stderr, "\nCompiler flags:\n\n");
 
         logging::write(
@@ -883,13 +1882,35 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} [{}] {}\n",
+                "•".bold(),
+                "--crate-type".custom_color((141, 141, 142)).bold(),
+                "exe|staticlib|sharedlib|obj",
+                "Select the kind of artifact to produce. Executables are the default.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} [{}] {}\n",
+                "•".bold(),
+                "-o".custom_color((141, 141, 142)).bold(),
+                "\"PATH\"",
+                "Write the final artifact to the specified path.",
+            ),
+        );
+
         logging::write(
             logging::OutputIn::Stderr,
             &format!(
                 "{} {} [{}] {}\n",
                 "•".bold(),
                 "-emit".custom_color((141, 141, 142)).bold(),
-                "llvm-bc|llvm-ir|asm|raw-llvm-ir|raw-llvm-bc|raw-asm|obj|ast|tokens",
+                "llvm-bc|llvm-ir|asm|raw-llvm-ir|raw-llvm-bc|raw-asm|obj|ast|ast-json|tokens|tokens-json|c-header|cfg-dot|callgraph-dot",
                 "Compile the code into specified representation.",
             ),
         );
@@ -905,6 +1926,58 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "-g".custom_color((141, 141, 142)).bold(),
+                "Generate DWARF debug information.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "--bounds-checks".custom_color((141, 141, 142)).bold(),
+                "Abort on out of range array indexes. Enabled by default with '-opt none'.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "--no-bounds-checks".custom_color((141, 141, 142)).bold(),
+                "Disable array bounds checks.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "-fsanitize=address|undefined"
+                    .custom_color((141, 141, 142))
+                    .bold(),
+                "Instrument memory accesses or trap on signed overflow, division by zero and oversized shifts.",
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "-fcoverage".custom_color((141, 141, 142)).bold(),
+                "Count the runs of every function and branch, and append them to 'thrush.coverage' or $THRUSH_COVERAGE_FILE at exit.",
+            ),
+        );
+
         /* logging::write(logging::OutputIn::Stderr, "\nJIT Compiler flags:\n\n");
 
         logging::write(
@@ -997,6 +2070,16 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "--link-dry-run".custom_color((141, 141, 142)).bold(),
+                "Print the linker command instead of running it."
+            ),
+        );
+
         logging::write(
             logging::OutputIn::Stderr,
             &format!(
@@ -1007,6 +2090,46 @@ impl CLI {
             ),
         );
 
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "-MD".custom_color((141, 141, 142)).bold(),
+                "Write a Makefile dependency file next to the output, listing the sources and C headers of every object."
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "-MF [FILE]".custom_color((141, 141, 142)).bold(),
+                "Write the dependency file to the given path. Implies '-MD'."
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "--no-incremental".custom_color((141, 141, 142)).bold(),
+                "Compile every file again instead of reusing the objects cached in the build directory."
+            ),
+        );
+
+        logging::write(
+            logging::OutputIn::Stderr,
+            &format!(
+                "{} {} {}\n",
+                "•".bold(),
+                "--explain-rebuild".custom_color((141, 141, 142)).bold(),
+                "Show why each file could not reuse its cached object."
+            ),
+        );
+
         logging::write(
             logging::OutputIn::Stderr,
             &format!(
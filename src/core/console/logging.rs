@@ -1,11 +1,38 @@
 use colored::{ColoredString, Colorize};
 
 use std::{
+    cell::RefCell,
+    collections::BTreeMap,
     io::{self, Write},
     process,
+    sync::{Mutex, MutexGuard, PoisonError},
 };
 
-#[derive(Debug)]
+pub type CapturedOutput = Vec<(OutputIn, Vec<u8>)>;
+
+thread_local! {
+    static CAPTURED: RefCell<Option<CapturedOutput>> = const { RefCell::new(None) };
+    static RECORDED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// The output of the files of a parallel build, printed in the order of the files.
+static ORDERED_OUTPUT: Mutex<OrderedOutput> = Mutex::new(OrderedOutput {
+    next: 0,
+    pending: BTreeMap::new(),
+});
+
+/// The next file to print, and the output of the files after it that already finished.
+struct OrderedOutput {
+    next: usize,
+    pending: BTreeMap<usize, CapturedOutput>,
+}
+
+struct Output(OutputIn);
+
+/// Keeps a copy of what this thread prints to stderr, until it is finished or dropped.
+pub struct Recording;
+
+#[derive(Debug, Clone, Copy)]
 pub enum OutputIn {
     Stdout,
     Stderr,
@@ -25,11 +52,11 @@ pub enum LoggingType {
 
 pub fn log(ltype: LoggingType, msg: &str) {
     if ltype.is_bug() {
-        io::stderr()
+        self::stderr()
             .write_all(format!("{} {}\n", ltype.as_styled(), msg).as_bytes())
             .unwrap_or_default();
 
-        io::stderr()
+        self::stderr()
             .write_all(
                 format!(
                     "\nThis is a {} at code generation time. Report it in: '{}'.\n",
@@ -43,15 +70,15 @@ pub fn log(ltype: LoggingType, msg: &str) {
             )
             .unwrap_or_default();
 
-        process::exit(1);
+        self::exit_failure();
     }
 
     if ltype.is_backend_bug() {
-        io::stderr()
+        self::stderr()
             .write_all(format!("{} {}\n", ltype.as_styled(), msg).as_bytes())
             .unwrap_or_default();
 
-        io::stderr()
+        self::stderr()
             .write_all(
                 format!(
                     "\nThis is a {} at code generation time. Report it in: '{}'.\n",
@@ -65,23 +92,23 @@ pub fn log(ltype: LoggingType, msg: &str) {
             )
             .unwrap_or_default();
 
-        process::exit(1);
+        self::exit_failure();
     }
 
     if ltype.is_panic() {
-        io::stderr()
+        self::stderr()
             .write_all(format!("{} {}\n", ltype.as_styled(), msg).as_bytes())
             .unwrap_or_default();
 
-        process::exit(1);
+        self::exit_failure();
     }
 
     if ltype.is_backend_panic() {
-        io::stderr()
+        self::stderr()
             .write_all(format!("\n{} {}", ltype.as_styled(), msg).as_bytes())
             .unwrap_or_default();
 
-        io::stderr()
+        self::stderr()
             .write_all(
                 format!(
                     "\n\nMaybe this is a issue... Report it in: '{}'.\n",
@@ -94,7 +121,7 @@ pub fn log(ltype: LoggingType, msg: &str) {
             )
             .unwrap_or_default();
 
-        io::stderr()
+        self::stderr()
             .write_all(
                 format!(
                     "\n{} It isn't a issue if:\n• Comes from the inline assembler thing.\n\n",
@@ -108,7 +135,7 @@ pub fn log(ltype: LoggingType, msg: &str) {
     }
 
     if ltype.is_err() {
-        io::stderr()
+        self::stderr()
             .write_all(format!("{} {}\n", ltype.as_styled(), msg).as_bytes())
             .unwrap_or_default();
 
@@ -116,33 +143,159 @@ pub fn log(ltype: LoggingType, msg: &str) {
     }
 
     if ltype.is_frontend_panic() {
-        io::stderr()
+        self::stderr()
             .write_all(format!("\n{} {}", ltype.as_styled(), msg).as_bytes())
             .unwrap_or_default();
 
-        process::exit(1);
+        self::exit_failure();
     }
 
     if ltype.is_warn() || ltype.is_info() {
-        io::stderr()
+        self::stderr()
             .write_all(format!("{} {}", ltype.as_styled(), msg).as_bytes())
             .unwrap_or_default();
 
         return;
     }
 
-    io::stdout()
+    self::stdout()
         .write_all(format!("{} {}", ltype.as_styled(), msg).as_bytes())
         .unwrap_or_default();
 }
 
 pub fn write(output_in: OutputIn, text: &str) {
+    self::emit(output_in, text.as_bytes());
+}
+
+/// Runs the closure keeping everything it logs on this thread, so the output of
+/// files compiled in parallel can be printed file by file.
+pub fn capture<T>(function: impl FnOnce() -> T) -> (T, CapturedOutput) {
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(Vec::with_capacity(100)));
+
+    let result: T = function();
+
+    let output: CapturedOutput = CAPTURED
+        .with(|captured| captured.borrow_mut().take())
+        .unwrap_or_default();
+
+    (result, output)
+}
+
+/// Starts keeping a copy of what this thread prints to stderr, which still gets printed.
+pub fn record() -> Recording {
+    RECORDED.with(|recorded| *recorded.borrow_mut() = Some(String::with_capacity(256)));
+
+    Recording
+}
+
+/// Starts the ordered output of a new parallel build from its first file.
+pub fn reset_ordered_output() {
+    let mut guard: MutexGuard<OrderedOutput> = self::lock_ordered_output();
+
+    guard.next = 0;
+    guard.pending.clear();
+}
+
+/// Prints the output of a file of a parallel build once every file before it is printed.
+pub fn replay_in_order(position: usize, output: CapturedOutput) {
+    let mut guard: MutexGuard<OrderedOutput> = self::lock_ordered_output();
+    let ordered: &mut OrderedOutput = &mut guard;
+
+    ordered.pending.insert(position, output);
+
+    while let Some(output) = ordered.pending.remove(&ordered.next) {
+        self::replay(output);
+        ordered.next += 1;
+    }
+}
+
+pub fn replay(output: CapturedOutput) {
+    output
+        .iter()
+        .for_each(|(output_in, text)| self::emit(*output_in, text));
+}
+
+fn emit(output_in: OutputIn, text: &[u8]) {
+    if let OutputIn::Stderr = output_in {
+        RECORDED.with(|recorded| {
+            if let Some(recorded) = recorded.borrow_mut().as_mut() {
+                recorded.push_str(&String::from_utf8_lossy(text));
+            }
+        });
+    }
+
+    let captured: bool = CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some(captured) => {
+            captured.push((output_in, text.to_vec()));
+            true
+        }
+        None => false,
+    });
+
+    if captured {
+        return;
+    }
+
     match output_in {
-        OutputIn::Stdout => io::stdout().write_all(text.as_bytes()).unwrap_or(()),
-        OutputIn::Stderr => io::stderr().write_all(text.as_bytes()).unwrap_or(()),
+        OutputIn::Stdout => io::stdout().write_all(text).unwrap_or(()),
+        OutputIn::Stderr => io::stderr().write_all(text).unwrap_or(()),
     };
 }
 
+fn stdout() -> Output {
+    Output(OutputIn::Stdout)
+}
+
+fn stderr() -> Output {
+    Output(OutputIn::Stderr)
+}
+
+/// Prints what the files of a parallel build and this thread had captured before
+/// leaving, so a fatal error never hides the diagnostics that led to it.
+fn exit_failure() -> ! {
+    let pending: BTreeMap<usize, CapturedOutput> =
+        std::mem::take(&mut self::lock_ordered_output().pending);
+
+    pending.into_values().for_each(self::replay);
+
+    if let Some(output) = CAPTURED.with(|captured| captured.borrow_mut().take()) {
+        self::replay(output);
+    }
+
+    process::exit(1);
+}
+
+fn lock_ordered_output() -> MutexGuard<'static, OrderedOutput> {
+    ORDERED_OUTPUT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Recording {
+    pub fn finish(self) -> String {
+        RECORDED
+            .with(|recorded| recorded.borrow_mut().take())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for Recording {
+    fn drop(&mut self) {
+        RECORDED.with(|recorded| *recorded.borrow_mut() = None);
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self::emit(self.0, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 impl LoggingType {
     pub fn as_styled(&self) -> ColoredString {
         match self {
@@ -1,6 +1,9 @@
 use colored::Colorize;
 
-use crate::core::compiler::constants::{LLVM_TARGET_TRIPLES_X86_64, LLVM_X86_64_SUPPORTED_CPUS};
+use crate::core::{
+    compiler::constants::{LLVM_TARGET_TRIPLES_X86_64, LLVM_X86_64_SUPPORTED_CPUS},
+    diagnostic::codes::DiagnosticCode,
+};
 
 /* ######################################################################
 
@@ -56,3 +59,43 @@ pub fn print_llvm_supported_targets_triples() {
 
 
 ########################################################################*/
+
+/* ######################################################################
+
+
+    DIAGNOSTICS | CLI UTILS - START
+
+
+########################################################################*/
+
+pub fn print_diagnostic_explanation(code: DiagnosticCode) {
+    let kind: &str = if code.is_warning() {
+        "Warning"
+    } else {
+        "Error"
+    };
+
+    println!(
+        "{} {}: {}\n",
+        kind.custom_color((141, 141, 142)).bold(),
+        code.as_str().bold().underline(),
+        code.get_title()
+    );
+
+    println!("{}", code.get_explanation());
+
+    if let Some(lint_name) = code.get_lint_name() {
+        println!(
+            "\nThis warning can be configured as '{}' with '-A', '-W', '-D' or '@allow({})'.",
+            lint_name, lint_name
+        );
+    }
+}
+
+/* ######################################################################
+
+
+    DIAGNOSTICS | CLI UTILS - END
+
+
+########################################################################*/
@@ -0,0 +1,829 @@
+use std::fmt::{self, Display};
+
+/* ######################################################################
+
+
+    DIAGNOSTIC CODES - START
+
+
+########################################################################*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCode {
+    SyntaxError,
+    UnknownCharacter,
+    TypeError,
+    MismatchedTypes,
+    NotFound,
+    UnknownReference,
+    StructureNotFound,
+    TooManyStructureFields,
+    MissingStructureFields,
+    ParameterAlreadyDeclared,
+    LLIAlreadyDeclared,
+    LocalAlreadyDeclared,
+    StaticAlreadyDeclared,
+    ConstantAlreadyDeclared,
+    CustomTypeAlreadyDeclared,
+    StructureAlreadyDeclared,
+    EnumAlreadyDeclared,
+    AssemblerFunctionAlreadyDeclared,
+    FunctionAlreadyDeclared,
+    DuplicatedEntrypoint,
+    AttributeError,
+    IllogicalAttribute,
+    RepeatedAttribute,
+    MissingAttribute,
+    CImportError,
+    MissingReturn,
+    UnassignedLocal,
+    DanglingStackPointer,
+
+    UndefinedBehavior,
+    PossibleUndefinedBehavior,
+    NotApplicableAttribute,
+    UnusedLocal,
+    UnusedMutableLocal,
+    UnusedLocalConstant,
+    UnusedLocalStatic,
+    UnusedLocalMutableStatic,
+    UnusedLLI,
+    UnusedParameter,
+    UnusedMutableParameter,
+    UnusedStatic,
+    UnusedConstant,
+    UnusedFunction,
+    UnusedAssemblerFunction,
+    UnusedEnum,
+    UnusedEnumField,
+    UnusedStructure,
+    UnusedStructureField,
+    UnsupportedCDeclaration,
+    UnreachableCode,
+}
+
+pub const DIAGNOSTIC_CODES: &[DiagnosticCode] = &[
+    DiagnosticCode::SyntaxError,
+    DiagnosticCode::UnknownCharacter,
+    DiagnosticCode::TypeError,
+    DiagnosticCode::MismatchedTypes,
+    DiagnosticCode::NotFound,
+    DiagnosticCode::UnknownReference,
+    DiagnosticCode::StructureNotFound,
+    DiagnosticCode::TooManyStructureFields,
+    DiagnosticCode::MissingStructureFields,
+    DiagnosticCode::ParameterAlreadyDeclared,
+    DiagnosticCode::LLIAlreadyDeclared,
+    DiagnosticCode::LocalAlreadyDeclared,
+    DiagnosticCode::StaticAlreadyDeclared,
+    DiagnosticCode::ConstantAlreadyDeclared,
+    DiagnosticCode::CustomTypeAlreadyDeclared,
+    DiagnosticCode::StructureAlreadyDeclared,
+    DiagnosticCode::EnumAlreadyDeclared,
+    DiagnosticCode::AssemblerFunctionAlreadyDeclared,
+    DiagnosticCode::FunctionAlreadyDeclared,
+    DiagnosticCode::DuplicatedEntrypoint,
+    DiagnosticCode::AttributeError,
+    DiagnosticCode::IllogicalAttribute,
+    DiagnosticCode::RepeatedAttribute,
+    DiagnosticCode::MissingAttribute,
+    DiagnosticCode::CImportError,
+    DiagnosticCode::MissingReturn,
+    DiagnosticCode::UnassignedLocal,
+    DiagnosticCode::DanglingStackPointer,
+    DiagnosticCode::UndefinedBehavior,
+    DiagnosticCode::PossibleUndefinedBehavior,
+    DiagnosticCode::NotApplicableAttribute,
+    DiagnosticCode::UnusedLocal,
+    DiagnosticCode::UnusedMutableLocal,
+    DiagnosticCode::UnusedLocalConstant,
+    DiagnosticCode::UnusedLocalStatic,
+    DiagnosticCode::UnusedLocalMutableStatic,
+    DiagnosticCode::UnusedLLI,
+    DiagnosticCode::UnusedParameter,
+    DiagnosticCode::UnusedMutableParameter,
+    DiagnosticCode::UnusedStatic,
+    DiagnosticCode::UnusedConstant,
+    DiagnosticCode::UnusedFunction,
+    DiagnosticCode::UnusedAssemblerFunction,
+    DiagnosticCode::UnusedEnum,
+    DiagnosticCode::UnusedEnumField,
+    DiagnosticCode::UnusedStructure,
+    DiagnosticCode::UnusedStructureField,
+    DiagnosticCode::UnsupportedCDeclaration,
+    DiagnosticCode::UnreachableCode,
+];
+
+pub const LINT_GROUP_UNUSED: &str = "unused";
+pub const LINT_GROUP_WARNINGS: &str = "warnings";
+
+/* ######################################################################
+
+
+    DIAGNOSTIC CODES - END
+
+
+########################################################################*/
+
+impl DiagnosticCode {
+    /// The stable identifier of the diagnostic. Codes are never reused or
+    /// renumbered once released.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCode::SyntaxError => "E0001",
+            DiagnosticCode::UnknownCharacter => "E0002",
+            DiagnosticCode::TypeError => "E0003",
+            DiagnosticCode::MismatchedTypes => "E0004",
+            DiagnosticCode::NotFound => "E0005",
+            DiagnosticCode::UnknownReference => "E0006",
+            DiagnosticCode::StructureNotFound => "E0007",
+            DiagnosticCode::TooManyStructureFields => "E0008",
+            DiagnosticCode::MissingStructureFields => "E0009",
+            DiagnosticCode::ParameterAlreadyDeclared => "E0010",
+            DiagnosticCode::LLIAlreadyDeclared => "E0011",
+            DiagnosticCode::LocalAlreadyDeclared => "E0012",
+            DiagnosticCode::StaticAlreadyDeclared => "E0013",
+            DiagnosticCode::ConstantAlreadyDeclared => "E0014",
+            DiagnosticCode::CustomTypeAlreadyDeclared => "E0015",
+            DiagnosticCode::StructureAlreadyDeclared => "E0016",
+            DiagnosticCode::EnumAlreadyDeclared => "E0017",
+            DiagnosticCode::AssemblerFunctionAlreadyDeclared => "E0018",
+            DiagnosticCode::FunctionAlreadyDeclared => "E0019",
+            DiagnosticCode::DuplicatedEntrypoint => "E0020",
+            DiagnosticCode::AttributeError => "E0021",
+            DiagnosticCode::IllogicalAttribute => "E0022",
+            DiagnosticCode::RepeatedAttribute => "E0023",
+            DiagnosticCode::MissingAttribute => "E0024",
+            DiagnosticCode::CImportError => "E0025",
+            DiagnosticCode::MissingReturn => "E0026",
+            DiagnosticCode::UnassignedLocal => "E0027",
+            DiagnosticCode::DanglingStackPointer => "E0028",
+
+            DiagnosticCode::UndefinedBehavior => "W0001",
+            DiagnosticCode::PossibleUndefinedBehavior => "W0002",
+            DiagnosticCode::NotApplicableAttribute => "W0003",
+            DiagnosticCode::UnusedLocal => "W0004",
+            DiagnosticCode::UnusedMutableLocal => "W0005",
+            DiagnosticCode::UnusedLocalConstant => "W0006",
+            DiagnosticCode::UnusedLocalStatic => "W0007",
+            DiagnosticCode::UnusedLocalMutableStatic => "W0008",
+            DiagnosticCode::UnusedLLI => "W0009",
+            DiagnosticCode::UnusedParameter => "W0010",
+            DiagnosticCode::UnusedMutableParameter => "W0011",
+            DiagnosticCode::UnusedStatic => "W0012",
+            DiagnosticCode::UnusedConstant => "W0013",
+            DiagnosticCode::UnusedFunction => "W0014",
+            DiagnosticCode::UnusedAssemblerFunction => "W0015",
+            DiagnosticCode::UnusedEnum => "W0016",
+            DiagnosticCode::UnusedEnumField => "W0017",
+            DiagnosticCode::UnusedStructure => "W0018",
+            DiagnosticCode::UnusedStructureField => "W0019",
+            DiagnosticCode::UnsupportedCDeclaration => "W0020",
+            DiagnosticCode::UnreachableCode => "W0021",
+        }
+    }
+
+    pub fn get_title(&self) -> &'static str {
+        match self {
+            DiagnosticCode::SyntaxError => "Syntax error",
+            DiagnosticCode::UnknownCharacter => "Unknown character",
+            DiagnosticCode::TypeError => "Type error",
+            DiagnosticCode::MismatchedTypes => "Mismatched types",
+            DiagnosticCode::NotFound => "Not found",
+            DiagnosticCode::UnknownReference => "Unknown reference",
+            DiagnosticCode::StructureNotFound => "Structure not found",
+            DiagnosticCode::TooManyStructureFields => "Too many fields in structure",
+            DiagnosticCode::MissingStructureFields => "Missing fields in structure",
+            DiagnosticCode::ParameterAlreadyDeclared => "Parameter already declared",
+            DiagnosticCode::LLIAlreadyDeclared => "Low level instruction already declared",
+            DiagnosticCode::LocalAlreadyDeclared => "Local variable already declared",
+            DiagnosticCode::StaticAlreadyDeclared => "Static already declared",
+            DiagnosticCode::ConstantAlreadyDeclared => "Constant already declared",
+            DiagnosticCode::CustomTypeAlreadyDeclared => "Custom type already declared",
+            DiagnosticCode::StructureAlreadyDeclared => "Structure already declared",
+            DiagnosticCode::EnumAlreadyDeclared => "Enum already declared",
+            DiagnosticCode::AssemblerFunctionAlreadyDeclared => {
+                "Assembly function already declared"
+            }
+            DiagnosticCode::FunctionAlreadyDeclared => "Function already declared",
+            DiagnosticCode::DuplicatedEntrypoint => "Duplicated entrypoint",
+            DiagnosticCode::AttributeError => "Attribute error",
+            DiagnosticCode::IllogicalAttribute => "Illogical attribute",
+            DiagnosticCode::RepeatedAttribute => "Repeated attribute",
+            DiagnosticCode::MissingAttribute => "Missing attribute",
+            DiagnosticCode::CImportError => "C import error",
+            DiagnosticCode::MissingReturn => "Missing return",
+            DiagnosticCode::UnassignedLocal => "Use of an unassigned local",
+            DiagnosticCode::DanglingStackPointer => "Dangling stack pointer",
+
+            DiagnosticCode::UndefinedBehavior => "Undefined behavior",
+            DiagnosticCode::PossibleUndefinedBehavior => "Possible undefined behavior",
+            DiagnosticCode::NotApplicableAttribute => "Not applicable attribute",
+            DiagnosticCode::UnusedLocal => "Local not used",
+            DiagnosticCode::UnusedMutableLocal => "Mutable local not used",
+            DiagnosticCode::UnusedLocalConstant => "Local constant not used",
+            DiagnosticCode::UnusedLocalStatic => "Local static not used",
+            DiagnosticCode::UnusedLocalMutableStatic => "Local mutable static not used",
+            DiagnosticCode::UnusedLLI => "LLI not used",
+            DiagnosticCode::UnusedParameter => "Parameter not used",
+            DiagnosticCode::UnusedMutableParameter => "Mutable parameter not used",
+            DiagnosticCode::UnusedStatic => "Static not used",
+            DiagnosticCode::UnusedConstant => "Constant not used",
+            DiagnosticCode::UnusedFunction => "Function not used",
+            DiagnosticCode::UnusedAssemblerFunction => "Assembler function not used",
+            DiagnosticCode::UnusedEnum => "Enum not used",
+            DiagnosticCode::UnusedEnumField => "Enum field not used",
+            DiagnosticCode::UnusedStructure => "Structure not used",
+            DiagnosticCode::UnusedStructureField => "Structure field not used",
+            DiagnosticCode::UnsupportedCDeclaration => "Unsupported C declaration",
+            DiagnosticCode::UnreachableCode => "Unreachable code",
+        }
+    }
+
+    pub fn get_explanation(&self) -> &'static str {
+        match self {
+            DiagnosticCode::SyntaxError => {
+                "The source code does not follow the grammar of the language.
+
+This is usually caused by a missing token, such as a ';' at the end of a
+statement or a '}' closing a block, or by a malformed literal.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local x: s32 = 10
+        return x;
+    }
+
+Add the missing ';' after the local declaration:
+
+    fn main() s32 {
+        local x: s32 = 10;
+        return x;
+    }"
+            }
+
+            DiagnosticCode::UnknownCharacter => {
+                "The lexer found a character that isn't part of the language.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local x: s32 = 10 $ 2;
+    }
+
+Remove the character or replace it with a valid operator."
+            }
+
+            DiagnosticCode::TypeError => {
+                "An expression or a statement was used with a type that it does not
+support.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local x: bool = true;
+        return x + 1;
+    }
+
+Booleans cannot take part in arithmetic. Use a numeric type or convert the
+value explicitly with 'as'."
+            }
+
+            DiagnosticCode::MismatchedTypes => {
+                "The type of a value does not match the type expected at that position.
+
+Erroneous code example:
+
+    fn square(x: s32) s32 {
+        return x * x;
+    }
+
+    fn main() s32 {
+        return square(true);
+    }
+
+Pass a value of the declared parameter type, or cast it with 'as'."
+            }
+
+            DiagnosticCode::NotFound => {
+                "A name was used but no symbol with that name is in scope.
+
+Erroneous code example:
+
+    fn main() s32 {
+        return counter;
+    }
+
+Declare the symbol before using it, or check the spelling of the name."
+            }
+
+            DiagnosticCode::UnknownReference => {
+                "A reference points to a symbol that the compiler could not resolve.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local p: ptr = address missing[0];
+        return 0;
+    }
+
+Make sure the referenced symbol is declared and visible at this point."
+            }
+
+            DiagnosticCode::StructureNotFound => {
+                "A structure was constructed or used as a type, but it was never
+declared.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local p: Point = new Point { x: 1, y: 2 };
+        return 0;
+    }
+
+Declare the structure first:
+
+    struct Point {
+        x: s32,
+        y: s32
+    }"
+            }
+
+            DiagnosticCode::TooManyStructureFields => {
+                "A structure constructor sets more fields than the structure declares.
+
+Erroneous code example:
+
+    struct Point {
+        x: s32,
+        y: s32
+    }
+
+    local p: Point = new Point { x: 1, y: 2, z: 3 };
+
+Remove the fields that are not part of the declaration."
+            }
+
+            DiagnosticCode::MissingStructureFields => {
+                "A structure constructor does not set every field of the structure.
+
+Erroneous code example:
+
+    struct Point {
+        x: s32,
+        y: s32
+    }
+
+    local p: Point = new Point { x: 1 };
+
+Every field must be initialized when constructing a structure."
+            }
+
+            DiagnosticCode::ParameterAlreadyDeclared => {
+                "Two parameters of the same function share the same name.
+
+Erroneous code example:
+
+    fn add(a: s32, a: s32) s32 {
+        return a + a;
+    }
+
+Give each parameter a unique name."
+            }
+
+            DiagnosticCode::LLIAlreadyDeclared => {
+                "A low level instruction was declared twice in the same scope.
+
+Erroneous code example:
+
+    instr value: s32 = 1;
+    instr value: s32 = 2;
+
+Rename one of the instructions or remove the duplicated declaration."
+            }
+
+            DiagnosticCode::LocalAlreadyDeclared => {
+                "A local variable was declared twice in the same scope, or it has the
+same name as a parameter of the enclosing function.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local x: s32 = 1;
+        local x: s32 = 2;
+        return x;
+    }
+
+Rename the second local, or assign to the existing one instead."
+            }
+
+            DiagnosticCode::StaticAlreadyDeclared => {
+                "A static variable was declared twice in the same scope.
+
+Erroneous code example:
+
+    static counter: s32 = 0;
+    static counter: s32 = 1;
+
+Rename one of the statics or remove the duplicated declaration."
+            }
+
+            DiagnosticCode::ConstantAlreadyDeclared => {
+                "A constant was declared twice in the same scope.
+
+Erroneous code example:
+
+    const LIMIT: s32 = 10;
+    const LIMIT: s32 = 20;
+
+Rename one of the constants or remove the duplicated declaration."
+            }
+
+            DiagnosticCode::CustomTypeAlreadyDeclared => {
+                "A custom type alias was declared twice.
+
+Erroneous code example:
+
+    type Pair = { s32 s32 }
+    type Pair = { s64 s64 }
+
+Rename one of the aliases or remove the duplicated declaration."
+            }
+
+            DiagnosticCode::StructureAlreadyDeclared => {
+                "A structure was declared twice in the same file.
+
+Erroneous code example:
+
+    struct Point { x: s32, y: s32 }
+    struct Point { x: f32, y: f32 }
+
+Rename one of the structures or remove the duplicated declaration."
+            }
+
+            DiagnosticCode::EnumAlreadyDeclared => {
+                "An enum was declared twice in the same file.
+
+Rename one of the enums or remove the duplicated declaration."
+            }
+
+            DiagnosticCode::AssemblerFunctionAlreadyDeclared => {
+                "An assembler function was declared twice in the same file, or it has
+the same name as another function.
+
+Rename one of the functions or remove the duplicated declaration."
+            }
+
+            DiagnosticCode::FunctionAlreadyDeclared => {
+                "A function was declared twice in the same file.
+
+Erroneous code example:
+
+    fn sum(a: s32, b: s32) s32 { return a + b; }
+    fn sum(a: s32, b: s32) s32 { return b + a; }
+
+Functions cannot be overloaded. Rename one of them."
+            }
+
+            DiagnosticCode::DuplicatedEntrypoint => {
+                "More than one 'main' function was declared.
+
+A program has exactly one entrypoint. Remove or rename the extra 'main'
+functions."
+            }
+
+            DiagnosticCode::AttributeError => {
+                "An attribute was applied where it isn't allowed, or it conflicts with
+the declaration it is attached to.
+
+Erroneous code example:
+
+    fn puts(str: ptr) s32 @public @extern(\"puts\") {
+        return 0;
+    }
+
+External functions are defined elsewhere and cannot have a body:
+
+    fn puts(str: ptr) s32 @public @extern(\"puts\");"
+            }
+
+            DiagnosticCode::IllogicalAttribute => {
+                "Two attributes that contradict each other were applied to the same
+declaration.
+
+Erroneous code example:
+
+    fn fast() void @inline @noinline {}
+
+Keep only one of the inlining attributes."
+            }
+
+            DiagnosticCode::RepeatedAttribute => {
+                "The same attribute was applied more than once to a declaration.
+
+Erroneous code example:
+
+    fn helper() void @public @public {}
+
+Remove the repeated attribute."
+            }
+
+            DiagnosticCode::MissingAttribute => {
+                "A declaration requires an attribute that it doesn't have.
+
+Erroneous code example:
+
+    fn printf(fmt: ptr) s32 @extern(\"printf\");
+
+External functions always have public visibility:
+
+    fn printf(fmt: ptr) s32 @public @extern(\"printf\");"
+            }
+
+            DiagnosticCode::CImportError => {
+                "A C header requested with '@cimport' could not be imported.
+
+Erroneous code example:
+
+    @cimport(\"missing.h\");
+
+Headers are searched relative to the importing file, then in every
+directory passed with '-I'. Check the path and the include directories."
+            }
+
+            DiagnosticCode::MissingReturn => {
+                "A function that returns a value can reach the end of its body without
+a 'return'.
+
+Erroneous code example:
+
+    fn sign(x: s32) s32 {
+        if x < 0 {
+            return -1;
+        } elif x > 0 {
+            return 1;
+        }
+    }
+
+Every path through the body must end in a 'return' with a value of the
+return type, or in a 'loop' that is never left."
+            }
+
+            DiagnosticCode::UnassignedLocal => {
+                "A local declared without a value is read before a value is assigned
+to it on every path that reaches the read.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local mut x: s32;
+
+        if true {
+            x = 1;
+        }
+
+        return x;
+    }
+
+Assign the local on every path before reading it, or give it a value
+when it is declared."
+            }
+
+            DiagnosticCode::DanglingStackPointer => {
+                "A pointer to the stack memory of a function outlives the function,
+either because it is returned or because it is stored in a static.
+
+Erroneous code example:
+
+    fn counter() ptr[s32] {
+        local mut count: s32 = 0;
+
+        return address count;
+    }
+
+The stack memory of 'count' is released when 'counter' returns, so the
+pointer is dangling. Allocate the value with 'alloc @heap' or 'alloc
+@static' instead, or return the value itself."
+            }
+
+            DiagnosticCode::UndefinedBehavior => {
+                "A low level instruction computes an address from a value whose runtime
+contents are unknown, which may result in undefined behavior.
+
+Make sure that the pointer used for the memory calculation is valid and
+points to an object of the expected type."
+            }
+
+            DiagnosticCode::PossibleUndefinedBehavior => {
+                "The function requests aggressive optimizations that can change its
+semantics in specific scenarios.
+
+Review the optimization attributes applied to the function and remove them
+if the function relies on strict semantics."
+            }
+
+            DiagnosticCode::NotApplicableAttribute => {
+                "An attribute has no effect on the declaration it is applied to.
+
+Remove the attribute, it will be ignored by the compiler."
+            }
+
+            DiagnosticCode::UnusedLocal => {
+                "A local variable is declared but never read.
+
+Erroneous code example:
+
+    fn main() s32 {
+        local x: s32 = 1;
+        return 0;
+    }
+
+Remove the local or use it."
+            }
+
+            DiagnosticCode::UnusedMutableLocal => {
+                "A mutable local variable is declared but never read.
+
+Remove the local or use it."
+            }
+
+            DiagnosticCode::UnusedLocalConstant => {
+                "A constant declared inside a function is never used.
+
+Remove the constant or use it."
+            }
+
+            DiagnosticCode::UnusedLocalStatic => {
+                "A static declared inside a function is never used.
+
+Remove the static or use it."
+            }
+
+            DiagnosticCode::UnusedLocalMutableStatic => {
+                "A mutable static declared inside a function is never used.
+
+Remove the static or use it."
+            }
+
+            DiagnosticCode::UnusedLLI => {
+                "A low level instruction is declared but its value is never used.
+
+Remove the instruction or use its value."
+            }
+
+            DiagnosticCode::UnusedParameter => {
+                "A function parameter is never read inside the function body.
+
+Remove the parameter or use it."
+            }
+
+            DiagnosticCode::UnusedMutableParameter => {
+                "A mutable function parameter is never read inside the function body.
+
+Remove the parameter or use it."
+            }
+
+            DiagnosticCode::UnusedStatic => {
+                "A global static is declared but never used.
+
+Remove the static, use it, or mark it '@public' if it is part of the
+library interface."
+            }
+
+            DiagnosticCode::UnusedConstant => {
+                "A global constant is declared but never used.
+
+Remove the constant or use it."
+            }
+
+            DiagnosticCode::UnusedFunction => {
+                "A function is declared but never called.
+
+Remove the function, call it, or mark it '@public' if it is part of the
+library interface."
+            }
+
+            DiagnosticCode::UnusedAssemblerFunction => {
+                "An assembler function is declared but never called.
+
+Remove the function, call it, or mark it '@public' if it is part of the
+library interface."
+            }
+
+            DiagnosticCode::UnusedEnum => {
+                "An enum is declared but never used.
+
+Remove the enum or use it."
+            }
+
+            DiagnosticCode::UnusedEnumField => {
+                "An enum field is declared but never used.
+
+Remove the field or use it."
+            }
+
+            DiagnosticCode::UnusedStructure => {
+                "A structure is declared but never used.
+
+Remove the structure or use it."
+            }
+
+            DiagnosticCode::UnusedStructureField => {
+                "A structure field is declared but never read.
+
+Remove the field or use it."
+            }
+
+            DiagnosticCode::UnsupportedCDeclaration => {
+                "A declaration of a C header imported with '@cimport' has no Thrush
+equivalent and was skipped.
+
+Unions, bit-fields, global variables, inline functions and macros that
+aren't integer constants cannot be imported. Declare the parts you need by
+hand with '@extern' if required."
+            }
+
+            DiagnosticCode::UnreachableCode => {
+                "A statement can never be executed, because every path before it ends
+in a 'return', 'break' or 'continue', or in a 'loop' that is never left.
+
+Erroneous code example:
+
+    fn main() s32 {
+        return 0;
+        local x: s32 = 1;
+    }
+
+Remove the statement or the control flow that skips it."
+            }
+        }
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.as_str().starts_with('W')
+    }
+
+    /// The name used to refer to a warning from '-W', '-A', '-D' and '@allow'.
+    pub fn get_lint_name(&self) -> Option<&'static str> {
+        match self {
+            DiagnosticCode::UndefinedBehavior => Some("undefined_behavior"),
+            DiagnosticCode::PossibleUndefinedBehavior => Some("possible_undefined_behavior"),
+            DiagnosticCode::NotApplicableAttribute => Some("not_applicable_attribute"),
+            DiagnosticCode::UnusedLocal => Some("unused_local"),
+            DiagnosticCode::UnusedMutableLocal => Some("unused_mut_local"),
+            DiagnosticCode::UnusedLocalConstant => Some("unused_local_constant"),
+            DiagnosticCode::UnusedLocalStatic => Some("unused_local_static"),
+            DiagnosticCode::UnusedLocalMutableStatic => Some("unused_local_mut_static"),
+            DiagnosticCode::UnusedLLI => Some("unused_lli"),
+            DiagnosticCode::UnusedParameter => Some("unused_parameter"),
+            DiagnosticCode::UnusedMutableParameter => Some("unused_mut_parameter"),
+            DiagnosticCode::UnusedStatic => Some("unused_static"),
+            DiagnosticCode::UnusedConstant => Some("unused_constant"),
+            DiagnosticCode::UnusedFunction => Some("unused_function"),
+            DiagnosticCode::UnusedAssemblerFunction => Some("unused_asm_function"),
+            DiagnosticCode::UnusedEnum => Some("unused_enum"),
+            DiagnosticCode::UnusedEnumField => Some("unused_enum_field"),
+            DiagnosticCode::UnusedStructure => Some("unused_struct"),
+            DiagnosticCode::UnusedStructureField => Some("unused_struct_field"),
+            DiagnosticCode::UnsupportedCDeclaration => Some("unsupported_c_declaration"),
+            DiagnosticCode::UnreachableCode => Some("unreachable_code"),
+
+            _ => None,
+        }
+    }
+
+    /// Whether the lint name refers to this warning, either by its own name, by its
+    /// code, or through one of the lint groups 'unused' and 'warnings'.
+    pub fn matches_lint(&self, lint: &str) -> bool {
+        let Some(lint_name) = self.get_lint_name() else {
+            return false;
+        };
+
+        lint == lint_name
+            || lint == LINT_GROUP_WARNINGS
+            || (lint == LINT_GROUP_UNUSED && lint_name.starts_with("unused_"))
+            || self.as_str().eq_ignore_ascii_case(lint)
+    }
+}
+
+impl DiagnosticCode {
+    pub fn is_known_lint(lint: &str) -> bool {
+        DIAGNOSTIC_CODES
+            .iter()
+            .any(|diagnostic_code| diagnostic_code.matches_lint(lint))
+    }
+
+    pub fn parse(code: &str) -> Option<DiagnosticCode> {
+        DIAGNOSTIC_CODES
+            .iter()
+            .find(|diagnostic_code| diagnostic_code.as_str().eq_ignore_ascii_case(code))
+            .copied()
+    }
+}
+
+impl Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
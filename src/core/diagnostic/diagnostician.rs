@@ -1,14 +1,11 @@
 use std::fmt::Display;
 
-use crate::core::compiler::options::CompilerFile;
-use crate::core::console::logging::{self, LoggingType};
+use crate::core::compiler::sourcemap::SourceMap;
+use crate::core::console::logging::LoggingType;
 use crate::core::diagnostic::{self, Diagnostic, printers};
 use crate::core::errors::standard::ThrushCompilerIssue;
 
-use {
-    colored::Colorize,
-    std::{fs, path::PathBuf},
-};
+use colored::Colorize;
 
 #[derive(Debug, Clone, Copy)]
 pub enum Notificator {
@@ -18,70 +15,70 @@ pub enum Notificator {
 
 #[derive(Debug, Clone)]
 pub struct Diagnostician {
-    path: PathBuf,
-    code: String,
+    source_map: SourceMap,
 }
 
 impl Diagnostician {
-    pub fn new(file: &CompilerFile) -> Self {
-        let code: String = fs::read_to_string(&file.path).unwrap_or_else(|_| {
-            logging::log(
-                LoggingType::Panic,
-                &format!(
-                    "Unable to read '{}' file for a correct diagnostic.",
-                    file.path.display()
-                ),
-            );
-
-            unreachable!()
-        });
-
+    pub fn new(source_map: &SourceMap) -> Self {
         Self {
-            path: file.path.clone(),
-            code,
+            source_map: source_map.clone(),
         }
     }
 }
 
+impl Diagnostician {
+    pub fn get_source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+}
+
 impl Diagnostician {
     pub fn build_diagnostic(&mut self, error: &ThrushCompilerIssue, logging_type: LoggingType) {
         match error {
-            ThrushCompilerIssue::Error(title, help, note, span) => {
+            ThrushCompilerIssue::Error(code, help, note, span) => {
                 let diagnostic: Diagnostic =
-                    diagnostic::build(&self.code, *span, help, Notificator::CommonHelp);
+                    diagnostic::build(&self.source_map, *span, help, Notificator::CommonHelp, &[]);
 
-                printers::print(
-                    &diagnostic,
-                    (title, &self.path, note.as_deref(), logging_type),
+                printers::print(&diagnostic, (*code, note.as_deref(), logging_type));
+            }
+
+            ThrushCompilerIssue::LabeledError(code, help, note, span, labels) => {
+                let diagnostic: Diagnostic = diagnostic::build(
+                    &self.source_map,
+                    *span,
+                    help,
+                    Notificator::CommonHelp,
+                    labels,
                 );
+
+                printers::print(&diagnostic, (*code, note.as_deref(), logging_type));
             }
 
-            ThrushCompilerIssue::Warning(title, help, span) => {
+            ThrushCompilerIssue::Warning(code, help, span) => {
                 let diagnostic: Diagnostic =
-                    diagnostic::build(&self.code, *span, help, Notificator::CommonHelp);
+                    diagnostic::build(&self.source_map, *span, help, Notificator::CommonHelp, &[]);
 
-                printers::print(&diagnostic, (title, &self.path, None, logging_type));
+                printers::print(&diagnostic, (*code, None, logging_type));
             }
 
             ThrushCompilerIssue::FrontEndBug(title, info, span, position, line) => {
-                let diagnostic: Diagnostic =
-                    diagnostic::build(&self.code, *span, info, Notificator::CompilerFronteEndBug);
+                let diagnostic: Diagnostic = diagnostic::build(
+                    &self.source_map,
+                    *span,
+                    info,
+                    Notificator::CompilerFronteEndBug,
+                    &[],
+                );
 
                 printers::print_compiler_frontend_bug(
                     &diagnostic,
-                    (title, *position, logging_type, &self.path, *line),
+                    (title, *position, logging_type, *line),
                 );
             }
         };
     }
 }
 
-impl Diagnostician {
-    pub fn get_file_path(&self) -> PathBuf {
-        self.path.clone()
-    }
-}
-
 impl Display for Notificator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
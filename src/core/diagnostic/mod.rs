@@ -1,122 +1,246 @@
+pub mod codes;
 pub mod diagnostician;
 pub mod errors;
 pub mod position;
 pub mod printers;
 
 use colored::Colorize;
-use std::str::Lines;
+use std::path::{Path, PathBuf};
 
 use crate::{
-    core::diagnostic::{diagnostician::Notificator, position::CodePosition},
+    core::{
+        compiler::sourcemap::{SourceFile, SourceMap},
+        diagnostic::{diagnostician::Notificator, position::CodeRange},
+        errors::standard::SecondaryLabel,
+    },
     frontend::lexer::span::Span,
 };
 
+const MAXIMUM_SNIPPET_LINES: usize = 6;
+
 #[derive(Debug)]
-pub struct Diagnostic<'a> {
-    code: &'a str,
-    signaler: String,
-    span: Span,
+pub struct Diagnostic {
+    path: PathBuf,
+    snippet: String,
+    line: usize,
+    column: usize,
 }
 
-impl<'a> Diagnostic<'a> {
-    pub fn new(code: &'a str, signaler: String, span: Span) -> Self {
+impl Diagnostic {
+    pub fn new(path: PathBuf, snippet: String, line: usize, column: usize) -> Self {
         Self {
-            code,
-            signaler,
-            span,
+            path,
+            snippet,
+            line,
+            column,
         }
     }
 }
 
-impl Diagnostic<'_> {
-    pub fn get_code(&self) -> &str {
-        self.code
+impl Diagnostic {
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get_snippet(&self) -> &str {
+        &self.snippet
     }
 
-    pub fn get_signaler(&self) -> &str {
-        &self.signaler
+    pub fn get_line(&self) -> usize {
+        self.line
     }
 
-    pub fn get_span(&self) -> Span {
-        self.span
+    pub fn get_column(&self) -> usize {
+        self.column
     }
 }
 
-pub fn build<'a>(
-    code: &'a str,
+pub fn build(
+    source_map: &SourceMap,
     span: Span,
-    info: &'a str,
+    info: &str,
     notificator: Notificator,
-) -> Diagnostic<'a> {
-    if let Some(code_position) = position::find_line_and_range(code, span) {
-        if let Some(diagnostic) = self::generate(code, code_position, info, notificator) {
-            return diagnostic;
-        }
-    }
-
-    self::generate_basic(code, span, info)
+    labels: &[SecondaryLabel],
+) -> Diagnostic {
+    let Some(source) = source_map.get(span.get_file()) else {
+        return Diagnostic::new(
+            PathBuf::from("<unknown>"),
+            format!("{}{}\n\n", notificator, info),
+            span.get_line(),
+            span.get_span_start(),
+        );
+    };
+
+    let mut diagnostic: Diagnostic = match position::find_line_and_range(&source, span) {
+        Some(range) => self::generate(&source, range, info, notificator),
+        None => self::generate_basic(&source, span, info),
+    };
+
+    labels.iter().for_each(|(label_span, message)| {
+        diagnostic.snippet.push_str(&self::generate_label(
+            source_map,
+            &source,
+            *label_span,
+            message,
+        ));
+    });
+
+    diagnostic
 }
 
-pub fn generate_basic<'a>(code: &'a str, span: Span, info: &'a str) -> Diagnostic<'a> {
-    let lines: Vec<&str> = code.lines().collect();
-
-    let line: usize = span.line;
+pub fn generate_basic(source: &SourceFile, span: Span, info: &str) -> Diagnostic {
+    let line: usize = span.get_line().saturating_sub(1);
+    let code: &str = source.get_line_text(line).trim_start();
 
-    let code: &str = lines[line - 1].trim_start();
-    let mut signaler: String = String::with_capacity(100);
+    let mut snippet: String = String::with_capacity(code.len() * 2 + info.len());
 
-    for i in 0..=code.len() {
-        if i == code.len() {
-            signaler.push_str(&format!("\n\n{}{}\n", "HELP: ".bright_green().bold(), info));
-            break;
-        }
-
-        signaler.push('^');
-    }
+    snippet.push_str(code);
+    snippet.push('\n');
+    snippet.push_str(&"^".repeat(code.len().max(1)));
+    snippet.push_str(&format!(
+        "\n\n{}{}\n\n",
+        "HELP: ".bright_green().bold(),
+        info
+    ));
 
-    Diagnostic::new(code, signaler, span)
+    Diagnostic::new(source.get_path().to_path_buf(), snippet, line + 1, 0)
 }
 
-pub fn generate<'a>(
-    code: &'a str,
-    position: CodePosition,
+pub fn generate(
+    source: &SourceFile,
+    range: CodeRange,
     info: &str,
     notificator: Notificator,
-) -> Option<Diagnostic<'a>> {
-    let mut lines: Lines = code.lines();
-
-    let pivot_line: usize = position.get_line();
-    let code_line: &str = lines.nth(pivot_line.saturating_sub(1))?;
-
-    let line: usize = position.get_line();
-    let start: usize = position.get_start();
-    let end: usize = position.get_end();
-
-    let code_before_trim: usize = code_line.len();
-    let code: &str = code_line.trim_start();
-
-    let trim_diferrence: usize = code_before_trim - code.len();
+) -> Diagnostic {
+    let message: String = format!("{}{}", notificator, info);
+    let snippet: String = self::render_range(source, range, '^', &message);
+
+    Diagnostic::new(
+        source.get_path().to_path_buf(),
+        snippet,
+        range.get_start().get_line() + 1,
+        range.get_start().get_column(),
+    )
+}
 
-    let mut signaler: String = String::with_capacity(100);
+fn generate_label(
+    source_map: &SourceMap,
+    primary: &SourceFile,
+    span: Span,
+    message: &str,
+) -> String {
+    let Some(source) = source_map.get(span.get_file()) else {
+        return String::new();
+    };
+
+    let Some(range) = position::find_line_and_range(&source, span) else {
+        return String::new();
+    };
+
+    let mut label: String = String::with_capacity(100);
+
+    if source.get_id() != primary.get_id() {
+        label.push_str(&format!(
+            "{} {}:{}\n",
+            source
+                .get_path()
+                .to_string_lossy()
+                .bright_blue()
+                .underline(),
+            range.get_start().get_line() + 1,
+            range.get_start().get_column()
+        ));
+    }
 
-    let end_position: usize = end.saturating_sub(trim_diferrence).saturating_sub(1);
+    label.push_str(&self::render_range(
+        &source,
+        range,
+        '-',
+        &message.bright_blue().bold().to_string(),
+    ));
 
-    for pos in 0..=end_position {
-        if pos == end_position {
-            signaler.push('^');
-            signaler.push(' ');
-            signaler.push_str(&format!("{}{}", notificator, info));
-            signaler.push_str("\n\n");
+    label
+}
 
-            break;
+/// Renders every line touched by the range, underlining the covered columns.
+/// Long ranges are elided in the middle so a runaway comment or string does
+/// not flood the terminal.
+fn render_range(source: &SourceFile, range: CodeRange, marker: char, message: &str) -> String {
+    let first_line: usize = range.get_start().get_line();
+    let last_line: usize = range.get_end().get_line();
+
+    let lines: Vec<usize> = if last_line - first_line < MAXIMUM_SNIPPET_LINES {
+        (first_line..=last_line).collect()
+    } else {
+        (first_line..first_line + MAXIMUM_SNIPPET_LINES / 2)
+            .chain(last_line + 1 - MAXIMUM_SNIPPET_LINES / 2..=last_line)
+            .collect()
+    };
+
+    let indentation: usize = lines
+        .iter()
+        .map(|line| source.get_line_text(*line))
+        .filter(|text| !text.trim().is_empty())
+        .map(|text| text.len() - text.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut snippet: String = String::with_capacity(256);
+    let mut previous_line: usize = first_line;
+
+    for line in lines {
+        if line > previous_line + 1 {
+            snippet.push_str("...\n");
         }
 
-        signaler.push(' ');
+        previous_line = line;
+
+        let text: &str = source.get_line_text(line);
+        let code: &str = text.get(indentation..).unwrap_or_default();
+
+        let start_column: usize = if line == first_line {
+            range.get_start().get_column()
+        } else {
+            text.len() - text.trim_start().len()
+        };
+
+        let end_column: usize = if line == last_line && !range.is_multiline() {
+            range.get_end().get_column().max(start_column + 1)
+        } else if line == last_line {
+            range.get_end().get_column()
+        } else {
+            text.len()
+        };
+
+        let start_column: usize = self::display_width(text, start_column, indentation);
+        let end_column: usize = self::display_width(text, end_column, indentation);
+
+        snippet.push_str(code);
+        snippet.push('\n');
+
+        snippet.push_str(&" ".repeat(start_column));
+        snippet.push_str(
+            &marker
+                .to_string()
+                .repeat(end_column.saturating_sub(start_column).max(1)),
+        );
+
+        if line == last_line {
+            snippet.push(' ');
+            snippet.push_str(message);
+            snippet.push_str("\n\n");
+        } else {
+            snippet.push('\n');
+        }
     }
 
-    Some(Diagnostic::new(
-        code,
-        signaler,
-        Span::new(line, (start, end)),
-    ))
+    snippet
+}
+
+fn display_width(text: &str, column: usize, indentation: usize) -> usize {
+    let column: usize = column.min(text.len());
+
+    text.get(indentation.min(column)..column)
+        .map(|slice| slice.chars().count())
+        .unwrap_or(column.saturating_sub(indentation))
 }
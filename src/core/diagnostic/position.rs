@@ -1,15 +1,20 @@
-use crate::frontend::lexer::span::Span;
+use crate::{core::compiler::sourcemap::SourceFile, frontend::lexer::span::Span};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct CodePosition {
     line: usize,
-    start: usize,
-    end: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CodeRange {
+    start: CodePosition,
+    end: CodePosition,
 }
 
 impl CodePosition {
-    pub fn new(line: usize, start: usize, end: usize) -> Self {
-        Self { line, start, end }
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
     }
 }
 
@@ -18,39 +23,41 @@ impl CodePosition {
         self.line
     }
 
-    pub fn get_start(&self) -> usize {
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+}
+
+impl CodeRange {
+    pub fn get_start(&self) -> CodePosition {
         self.start
     }
 
-    pub fn get_end(&self) -> usize {
+    pub fn get_end(&self) -> CodePosition {
         self.end
     }
+
+    pub fn is_multiline(&self) -> bool {
+        self.start.line != self.end.line
+    }
 }
 
-pub fn find_line_and_range(code: &str, span: Span) -> Option<CodePosition> {
+/// Resolves the byte offsets of a span into zero-based lines and byte columns.
+pub fn find_line_and_range(source: &SourceFile, span: Span) -> Option<CodeRange> {
+    let code_len: usize = source.get_code().len();
+
     let start: usize = span.get_span_start();
     let end: usize = span.get_span_end();
 
-    let mut line_start: usize = 0;
-    let mut line_num: usize = 1;
-
-    for (i, c) in code.char_indices() {
-        if i >= start {
-            break;
-        }
-        if c == '\n' {
-            line_start = i + 1;
-            line_num += 1;
-        }
-    }
-
-    if start >= code.len() || end > code.len() || start > end {
+    if start > code_len || end > code_len || start > end {
         return None;
     }
 
-    Some(CodePosition::new(
-        line_num,
-        start.saturating_sub(line_start),
-        end.saturating_sub(line_start),
-    ))
+    let start_line: usize = source.get_line_index(start);
+    let end_line: usize = source.get_line_index(end.saturating_sub(1).max(start));
+
+    Some(CodeRange {
+        start: CodePosition::new(start_line, start - source.get_line_start(start_line)),
+        end: CodePosition::new(end_line, end - source.get_line_start(end_line)),
+    })
 }
@@ -6,22 +6,22 @@ use crate::core::{
     console::logging::{self, LoggingType},
     diagnostic::{
         Diagnostic,
+        codes::DiagnosticCode,
         errors::{FrontendError, FrontendErrorDisassembler, Issue, IssueDisassembler},
     },
     errors::position::CompilationPosition,
 };
 
 pub fn print(diagnostic: &Diagnostic, issue: Issue<'_>) {
-    let title: &str = issue.get_title();
-    let path: &Path = issue.get_path();
+    let code: DiagnosticCode = issue.get_code();
     let note: Option<&str> = issue.get_note();
     let logging_type: LoggingType = issue.get_logging_type();
 
-    let code: &str = diagnostic.get_code();
-    let signaler: &str = diagnostic.get_signaler();
+    let path: &Path = diagnostic.get_path();
+    let snippet: &str = diagnostic.get_snippet();
 
-    let line: usize = diagnostic.get_span().get_line();
-    let start: usize = diagnostic.get_span().get_span_start();
+    let line: usize = diagnostic.get_line();
+    let start: usize = diagnostic.get_column();
 
     logging::write(
         logging::OutputIn::Stderr,
@@ -40,13 +40,15 @@ pub fn print(diagnostic: &Diagnostic, issue: Issue<'_>) {
 
     logging::write(
         logging::OutputIn::Stderr,
-        &format!("\n{} {}\n", logging_type.as_styled(), title.to_uppercase()),
+        &format!(
+            "\n{}{} {}\n",
+            logging_type.as_styled(),
+            logging_type.text_with_color(&format!("[{}]", code)),
+            code.get_title().to_uppercase()
+        ),
     );
 
-    logging::write(
-        logging::OutputIn::Stderr,
-        &format!("\n{}\n{}", code, signaler),
-    );
+    logging::write(logging::OutputIn::Stderr, &format!("\n{}", snippet));
 
     if let Some(note) = note {
         logging::write(
@@ -60,14 +62,13 @@ pub fn print_compiler_frontend_bug(diagnostic: &Diagnostic, error: FrontendError
     let title: &str = error.get_title();
     let position: CompilationPosition = error.get_position();
     let compiler_line: u32 = error.get_line();
-    let path: &Path = error.get_path();
     let logging_type: LoggingType = error.get_logging_type();
 
-    let code: &str = diagnostic.get_code();
-    let signaler: &str = diagnostic.get_signaler();
+    let path: &Path = diagnostic.get_path();
+    let snippet: &str = diagnostic.get_snippet();
 
-    let line: usize = diagnostic.get_span().get_line();
-    let start: usize = diagnostic.get_span().get_span_start();
+    let line: usize = diagnostic.get_line();
+    let start: usize = diagnostic.get_column();
 
     logging::write(
         logging::OutputIn::Stderr,
@@ -97,10 +98,7 @@ pub fn print_compiler_frontend_bug(diagnostic: &Diagnostic, error: FrontendError
         ),
     );
 
-    logging::write(
-        logging::OutputIn::Stderr,
-        &format!("\n{}\n{}", code, signaler),
-    );
+    logging::write(logging::OutputIn::Stderr, &format!("\n{}", snippet));
 
     logging::write(
         logging::OutputIn::Stderr,
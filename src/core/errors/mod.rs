@@ -1,3 +1,2 @@
-pub mod lexer;
 pub mod position;
 pub mod standard;
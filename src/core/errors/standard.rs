@@ -1,11 +1,20 @@
-use crate::frontend::lexer::span::Span;
+use crate::{core::diagnostic::codes::DiagnosticCode, frontend::lexer::span::Span};
 
 use super::position::CompilationPosition;
 
+pub type SecondaryLabel = (Span, String);
+
 #[derive(Debug, Clone)]
 pub enum ThrushCompilerIssue {
-    Error(String, String, Option<String>, Span),
-    Warning(String, String, Span),
+    Error(DiagnosticCode, String, Option<String>, Span),
+    LabeledError(
+        DiagnosticCode,
+        String,
+        Option<String>,
+        Span,
+        Box<[SecondaryLabel]>,
+    ),
+    Warning(DiagnosticCode, String, Span),
     FrontEndBug(String, String, Span, CompilationPosition, u32),
 }
 
@@ -1 +1,2 @@
 pub mod rand;
+pub mod sha256;
@@ -0,0 +1,96 @@
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const H: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Hex encoded SHA-256 digest of the data.
+pub fn digest(data: &[u8]) -> String {
+    let mut state: [u32; 8] = H;
+
+    let mut chunks = data.chunks_exact(64);
+
+    chunks
+        .by_ref()
+        .for_each(|chunk| self::compress(&mut state, chunk));
+
+    let remainder: &[u8] = chunks.remainder();
+    let bit_length: u64 = (data.len() as u64).wrapping_mul(8);
+
+    let mut tail: Vec<u8> = Vec::with_capacity(128);
+
+    tail.extend_from_slice(remainder);
+    tail.push(0x80);
+
+    while tail.len() % 64 != 56 {
+        tail.push(0);
+    }
+
+    tail.extend_from_slice(&bit_length.to_be_bytes());
+
+    tail.chunks_exact(64)
+        .for_each(|chunk| self::compress(&mut state, chunk));
+
+    state.iter().map(|word| format!("{:08x}", word)).collect()
+}
+
+fn compress(state: &mut [u32; 8], chunk: &[u8]) {
+    let mut w: [u32; 64] = [0; 64];
+
+    chunk.chunks_exact(4).enumerate().for_each(|(idx, word)| {
+        w[idx] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+    });
+
+    for idx in 16..64 {
+        let s0: u32 =
+            w[idx - 15].rotate_right(7) ^ w[idx - 15].rotate_right(18) ^ (w[idx - 15] >> 3);
+        let s1: u32 =
+            w[idx - 2].rotate_right(17) ^ w[idx - 2].rotate_right(19) ^ (w[idx - 2] >> 10);
+
+        w[idx] = w[idx - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[idx - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+    for (k, w) in K.iter().zip(w.iter()) {
+        let s1: u32 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch: u32 = (e & f) ^ (!e & g);
+
+        let temp1: u32 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(*k)
+            .wrapping_add(*w);
+
+        let s0: u32 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj: u32 = (a & b) ^ (a & c) ^ (b & c);
+
+        let temp2: u32 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    [a, b, c, d, e, f, g, h]
+        .iter()
+        .enumerate()
+        .for_each(|(idx, value)| state[idx] = state[idx].wrapping_add(*value));
+}
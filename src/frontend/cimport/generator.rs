@@ -0,0 +1,163 @@
+use ahash::AHashSet as HashSet;
+
+use crate::frontend::{
+    lexer::keywords::KEYWORDS,
+    types::cimport::types::{CDeclaration, CDeclarations, CType},
+};
+
+/// Writes the imported declarations as Thrush source, so they go through the same
+/// parser and checks as hand-written bindings. Every declaration is '@allow(unused)',
+/// since a header usually exposes far more than a program uses.
+pub fn generate(declarations: &CDeclarations, header: &str) -> String {
+    let mut code: String = String::with_capacity(declarations.len() * 64);
+    let mut declared: HashSet<&str> = HashSet::with_capacity(declarations.len());
+
+    code.push_str(&format!("// Imported from '{}'.\n\n", header));
+
+    declarations.iter().for_each(|(declaration, _)| {
+        let name: &str = match declaration {
+            CDeclaration::Function { name, .. }
+            | CDeclaration::Struct { name, .. }
+            | CDeclaration::Enum { name, .. }
+            | CDeclaration::Constant { name, .. } => name,
+        };
+
+        if !declared.insert(name) {
+            return;
+        }
+
+        match declaration {
+            CDeclaration::Function {
+                name,
+                parameters,
+                return_type,
+                variadic,
+            } => code.push_str(&self::generate_function(
+                name,
+                parameters,
+                return_type,
+                *variadic,
+            )),
+
+            CDeclaration::Struct { name, fields } => {
+                code.push_str(&self::generate_struct(name, fields))
+            }
+
+            CDeclaration::Enum { name, values } => {
+                code.push_str(&self::generate_enum(name, values))
+            }
+
+            CDeclaration::Constant { name, value } => code.push_str(&format!(
+                "const {}: {} @public @allow(unused) = {};\n\n",
+                self::escape_name(name),
+                self::get_constant_type(*value),
+                value
+            )),
+        }
+    });
+
+    code
+}
+
+fn generate_function(
+    name: &str,
+    parameters: &[(Option<String>, CType)],
+    return_type: &CType,
+    variadic: bool,
+) -> String {
+    let parameters: Vec<String> = parameters
+        .iter()
+        .enumerate()
+        .map(|(idx, (name, kind))| {
+            let name: String = match name {
+                Some(name) => self::escape_name(name),
+                None => format!("arg{}", idx),
+            };
+
+            format!("{}: {}", name, self::generate_type(kind))
+        })
+        .collect();
+
+    let ignore: &str = if variadic { " @ignore" } else { "" };
+
+    format!(
+        "fn {}({}) {} @public{} @allow(unused) @extern(\"{}\");\n\n",
+        self::escape_name(name),
+        parameters.join(", "),
+        self::generate_type(return_type),
+        ignore,
+        name
+    )
+}
+
+fn generate_struct(name: &str, fields: &[(String, CType)]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(name, kind)| {
+            format!(
+                "    {}: {}",
+                self::escape_name(name),
+                self::generate_type(kind)
+            )
+        })
+        .collect();
+
+    format!(
+        "struct {} @public @allow(unused) {{\n{}\n}}\n\n",
+        self::escape_name(name),
+        fields.join(",\n")
+    )
+}
+
+fn generate_enum(name: &str, values: &[(String, i128)]) -> String {
+    let mut enumeration: String = format!("enum {} @allow(unused) {{\n", self::escape_name(name));
+
+    values.iter().for_each(|(name, value)| {
+        enumeration.push_str(&format!(
+            "    {}: {} = {};\n",
+            self::escape_name(name),
+            self::get_constant_type(*value),
+            value
+        ));
+    });
+
+    enumeration.push_str("};\n\n");
+
+    enumeration
+}
+
+fn generate_type(kind: &CType) -> String {
+    match kind {
+        CType::Void => String::from("void"),
+        CType::Bool => String::from("bool"),
+        CType::Char => String::from("char"),
+        CType::Integer(true, bits) => format!("s{}", bits),
+        CType::Integer(false, bits) => format!("u{}", bits),
+        CType::Float => String::from("f32"),
+        CType::Double => String::from("f64"),
+        CType::Enum => String::from("s32"),
+        CType::Struct(name) => self::escape_name(name),
+        CType::Array(inner, size) => format!("array[{}; {}]", self::generate_type(inner), size),
+        CType::Pointer | CType::Unsupported(..) => String::from("ptr"),
+    }
+}
+
+fn get_constant_type(value: i128) -> &'static str {
+    if i32::try_from(value).is_ok() {
+        "s32"
+    } else if i64::try_from(value).is_ok() {
+        "s64"
+    } else {
+        "u64"
+    }
+}
+
+/// C names that are Thrush keywords get a trailing '_'. Functions keep their
+/// original symbol through '@extern'.
+fn escape_name(name: &str) -> String {
+    if KEYWORDS.contains_key(name) {
+        return format!("{}_", name);
+    }
+
+    name.to_string()
+}
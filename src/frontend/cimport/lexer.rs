@@ -0,0 +1,229 @@
+use crate::{
+    core::compiler::sourcemap::FileId,
+    frontend::{
+        lexer::span::Span,
+        types::cimport::types::{CToken, CTokenKind, CTokens},
+    },
+};
+
+const PUNCTUATIONS: &[&str] = &[
+    "...", "<<", ">>", "->", "##", "&&", "||", "==", "!=", "<=", ">=", "(", ")", "{", "}", "[",
+    "]", ";", ",", "*", "=", "-", "+", "~", "!", "/", "%", "&", "|", "^", "<", ">", "?", ":", ".",
+    "#",
+];
+
+/// Splits C source into tokens. Preprocessor lines are kept whole as directives,
+/// so the parser decides which ones are meaningful.
+pub struct CLexer<'clexer> {
+    code: &'clexer [u8],
+    file: FileId,
+    base: usize,
+    current: usize,
+    line: usize,
+    at_line_start: bool,
+    tokens: CTokens,
+}
+
+impl<'clexer> CLexer<'clexer> {
+    pub fn lex(code: &'clexer str, file: FileId, base: usize, line: usize) -> CTokens {
+        Self {
+            code: code.as_bytes(),
+            file,
+            base,
+            current: 0,
+            line,
+            at_line_start: true,
+            tokens: Vec::with_capacity(code.len() / 4),
+        }
+        .start()
+    }
+
+    fn start(mut self) -> CTokens {
+        while self.current < self.code.len() {
+            let byte: u8 = self.code[self.current];
+
+            match byte {
+                b'\n' => {
+                    self.line += 1;
+                    self.current += 1;
+                    self.at_line_start = true;
+                }
+
+                byte if byte.is_ascii_whitespace() => self.current += 1,
+
+                b'/' if self.peek_at(1) == b'/' => self.skip_line_comment(),
+                b'/' if self.peek_at(1) == b'*' => self.skip_block_comment(),
+
+                b'#' if self.at_line_start => self.lex_directive(),
+
+                byte if byte.is_ascii_alphabetic() || byte == b'_' => self.lex_while(
+                    |byte| byte.is_ascii_alphanumeric() || byte == b'_',
+                    CTokenKind::Identifier,
+                ),
+
+                byte if byte.is_ascii_digit()
+                    || (byte == b'.' && self.peek_at(1).is_ascii_digit()) =>
+                {
+                    self.lex_number()
+                }
+
+                b'"' => self.lex_quoted(b'"'),
+                b'\'' => self.lex_quoted(b'\''),
+
+                _ => self.lex_punctuation(),
+            }
+        }
+
+        self.tokens
+    }
+
+    fn lex_directive(&mut self) {
+        let start: usize = self.current;
+        let line: usize = self.line;
+
+        while self.current < self.code.len() && self.code[self.current] != b'\n' {
+            if self.code[self.current] == b'\\' && self.peek_at(1) == b'\n' {
+                self.line += 1;
+                self.current += 2;
+                continue;
+            }
+
+            if self.code[self.current] == b'/' && self.peek_at(1) == b'*' {
+                self.skip_block_comment();
+                continue;
+            }
+
+            self.current += 1;
+        }
+
+        let text: String = String::from_utf8_lossy(&self.code[start + 1..self.current])
+            .replace("\\\n", " ")
+            .trim()
+            .to_string();
+
+        self.push(CTokenKind::Directive(text), start, line);
+    }
+
+    fn lex_number(&mut self) {
+        let start: usize = self.current;
+
+        while self.current < self.code.len() {
+            let byte: u8 = self.code[self.current];
+
+            let is_exponent_sign: bool = (byte == b'+' || byte == b'-')
+                && matches!(self.code[self.current - 1], b'e' | b'E' | b'p' | b'P');
+
+            if !(byte.is_ascii_alphanumeric() || byte == b'.' || byte == b'_' || is_exponent_sign) {
+                break;
+            }
+
+            self.current += 1;
+        }
+
+        let text: String = String::from_utf8_lossy(&self.code[start..self.current]).to_string();
+
+        self.push(CTokenKind::Number(text), start, self.line);
+    }
+
+    fn lex_quoted(&mut self, quote: u8) {
+        let start: usize = self.current;
+
+        self.current += 1;
+
+        while self.current < self.code.len() && self.code[self.current] != quote {
+            if self.code[self.current] == b'\\' {
+                self.current += 1;
+            }
+
+            if self.current < self.code.len() && self.code[self.current] == b'\n' {
+                break;
+            }
+
+            self.current += 1;
+        }
+
+        let end: usize = self.current.min(self.code.len());
+        let text: String = String::from_utf8_lossy(&self.code[start + 1..end]).to_string();
+
+        self.current = (self.current + 1).min(self.code.len());
+
+        let kind: CTokenKind = if quote == b'"' {
+            CTokenKind::Str(text)
+        } else {
+            CTokenKind::Char(text)
+        };
+
+        self.push(kind, start, self.line);
+    }
+
+    fn lex_punctuation(&mut self) {
+        let start: usize = self.current;
+        let rest: &[u8] = &self.code[self.current..];
+
+        if let Some(punctuation) = PUNCTUATIONS
+            .iter()
+            .find(|punctuation| rest.starts_with(punctuation.as_bytes()))
+        {
+            self.current += punctuation.len();
+            self.push(CTokenKind::Punctuation(punctuation), start, self.line);
+
+            return;
+        }
+
+        self.current += 1;
+    }
+
+    fn lex_while(&mut self, accept: fn(u8) -> bool, make: fn(String) -> CTokenKind) {
+        let start: usize = self.current;
+
+        while self.current < self.code.len() && accept(self.code[self.current]) {
+            self.current += 1;
+        }
+
+        let text: String = String::from_utf8_lossy(&self.code[start..self.current]).to_string();
+
+        self.push(make(text), start, self.line);
+    }
+
+    fn skip_line_comment(&mut self) {
+        while self.current < self.code.len() && self.code[self.current] != b'\n' {
+            self.current += 1;
+        }
+    }
+
+    fn skip_block_comment(&mut self) {
+        self.current += 2;
+
+        while self.current < self.code.len()
+            && !(self.code[self.current] == b'*' && self.peek_at(1) == b'/')
+        {
+            if self.code[self.current] == b'\n' {
+                self.line += 1;
+            }
+
+            self.current += 1;
+        }
+
+        self.current = (self.current + 2).min(self.code.len());
+    }
+
+    fn push(&mut self, kind: CTokenKind, start: usize, line: usize) {
+        self.at_line_start = false;
+
+        self.tokens.push(CToken {
+            kind,
+            span: Span::new(
+                self.file,
+                line,
+                (self.base + start, self.base + self.current),
+            ),
+        });
+    }
+
+    fn peek_at(&self, offset: usize) -> u8 {
+        self.code
+            .get(self.current + offset)
+            .copied()
+            .unwrap_or(b'\0')
+    }
+}
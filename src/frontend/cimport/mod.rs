@@ -0,0 +1,187 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use ahash::AHashSet as HashSet;
+
+use crate::{
+    core::{
+        compiler::{
+            lints::{LintLevel, LintsConfiguration},
+            sourcemap::{SourceFile, SourceMap},
+        },
+        console::logging::LoggingType,
+        diagnostic::{codes::DiagnosticCode, diagnostician::Diagnostician},
+        errors::standard::ThrushCompilerIssue,
+    },
+    frontend::{
+        cimport::{generator::generate, lexer::CLexer, parser::CHeaderParser},
+        lexer::{Lexer, span::Span, token::Token, tokentype::TokenType},
+        types::{
+            cimport::types::{CDeclarations, CTokens},
+            lexer::types::Tokens,
+        },
+    },
+};
+
+pub mod generator;
+pub mod lexer;
+pub mod parser;
+
+/// Replaces every '@cimport("header.h");' with the Thrush declarations of the header.
+/// Returns the expanded tokens, the number of errors found and the imported headers.
+pub fn expand(
+    tokens: Tokens,
+    source_map: &SourceMap,
+    include_dirs: &[PathBuf],
+    lints: &LintsConfiguration,
+) -> (Tokens, usize, Vec<PathBuf>) {
+    if !tokens.iter().any(|token| token.kind == TokenType::CImport) {
+        return (tokens, 0, Vec::new());
+    }
+
+    let mut diagnostician: Diagnostician = Diagnostician::new(source_map);
+    let mut imported: HashSet<PathBuf> = HashSet::with_capacity(10);
+
+    let mut expanded: Tokens = Vec::with_capacity(tokens.len());
+    let mut errors: usize = 0;
+
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        if token.kind != TokenType::CImport {
+            expanded.push(token);
+            continue;
+        }
+
+        let directive: Vec<Token> = (0..4)
+            .map_while(|_| tokens.next_if(|token| token.kind != TokenType::Eof))
+            .collect();
+
+        let header: Result<&Token, ThrushCompilerIssue> = match directive.as_slice() {
+            [lparen, header, rparen, semicolon]
+                if lparen.kind == TokenType::LParen
+                    && header.kind == TokenType::Str
+                    && rparen.kind == TokenType::RParen
+                    && semicolon.kind == TokenType::SemiColon =>
+            {
+                Ok(header)
+            }
+
+            _ => Err(ThrushCompilerIssue::Error(
+                DiagnosticCode::CImportError,
+                String::from("Expected '@cimport(\"header.h\");'."),
+                None,
+                token.span,
+            )),
+        };
+
+        let imported_tokens: Result<Tokens, ThrushCompilerIssue> = header.and_then(|header| {
+            self::import_header(
+                header,
+                source_map,
+                include_dirs,
+                lints,
+                &mut diagnostician,
+                &mut imported,
+                &mut errors,
+            )
+        });
+
+        match imported_tokens {
+            Ok(imported_tokens) => expanded.extend(imported_tokens),
+            Err(error) => {
+                errors += 1;
+                diagnostician.build_diagnostic(&error, LoggingType::Error);
+            }
+        }
+    }
+
+    (expanded, errors, imported.into_iter().collect())
+}
+
+fn import_header(
+    header: &Token,
+    source_map: &SourceMap,
+    include_dirs: &[PathBuf],
+    lints: &LintsConfiguration,
+    diagnostician: &mut Diagnostician,
+    imported: &mut HashSet<PathBuf>,
+    errors: &mut usize,
+) -> Result<Tokens, ThrushCompilerIssue> {
+    let span: Span = header.span;
+
+    let importer_dir: PathBuf = source_map
+        .get(span.get_file())
+        .and_then(|source| source.get_path().parent().map(Path::to_path_buf))
+        .unwrap_or_default();
+
+    let Some(header_path) = std::iter::once(&importer_dir)
+        .chain(include_dirs.iter())
+        .map(|dir| dir.join(&header.lexeme))
+        .find(|path| path.is_file())
+    else {
+        return Err(ThrushCompilerIssue::Error(
+            DiagnosticCode::CImportError,
+            format!("C header '{}' not found.", header.lexeme),
+            Some(String::from(
+                "Headers are searched next to the importing file and in every '-I' directory.",
+            )),
+            span,
+        ));
+    };
+
+    let header_path: PathBuf = header_path.canonicalize().unwrap_or(header_path);
+
+    if !imported.insert(header_path.clone()) {
+        return Ok(Vec::new());
+    }
+
+    let Ok(code) = std::fs::read_to_string(&header_path) else {
+        return Err(ThrushCompilerIssue::Error(
+            DiagnosticCode::CImportError,
+            format!("C header '{}' cannot be read.", header_path.display()),
+            None,
+            span,
+        ));
+    };
+
+    let header_source: Arc<SourceFile> = source_map.add(header_path.clone(), code);
+
+    let c_tokens: CTokens = CLexer::lex(header_source.get_code(), header_source.get_id(), 0, 1);
+    let (declarations, warnings): (CDeclarations, Vec<ThrushCompilerIssue>) =
+        CHeaderParser::parse(c_tokens);
+
+    warnings.iter().for_each(|warn| {
+        let level: LintLevel = match warn {
+            ThrushCompilerIssue::Warning(code, ..) => lints.get_level(*code),
+            _ => LintLevel::Warn,
+        };
+
+        match level {
+            LintLevel::Allow => (),
+            LintLevel::Warn => diagnostician.build_diagnostic(warn, LoggingType::Warning),
+            LintLevel::Deny => {
+                *errors += 1;
+                diagnostician.build_diagnostic(warn, LoggingType::Error);
+            }
+        }
+    });
+
+    let bindings: String = generate(&declarations, &header.lexeme);
+
+    let bindings_source: Arc<SourceFile> = source_map.add(
+        PathBuf::from(format!("{}.thrush", header_path.display())),
+        bindings,
+    );
+
+    let (mut bindings_tokens, lexer_errors): (Tokens, usize) =
+        Lexer::lex(&bindings_source, source_map);
+
+    *errors += lexer_errors;
+
+    bindings_tokens.pop();
+
+    Ok(bindings_tokens)
+}
@@ -0,0 +1,1068 @@
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+use crate::{
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
+    frontend::{
+        cimport::lexer::CLexer,
+        lexer::span::Span,
+        types::cimport::types::{CDeclaration, CDeclarations, CToken, CTokenKind, CTokens, CType},
+    },
+};
+
+const QUALIFIERS: &[&str] = &[
+    "const",
+    "volatile",
+    "restrict",
+    "__restrict",
+    "__restrict__",
+    "extern",
+    "static",
+    "inline",
+    "__inline",
+    "__inline__",
+    "__extension__",
+    "register",
+    "_Noreturn",
+    "__cdecl",
+    "__stdcall",
+];
+
+const ATTRIBUTES: &[&str] = &["__attribute__", "__declspec", "__asm__", "__asm"];
+
+type CParameters = (Vec<(Option<String>, CType)>, bool);
+
+/// A declaration parser for the subset of C found in library headers. Preprocessor
+/// conditionals aren't evaluated, so every branch of an '#if' is read.
+pub struct CHeaderParser {
+    tokens: CTokens,
+    current: usize,
+
+    ignored_macros: HashSet<String>,
+    typedefs: HashMap<String, CType>,
+    constants: HashMap<String, i128>,
+    structs: HashSet<String>,
+
+    pending_struct: Option<Vec<(String, CType)>>,
+    pending_enum: Option<Vec<(String, i128)>>,
+
+    declarations: CDeclarations,
+    warnings: Vec<ThrushCompilerIssue>,
+}
+
+impl CHeaderParser {
+    pub fn parse(tokens: CTokens) -> (CDeclarations, Vec<ThrushCompilerIssue>) {
+        let mut parser: CHeaderParser = Self {
+            tokens,
+            current: 0,
+            ignored_macros: HashSet::with_capacity(100),
+            typedefs: HashMap::with_capacity(100),
+            constants: HashMap::with_capacity(100),
+            structs: HashSet::with_capacity(100),
+            pending_struct: None,
+            pending_enum: None,
+            declarations: Vec::with_capacity(100),
+            warnings: Vec::with_capacity(10),
+        };
+
+        parser.start();
+
+        (parser.declarations, parser.warnings)
+    }
+
+    fn start(&mut self) {
+        while !self.is_eof() {
+            let start: usize = self.current;
+
+            let parsed: Result<(), String> = match self.peek_kind() {
+                CTokenKind::Directive(directive) => {
+                    let directive: String = directive.clone();
+                    let span: Span = self.peek_span();
+
+                    self.current += 1;
+                    self.parse_directive(&directive, span);
+
+                    Ok(())
+                }
+
+                CTokenKind::Punctuation(";" | "}") => {
+                    self.current += 1;
+                    Ok(())
+                }
+
+                CTokenKind::Identifier(identifier)
+                    if identifier == "extern"
+                        && matches!(self.peek_kind_at(1), Some(CTokenKind::Str(..))) =>
+                {
+                    self.current += 2;
+                    self.match_punctuation("{");
+
+                    Ok(())
+                }
+
+                CTokenKind::Identifier(identifier) if identifier == "typedef" => {
+                    self.current += 1;
+                    self.parse_typedef()
+                }
+
+                _ => self.parse_declaration(),
+            };
+
+            if let Err(reason) = parsed {
+                let span: Span = self.get_span_from(start);
+
+                self.pending_struct = None;
+                self.pending_enum = None;
+
+                self.skip_declaration(start);
+
+                self.warnings.push(ThrushCompilerIssue::Warning(
+                    DiagnosticCode::UnsupportedCDeclaration,
+                    format!("This declaration was not imported: {}.", reason),
+                    span,
+                ));
+            }
+        }
+    }
+
+    /* ######################################################################
+
+
+        C HEADER PARSER - DIRECTIVES
+
+
+    ########################################################################*/
+
+    fn parse_directive(&mut self, directive: &str, span: Span) {
+        let (command, rest) = directive
+            .split_once(|c: char| c.is_ascii_whitespace())
+            .unwrap_or((directive, ""));
+
+        if command == "undef" {
+            self.ignored_macros.remove(rest.trim());
+            return;
+        }
+
+        if command != "define" {
+            return;
+        }
+
+        let rest: &str = rest.trim_start();
+
+        let name_end: usize = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+
+        let (name, body) = rest.split_at(name_end);
+
+        if name.is_empty() || body.starts_with('(') {
+            return;
+        }
+
+        let body_tokens: CTokens = CLexer::lex(body, span.get_file(), 0, span.get_line());
+
+        match body_tokens.first().map(|token| &token.kind) {
+            None => {
+                self.ignored_macros.insert(name.to_string());
+            }
+
+            Some(CTokenKind::Identifier(identifier))
+                if ATTRIBUTES.contains(&identifier.as_str())
+                    || QUALIFIERS.contains(&identifier.as_str()) =>
+            {
+                self.ignored_macros.insert(name.to_string());
+            }
+
+            Some(_) => match self::evaluate(&body_tokens, &self.constants) {
+                Some(value) => {
+                    self.constants.insert(name.to_string(), value);
+
+                    self.declarations.push((
+                        CDeclaration::Constant {
+                            name: name.to_string(),
+                            value,
+                        },
+                        span,
+                    ));
+                }
+
+                None => self.warnings.push(ThrushCompilerIssue::Warning(
+                    DiagnosticCode::UnsupportedCDeclaration,
+                    format!(
+                        "Macro '{}' was not imported, only integer constant macros are supported.",
+                        name
+                    ),
+                    span,
+                )),
+            },
+        }
+    }
+
+    /* ######################################################################
+
+
+        C HEADER PARSER - DECLARATIONS
+
+
+    ########################################################################*/
+
+    fn parse_typedef(&mut self) -> Result<(), String> {
+        let start: usize = self.current - 1;
+        let base: CType = self.parse_specifiers()?;
+
+        loop {
+            let (name, mut kind, function) = self.parse_declarator(base.clone())?;
+
+            let Some(name) = name else {
+                return Err(String::from("a typedef without a name"));
+            };
+
+            if function.is_some() {
+                kind = CType::Unsupported(String::from("function type"));
+            }
+
+            if kind == CType::Struct(String::new()) {
+                if let Some(fields) = self.pending_struct.take() {
+                    self.declare_struct(name.clone(), fields, start)?;
+                }
+
+                kind = CType::Struct(name.clone());
+            }
+
+            if let Some(values) = self.pending_enum.take() {
+                self.declarations.push((
+                    CDeclaration::Enum {
+                        name: name.clone(),
+                        values,
+                    },
+                    self.get_span_from(start),
+                ));
+            }
+
+            self.typedefs.insert(name, kind);
+
+            if !self.match_punctuation(",") {
+                break;
+            }
+        }
+
+        self.expect_punctuation(";")
+    }
+
+    fn parse_declaration(&mut self) -> Result<(), String> {
+        let start: usize = self.current;
+        let base: CType = self.parse_specifiers()?;
+
+        if let Some(values) = self.pending_enum.take() {
+            values.into_iter().for_each(|(name, value)| {
+                self.declarations.push((
+                    CDeclaration::Constant { name, value },
+                    self.get_span_from(start),
+                ));
+            });
+        }
+
+        if self.pending_struct.is_some() {
+            return Err(String::from("anonymous structures are not supported"));
+        }
+
+        if self.match_punctuation(";") {
+            return Ok(());
+        }
+
+        loop {
+            let (name, return_type, function) = self.parse_declarator(base.clone())?;
+
+            let name: String = name.unwrap_or_default();
+
+            let Some((parameters, variadic)) = function else {
+                return Err(format!("global variable '{}' is not supported", name));
+            };
+
+            self.skip_attributes();
+
+            if self.check_punctuation("{") {
+                return Err(format!(
+                    "'{}' is defined in the header and has no symbol to link against",
+                    name
+                ));
+            }
+
+            let declaration: CDeclaration = CDeclaration::Function {
+                name,
+                parameters,
+                return_type,
+                variadic,
+            };
+
+            self.validate(&declaration)?;
+
+            self.declarations
+                .push((declaration, self.get_span_from(start)));
+
+            if !self.match_punctuation(",") {
+                break;
+            }
+        }
+
+        self.expect_punctuation(";")
+    }
+
+    fn declare_struct(
+        &mut self,
+        name: String,
+        fields: Vec<(String, CType)>,
+        start: usize,
+    ) -> Result<(), String> {
+        let declaration: CDeclaration = CDeclaration::Struct {
+            name: name.clone(),
+            fields,
+        };
+
+        self.validate(&declaration)?;
+
+        self.structs.insert(name);
+        self.declarations
+            .push((declaration, self.get_span_from(start)));
+
+        Ok(())
+    }
+
+    fn validate(&self, declaration: &CDeclaration) -> Result<(), String> {
+        let check = |kind: &CType| -> Result<(), String> {
+            match self.get_unsupported(kind) {
+                Some(reason) => Err(reason),
+                None => Ok(()),
+            }
+        };
+
+        match declaration {
+            CDeclaration::Function {
+                parameters,
+                return_type,
+                ..
+            } => {
+                check(return_type)?;
+
+                parameters.iter().try_for_each(|(_, kind)| check(kind))
+            }
+
+            CDeclaration::Struct { fields, .. } => {
+                fields.iter().try_for_each(|(_, kind)| check(kind))
+            }
+
+            _ => Ok(()),
+        }
+    }
+
+    fn get_unsupported(&self, kind: &CType) -> Option<String> {
+        match kind {
+            CType::Unsupported(what) => Some(format!("the C type '{}' is not supported", what)),
+            CType::Struct(name) if !self.structs.contains(name) => Some(format!(
+                "the structure '{}' is used by value but its fields are unknown",
+                name
+            )),
+            CType::Array(inner, _) => self.get_unsupported(inner),
+            _ => None,
+        }
+    }
+
+    /* ######################################################################
+
+
+        C HEADER PARSER - TYPES
+
+
+    ########################################################################*/
+
+    fn parse_specifiers(&mut self) -> Result<CType, String> {
+        let mut signed: Option<bool> = None;
+        let mut longs: usize = 0;
+        let mut short: bool = false;
+        let mut base: Option<String> = None;
+        let mut resolved: Option<CType> = None;
+
+        while let CTokenKind::Identifier(identifier) = self.peek_kind() {
+            let identifier: String = identifier.clone();
+
+            let is_empty: bool =
+                resolved.is_none() && base.is_none() && signed.is_none() && longs == 0 && !short;
+
+            match identifier.as_str() {
+                qualifier if QUALIFIERS.contains(&qualifier) => self.current += 1,
+                attribute if ATTRIBUTES.contains(&attribute) => self.skip_attributes(),
+                ignored if self.ignored_macros.contains(ignored) => self.current += 1,
+
+                "signed" => {
+                    signed = Some(true);
+                    self.current += 1;
+                }
+                "unsigned" => {
+                    signed = Some(false);
+                    self.current += 1;
+                }
+                "short" => {
+                    short = true;
+                    self.current += 1;
+                }
+                "long" => {
+                    longs += 1;
+                    self.current += 1;
+                }
+                "int" | "char" | "void" | "float" | "double" | "_Bool" | "bool" => {
+                    base = Some(identifier);
+                    self.current += 1;
+                }
+
+                "struct" | "union" | "enum" if is_empty => {
+                    resolved = Some(self.parse_tagged(&identifier)?);
+                }
+
+                name if is_empty => {
+                    resolved = Some(self.resolve_type_name(name)?);
+                    self.current += 1;
+                }
+
+                _ => break,
+            }
+        }
+
+        if let Some(resolved) = resolved {
+            return Ok(resolved);
+        }
+
+        let signedness: bool = signed.unwrap_or(true);
+
+        match base.as_deref() {
+            Some("void") => Ok(CType::Void),
+            Some("_Bool" | "bool") => Ok(CType::Bool),
+            Some("float") => Ok(CType::Float),
+            Some("double") if longs > 0 => Ok(CType::Unsupported(String::from("long double"))),
+            Some("double") => Ok(CType::Double),
+            Some("char") if signed.is_none() => Ok(CType::Char),
+            Some("char") => Ok(CType::Integer(signedness, 8)),
+            Some(_) => Ok(self::integer_type(signedness, short, longs)),
+            None if signed.is_some() || short || longs > 0 => {
+                Ok(self::integer_type(signedness, short, longs))
+            }
+            None => Err(String::from("expected a type")),
+        }
+    }
+
+    fn resolve_type_name(&self, name: &str) -> Result<CType, String> {
+        if let Some(kind) = self.typedefs.get(name) {
+            return Ok(kind.clone());
+        }
+
+        match name {
+            "int8_t" => Ok(CType::Integer(true, 8)),
+            "int16_t" => Ok(CType::Integer(true, 16)),
+            "int32_t" | "wchar_t" => Ok(CType::Integer(true, 32)),
+            "int64_t" | "ssize_t" | "intptr_t" | "ptrdiff_t" | "intmax_t" => {
+                Ok(CType::Integer(true, 64))
+            }
+            "uint8_t" => Ok(CType::Integer(false, 8)),
+            "uint16_t" => Ok(CType::Integer(false, 16)),
+            "uint32_t" => Ok(CType::Integer(false, 32)),
+            "uint64_t" | "size_t" | "uintptr_t" | "uintmax_t" => Ok(CType::Integer(false, 64)),
+            "va_list" | "__builtin_va_list" => Ok(CType::Unsupported(String::from("va_list"))),
+
+            unknown => Err(format!("unknown type '{}'", unknown)),
+        }
+    }
+
+    fn parse_tagged(&mut self, keyword: &str) -> Result<CType, String> {
+        self.current += 1;
+        self.skip_attributes();
+
+        let tag: Option<String> = match self.peek_kind() {
+            CTokenKind::Identifier(tag) => {
+                let tag: String = tag.clone();
+                self.current += 1;
+                Some(tag)
+            }
+            _ => None,
+        };
+
+        let has_body: bool = self.check_punctuation("{");
+
+        match keyword {
+            "union" if has_body => Err(String::from("unions are not supported")),
+            "union" => Ok(CType::Unsupported(format!(
+                "union {}",
+                tag.unwrap_or_default()
+            ))),
+
+            "enum" => {
+                if has_body {
+                    let values: Vec<(String, i128)> = self.parse_enum_body()?;
+
+                    match tag {
+                        Some(tag) => self.declarations.push((
+                            CDeclaration::Enum { name: tag, values },
+                            self.get_span_from(self.current - 1),
+                        )),
+                        None => self.pending_enum = Some(values),
+                    }
+                }
+
+                Ok(CType::Enum)
+            }
+
+            _ => {
+                if has_body {
+                    let fields: Vec<(String, CType)> = self.parse_struct_body()?;
+
+                    match tag {
+                        Some(ref tag) => {
+                            self.declare_struct(tag.clone(), fields, self.current - 1)?
+                        }
+                        None => self.pending_struct = Some(fields),
+                    }
+                }
+
+                Ok(CType::Struct(tag.unwrap_or_default()))
+            }
+        }
+    }
+
+    fn parse_struct_body(&mut self) -> Result<Vec<(String, CType)>, String> {
+        self.expect_punctuation("{")?;
+
+        let mut fields: Vec<(String, CType)> = Vec::with_capacity(10);
+
+        while !self.match_punctuation("}") {
+            if self.is_eof() {
+                return Err(String::from("unterminated structure"));
+            }
+
+            let base: CType = self.parse_specifiers()?;
+
+            if self.pending_struct.is_some() {
+                return Err(String::from(
+                    "nested anonymous structures are not supported",
+                ));
+            }
+
+            loop {
+                let (name, kind, function) = self.parse_declarator(base.clone())?;
+
+                if self.check_punctuation(":") {
+                    return Err(String::from("bit-fields are not supported"));
+                }
+
+                if function.is_some() {
+                    return Err(String::from("function fields are not supported"));
+                }
+
+                let Some(name) = name else {
+                    return Err(String::from("unnamed fields are not supported"));
+                };
+
+                fields.push((name, kind));
+
+                if !self.match_punctuation(",") {
+                    break;
+                }
+            }
+
+            self.expect_punctuation(";")?;
+        }
+
+        Ok(fields)
+    }
+
+    fn parse_enum_body(&mut self) -> Result<Vec<(String, i128)>, String> {
+        self.expect_punctuation("{")?;
+
+        let mut values: Vec<(String, i128)> = Vec::with_capacity(10);
+        let mut next_value: i128 = 0;
+
+        while !self.match_punctuation("}") {
+            let CTokenKind::Identifier(name) = self.peek_kind() else {
+                return Err(String::from("expected an enumerator"));
+            };
+
+            let name: String = name.clone();
+
+            self.current += 1;
+
+            if self.match_punctuation("=") {
+                let expression: CTokens = self.take_until(&[",", "}"]);
+
+                next_value = self::evaluate(&expression, &self.constants).ok_or_else(|| {
+                    format!("the value of enumerator '{}' is not a constant", name)
+                })?;
+            }
+
+            self.constants.insert(name.clone(), next_value);
+            values.push((name, next_value));
+
+            next_value += 1;
+
+            self.match_punctuation(",");
+        }
+
+        Ok(values)
+    }
+
+    fn parse_declarator(
+        &mut self,
+        base: CType,
+    ) -> Result<(Option<String>, CType, Option<CParameters>), String> {
+        let mut is_pointer: bool = false;
+
+        while self.match_punctuation("*") {
+            is_pointer = true;
+            self.skip_qualifiers();
+        }
+
+        if self.check_punctuation("(") && self.check_punctuation_at(1, "*") {
+            self.current += 1;
+
+            while self.match_punctuation("*") {
+                self.skip_qualifiers();
+            }
+
+            let name: Option<String> = self.match_identifier();
+
+            while self.check_punctuation("[") {
+                self.skip_balanced();
+            }
+
+            self.expect_punctuation(")")?;
+
+            if self.check_punctuation("(") {
+                self.skip_balanced();
+            }
+
+            return Ok((name, CType::Pointer, None));
+        }
+
+        let name: Option<String> = self.match_identifier();
+
+        self.skip_attributes();
+
+        let kind: CType = if is_pointer { CType::Pointer } else { base };
+
+        if self.check_punctuation("(") {
+            let parameters: CParameters = self.parse_parameters()?;
+            return Ok((name, kind, Some(parameters)));
+        }
+
+        let mut dimensions: Vec<u32> = Vec::with_capacity(2);
+
+        while self.match_punctuation("[") {
+            let expression: CTokens = self.take_until(&["]"]);
+
+            let size: u32 = self::evaluate(&expression, &self.constants)
+                .and_then(|size| u32::try_from(size).ok())
+                .ok_or_else(|| String::from("array sizes must be integer constants"))?;
+
+            self.expect_punctuation("]")?;
+
+            dimensions.push(size);
+        }
+
+        let kind: CType = dimensions
+            .into_iter()
+            .rev()
+            .fold(kind, |kind, size| CType::Array(Box::new(kind), size));
+
+        Ok((name, kind, None))
+    }
+
+    fn parse_parameters(&mut self) -> Result<CParameters, String> {
+        self.expect_punctuation("(")?;
+
+        let mut parameters: Vec<(Option<String>, CType)> = Vec::with_capacity(10);
+        let mut variadic: bool = false;
+
+        if self.check_identifier("void") && self.check_punctuation_at(1, ")") {
+            self.current += 1;
+        }
+
+        while !self.match_punctuation(")") {
+            if self.match_punctuation("...") {
+                variadic = true;
+                continue;
+            }
+
+            let base: CType = self.parse_specifiers()?;
+            let (name, kind, function) = self.parse_declarator(base)?;
+
+            let kind: CType = match kind {
+                CType::Array(..) => CType::Pointer,
+                _ if function.is_some() => CType::Pointer,
+                kind => kind,
+            };
+
+            parameters.push((name, kind));
+
+            if !self.match_punctuation(",") {
+                self.expect_punctuation(")")?;
+                break;
+            }
+        }
+
+        Ok((parameters, variadic))
+    }
+
+    /* ######################################################################
+
+
+        C HEADER PARSER - HELPERS
+
+
+    ########################################################################*/
+
+    fn skip_declaration(&mut self, start: usize) {
+        self.current = start;
+
+        let mut depth: usize = 0;
+        let mut is_function_body: bool = false;
+
+        while !self.is_eof() {
+            let kind: CTokenKind = self.peek_kind().clone();
+
+            self.current += 1;
+
+            match kind {
+                CTokenKind::Punctuation("{") => {
+                    if depth == 0 {
+                        is_function_body = self.current >= 2
+                            && self.tokens[self.current - 2].kind == CTokenKind::Punctuation(")");
+                    }
+
+                    depth += 1;
+                }
+
+                CTokenKind::Punctuation("(" | "[") => depth += 1,
+                CTokenKind::Punctuation(")" | "]") => depth = depth.saturating_sub(1),
+
+                CTokenKind::Punctuation("}") => {
+                    depth = depth.saturating_sub(1);
+
+                    if depth == 0 && is_function_body {
+                        return;
+                    }
+                }
+
+                CTokenKind::Punctuation(";") if depth == 0 => return,
+                CTokenKind::Directive(..) if depth == 0 && self.current - 1 > start => {
+                    self.current -= 1;
+                    return;
+                }
+
+                _ => (),
+            }
+        }
+    }
+
+    fn skip_attributes(&mut self) {
+        while let CTokenKind::Identifier(identifier) = self.peek_kind() {
+            if !ATTRIBUTES.contains(&identifier.as_str()) {
+                break;
+            }
+
+            self.current += 1;
+
+            if self.check_punctuation("(") {
+                self.skip_balanced();
+            }
+        }
+    }
+
+    fn skip_qualifiers(&mut self) {
+        while let CTokenKind::Identifier(identifier) = self.peek_kind() {
+            if !QUALIFIERS.contains(&identifier.as_str())
+                && !self.ignored_macros.contains(identifier)
+            {
+                break;
+            }
+
+            self.current += 1;
+        }
+    }
+
+    fn skip_balanced(&mut self) {
+        let mut depth: usize = 0;
+
+        while !self.is_eof() {
+            match self.peek_kind() {
+                CTokenKind::Punctuation("(" | "[" | "{") => depth += 1,
+                CTokenKind::Punctuation(")" | "]" | "}") => depth = depth.saturating_sub(1),
+                _ => (),
+            }
+
+            self.current += 1;
+
+            if depth == 0 {
+                return;
+            }
+        }
+    }
+
+    fn take_until(&mut self, delimiters: &[&str]) -> CTokens {
+        let start: usize = self.current;
+        let mut depth: usize = 0;
+
+        while !self.is_eof() {
+            match self.peek_kind() {
+                CTokenKind::Punctuation(punctuation)
+                    if depth == 0 && delimiters.contains(punctuation) =>
+                {
+                    break;
+                }
+                CTokenKind::Punctuation("(" | "[") => depth += 1,
+                CTokenKind::Punctuation(")" | "]") => depth = depth.saturating_sub(1),
+                _ => (),
+            }
+
+            self.current += 1;
+        }
+
+        self.tokens[start..self.current].to_vec()
+    }
+
+    fn match_identifier(&mut self) -> Option<String> {
+        match self.peek_kind() {
+            CTokenKind::Identifier(identifier)
+                if !QUALIFIERS.contains(&identifier.as_str())
+                    && !ATTRIBUTES.contains(&identifier.as_str()) =>
+            {
+                let identifier: String = identifier.clone();
+                self.current += 1;
+
+                Some(identifier)
+            }
+
+            _ => None,
+        }
+    }
+
+    fn match_punctuation(&mut self, punctuation: &str) -> bool {
+        if self.check_punctuation(punctuation) {
+            self.current += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn expect_punctuation(&mut self, punctuation: &str) -> Result<(), String> {
+        if self.match_punctuation(punctuation) {
+            return Ok(());
+        }
+
+        Err(format!("expected '{}'", punctuation))
+    }
+
+    fn check_punctuation(&self, punctuation: &str) -> bool {
+        self.check_punctuation_at(0, punctuation)
+    }
+
+    fn check_punctuation_at(&self, offset: usize, punctuation: &str) -> bool {
+        matches!(self.peek_kind_at(offset), Some(CTokenKind::Punctuation(found)) if *found == punctuation)
+    }
+
+    fn check_identifier(&self, identifier: &str) -> bool {
+        matches!(self.peek_kind(), CTokenKind::Identifier(found) if found == identifier)
+    }
+
+    fn get_span_from(&self, start: usize) -> Span {
+        let end: usize = self.current.saturating_sub(1).max(start);
+
+        match (self.tokens.get(start), self.tokens.get(end)) {
+            (Some(first), Some(last)) => first.span.merge(last.span),
+            (Some(first), None) => first.span,
+            _ => self
+                .tokens
+                .last()
+                .map(|token| token.span)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn peek_kind(&self) -> &CTokenKind {
+        self.peek_kind_at(0)
+            .unwrap_or(&CTokenKind::Punctuation(";"))
+    }
+
+    fn peek_kind_at(&self, offset: usize) -> Option<&CTokenKind> {
+        self.tokens
+            .get(self.current + offset)
+            .map(|token| &token.kind)
+    }
+
+    fn peek_span(&self) -> Span {
+        self.tokens
+            .get(self.current)
+            .map(|token| token.span)
+            .unwrap_or_default()
+    }
+
+    fn is_eof(&self) -> bool {
+        self.current >= self.tokens.len()
+    }
+}
+
+fn integer_type(signed: bool, short: bool, longs: usize) -> CType {
+    let bits: u32 = if short {
+        16
+    } else if longs > 0 {
+        64
+    } else {
+        32
+    };
+
+    CType::Integer(signed, bits)
+}
+
+/// Evaluates an integer constant expression, resolving names against the
+/// macros and enumerators seen so far.
+pub fn evaluate(tokens: &[CToken], constants: &HashMap<String, i128>) -> Option<i128> {
+    let mut evaluator: ConstantEvaluator = ConstantEvaluator {
+        tokens,
+        current: 0,
+        constants,
+    };
+
+    let value: i128 = evaluator.binary(0)?;
+
+    if evaluator.current != tokens.len() {
+        return None;
+    }
+
+    Some(value)
+}
+
+struct ConstantEvaluator<'evaluator> {
+    tokens: &'evaluator [CToken],
+    current: usize,
+    constants: &'evaluator HashMap<String, i128>,
+}
+
+impl ConstantEvaluator<'_> {
+    fn binary(&mut self, min_precedence: u8) -> Option<i128> {
+        let mut left: i128 = self.unary()?;
+
+        while let Some(CTokenKind::Punctuation(operator)) =
+            self.tokens.get(self.current).map(|token| &token.kind)
+        {
+            let precedence: u8 = match *operator {
+                "|" => 1,
+                "^" => 2,
+                "&" => 3,
+                "<<" | ">>" => 4,
+                "+" | "-" => 5,
+                "*" | "/" | "%" => 6,
+                _ => break,
+            };
+
+            if precedence < min_precedence {
+                break;
+            }
+
+            let operator: &str = operator;
+
+            self.current += 1;
+
+            let right: i128 = self.binary(precedence + 1)?;
+
+            left = match operator {
+                "|" => left | right,
+                "^" => left ^ right,
+                "&" => left & right,
+                "<<" => left.checked_shl(u32::try_from(right).ok()?)?,
+                ">>" => left.checked_shr(u32::try_from(right).ok()?)?,
+                "+" => left.checked_add(right)?,
+                "-" => left.checked_sub(right)?,
+                "*" => left.checked_mul(right)?,
+                "/" => left.checked_div(right)?,
+                _ => left.checked_rem(right)?,
+            };
+        }
+
+        Some(left)
+    }
+
+    fn unary(&mut self) -> Option<i128> {
+        let token: &CToken = self.tokens.get(self.current)?;
+
+        self.current += 1;
+
+        match &token.kind {
+            CTokenKind::Punctuation("-") => self.unary()?.checked_neg(),
+            CTokenKind::Punctuation("+") => self.unary(),
+            CTokenKind::Punctuation("~") => Some(!self.unary()?),
+            CTokenKind::Punctuation("!") => Some((self.unary()? == 0) as i128),
+
+            CTokenKind::Punctuation("(") => {
+                if self.is_cast() {
+                    while !matches!(
+                        self.tokens.get(self.current).map(|token| &token.kind),
+                        Some(CTokenKind::Punctuation(")")) | None
+                    ) {
+                        self.current += 1;
+                    }
+
+                    self.current += 1;
+
+                    return self.unary();
+                }
+
+                let value: i128 = self.binary(0)?;
+
+                match self.tokens.get(self.current).map(|token| &token.kind) {
+                    Some(CTokenKind::Punctuation(")")) => {
+                        self.current += 1;
+                        Some(value)
+                    }
+                    _ => None,
+                }
+            }
+
+            CTokenKind::Number(number) => self::parse_integer(number),
+            CTokenKind::Char(char) if char.len() == 1 => Some(char.as_bytes()[0] as i128),
+            CTokenKind::Identifier(name) => self.constants.get(name).copied(),
+
+            _ => None,
+        }
+    }
+
+    fn is_cast(&self) -> bool {
+        matches!(
+            self.tokens.get(self.current).map(|token| &token.kind),
+            Some(CTokenKind::Identifier(name)) if matches!(
+                name.as_str(),
+                "int" | "unsigned" | "signed" | "long" | "short" | "char"
+                    | "int8_t" | "int16_t" | "int32_t" | "int64_t"
+                    | "uint8_t" | "uint16_t" | "uint32_t" | "uint64_t" | "size_t"
+            )
+        )
+    }
+}
+
+fn parse_integer(number: &str) -> Option<i128> {
+    let number: &str = number.trim_end_matches(['u', 'U', 'l', 'L']);
+
+    if let Some(hex) = number
+        .strip_prefix("0x")
+        .or_else(|| number.strip_prefix("0X"))
+    {
+        return i128::from_str_radix(hex, 16).ok();
+    }
+
+    if let Some(binary) = number
+        .strip_prefix("0b")
+        .or_else(|| number.strip_prefix("0B"))
+    {
+        return i128::from_str_radix(binary, 2).ok();
+    }
+
+    if number.len() > 1 && number.starts_with('0') {
+        return i128::from_str_radix(&number[1..], 8).ok();
+    }
+
+    number.parse::<i128>().ok()
+}
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::lexer::{Lexer, span::Span, token::Token, tokentype::TokenType},
 };
 
@@ -10,11 +10,11 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
 
     lexer.end_span();
 
-    let span: Span = Span::new(lexer.line, lexer.span);
+    let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
     if lexer.peek() != '\'' {
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "Unclosed char. Did you forget to close the char with a '\''?.".into(),
             None,
             span,
@@ -27,7 +27,7 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
 
     if lexeme.len() > 1 {
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "A character can only contain one byte.".into(),
             None,
             span,
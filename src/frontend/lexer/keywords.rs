@@ -48,8 +48,11 @@ lazy_static! {
         keywords.insert("memmove", TokenType::MemMove);
         keywords.insert("memcpy", TokenType::MemCpy);
         keywords.insert("alignof", TokenType::AlignOf);
+        keywords.insert("assert", TokenType::Assert);
+        keywords.insert("assert_eq", TokenType::AssertEq);
 
         keywords.insert("@import", TokenType::Import);
+        keywords.insert("@cimport", TokenType::CImport);
 
         keywords.insert("@asmalingstack", TokenType::AsmAlignStack);
         keywords.insert("@asmsyntax", TokenType::AsmSyntax);
@@ -71,6 +74,8 @@ lazy_static! {
         keywords.insert("@strongstack", TokenType::StrongStack);
         keywords.insert("@precisefp", TokenType::PreciseFloats);
         keywords.insert("@convention", TokenType::Convention);
+        keywords.insert("@allow", TokenType::Allow);
+        keywords.insert("@test", TokenType::Test);
         keywords.insert("new", TokenType::New);
 
         keywords.insert("s8", TokenType::S8);
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::lexer::{
         Lexer, character, identifier, number, span::Span, string, tokentype::TokenType,
     },
@@ -19,13 +19,18 @@ pub fn analyze(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
         '.' => lexer.make(TokenType::Dot),
         '%' => lexer.make(TokenType::Arith),
         '*' => lexer.make(TokenType::Star),
-        '/' if lexer.char_match('/') => loop {
-            if lexer.peek() == '\n' || lexer.end() {
-                break;
+        '/' if lexer.char_match('/') => {
+            // '///' documents the declaration below it; '////' is a plain comment.
+            let is_doc_comment: bool = lexer.peek() == '/' && lexer.peek_next() != '/';
+
+            while lexer.peek() != '\n' && !lexer.end() {
+                lexer.advance();
             }
 
-            lexer.advance();
-        },
+            if is_doc_comment {
+                lexer.make(TokenType::DocComment);
+            }
+        }
         '/' if lexer.char_match('*') => loop {
             if lexer.char_match('*') {
                 continue;
@@ -34,10 +39,10 @@ pub fn analyze(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
             } else if lexer.end() {
                 lexer.end_span();
 
-                let span: Span = Span::new(lexer.line, lexer.span);
+                let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
                 return Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Expected '*/'.".into(),
                     None,
                     span,
@@ -81,10 +86,10 @@ pub fn analyze(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
         _ => {
             lexer.end_span();
 
-            let span: Span = Span::new(lexer.line, lexer.span);
+            let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
             return Err(ThrushCompilerIssue::Error(
-                "Unknown character".into(),
+                DiagnosticCode::UnknownCharacter,
                 "This character isn't recognized.".into(),
                 None,
                 span,
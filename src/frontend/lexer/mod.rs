@@ -1,4 +1,4 @@
-use std::{mem, process};
+use std::mem;
 
 use span::Span;
 use token::Token;
@@ -6,10 +6,10 @@ use unicode_categories::UnicodeCategories;
 
 use crate::{
     core::{
-        compiler::options::CompilerFile,
+        compiler::sourcemap::{FileId, SourceFile, SourceMap},
         console::logging::LoggingType,
         diagnostic::diagnostician::Diagnostician,
-        errors::{lexer::ThrushLexerPanic, standard::ThrushCompilerIssue},
+        errors::standard::ThrushCompilerIssue,
     },
     frontend::{lexer::tokentype::TokenType, types::lexer::types::Tokens},
 };
@@ -26,13 +26,11 @@ mod lex;
 mod number;
 mod string;
 
-const MAXIMUM_TOKENS_CAPACITY: usize = 1_000_000;
-const MAXIMUM_BYTES_TO_LEX: usize = 1_000_000;
-
-pub struct Lexer {
+pub struct Lexer<'lexer> {
     tokens: Vec<Token>,
     errors: Vec<ThrushCompilerIssue>,
-    code: Vec<char>,
+    code: &'lexer str,
+    file: FileId,
     start: usize,
     current: usize,
     line: usize,
@@ -40,66 +38,52 @@ pub struct Lexer {
     diagnostician: Diagnostician,
 }
 
-impl Lexer {
-    pub fn lex(raw_code: &str, file: &CompilerFile) -> Result<Tokens, ThrushLexerPanic> {
-        let code: Vec<char> = raw_code.chars().collect();
-
+impl<'lexer> Lexer<'lexer> {
+    pub fn lex(source: &'lexer SourceFile, source_map: &SourceMap) -> (Tokens, usize) {
         Self {
-            tokens: Vec::with_capacity(MAXIMUM_TOKENS_CAPACITY),
-            errors: Vec::with_capacity(100),
-            code,
+            tokens: Vec::new(),
+            errors: Vec::new(),
+            code: source.get_code(),
+            file: source.get_id(),
             start: 0,
             current: 0,
             line: 1,
             span: (0, 0),
-            diagnostician: Diagnostician::new(file),
+            diagnostician: Diagnostician::new(source_map),
         }
         .start()
     }
 
-    fn start(&mut self) -> Result<Tokens, ThrushLexerPanic> {
-        if self.code.len() > MAXIMUM_BYTES_TO_LEX {
-            return Err(ThrushLexerPanic::TooBigFile(
-                self.diagnostician.get_file_path(),
-            ));
-        }
-
+    fn start(&mut self) -> (Tokens, usize) {
         while !self.end() {
-            if self.tokens.len() >= MAXIMUM_TOKENS_CAPACITY {
-                return Err(ThrushLexerPanic::TooMuchTokens);
-            }
-
             self.start = self.current;
             self.start_span();
 
             if let Err(error) = lex::analyze(self) {
                 self.add_error(error);
+                self.make(TokenType::Error);
             }
         }
 
-        if !self.errors.is_empty() {
-            self.errors.iter().for_each(|error| {
-                self.diagnostician
-                    .build_diagnostic(error, LoggingType::Error);
-            });
-
-            process::exit(1);
-        };
+        self.errors.iter().for_each(|error| {
+            self.diagnostician
+                .build_diagnostic(error, LoggingType::Error);
+        });
 
         self.tokens.push(Token {
             lexeme: String::new(),
             ascii_lexeme: String::new(),
             kind: TokenType::Eof,
-            span: Span::new(self.line, self.span),
+            span: Span::new(self.file, self.line, self.span),
         });
 
-        Ok(mem::take(&mut self.tokens))
+        (mem::take(&mut self.tokens), self.errors.len())
     }
 
     pub fn make(&mut self, kind: TokenType) {
         self.end_span();
 
-        let span: Span = Span::new(self.line, self.span);
+        let span: Span = Span::new(self.file, self.line, self.span);
 
         let lexeme: String = self.lexeme();
 
@@ -118,8 +102,8 @@ impl Lexer {
     }
 
     pub fn char_match(&mut self, char: char) -> bool {
-        if !self.end() && self.code[self.current] == char {
-            self.current += 1;
+        if !self.end() && self.peek() == char {
+            self.current += char.len_utf8();
             return true;
         }
 
@@ -127,20 +111,20 @@ impl Lexer {
     }
 
     pub fn advance(&mut self) -> char {
-        let byte: char = self.code[self.current];
-        self.current += 1;
+        let char: char = self.peek();
+        self.current += char.len_utf8();
 
-        byte
+        char
     }
 
     #[inline]
     pub fn lexeme(&self) -> String {
-        String::from_iter(&self.code[self.start..self.current])
+        self.code[self.start..self.current].to_string()
     }
 
     #[inline]
     pub fn shrink_lexeme(&self) -> String {
-        String::from_iter(&self.code[self.start + 1..self.current - 1])
+        self.code[self.start + 1..self.current - 1].to_string()
     }
 
     #[inline]
@@ -155,26 +139,24 @@ impl Lexer {
 
     #[inline]
     pub fn peek_next(&self) -> char {
-        if self.current + 1 >= self.code.len() {
-            return '\0';
-        }
+        let mut chars: std::str::Chars = self.code[self.current..].chars();
 
-        self.code[self.current + 1]
+        chars.next();
+        chars.next().unwrap_or('\0')
     }
 
     #[must_use]
     pub fn previous(&self) -> char {
-        self.code[self.current - 1]
+        self.code[..self.current]
+            .chars()
+            .next_back()
+            .unwrap_or('\0')
     }
 
     #[must_use]
     #[inline]
     pub fn peek(&self) -> char {
-        if self.end() {
-            return '\0';
-        }
-
-        self.code[self.current]
+        self.code[self.current..].chars().next().unwrap_or('\0')
     }
 
     #[must_use]
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::lexer::{Lexer, span::Span},
 };
 
@@ -7,11 +7,11 @@ use crate::{
 pub fn check_float_format(lexer: &Lexer, lexeme: &str) -> Result<(), ThrushCompilerIssue> {
     let dot_count: usize = lexeme.bytes().filter(|&b| b == b'.').count();
 
-    let span: Span = Span::new(lexer.line, lexer.span);
+    let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
     if dot_count > 1 {
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "Only one decimal marker was expected.".into(),
             None,
             span,
@@ -27,7 +27,7 @@ pub fn check_float_format(lexer: &Lexer, lexeme: &str) -> Result<(), ThrushCompi
     }
 
     Err(ThrushCompilerIssue::Error(
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Float out of bounds.".into(),
         None,
         span,
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::lexer::{Lexer, span::Span},
 };
 
@@ -19,7 +19,7 @@ const U32_MAX: usize = 4294967295;
 
 #[inline]
 pub fn check_integer_format(lexer: &Lexer, lexeme: &str) -> Result<(), ThrushCompilerIssue> {
-    let span: Span = Span::new(lexer.line, lexer.span);
+    let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
     if lexeme.starts_with("0x") {
         return self::check_integer_hex_format(lexeme, span);
@@ -39,7 +39,7 @@ pub fn check_integer_format(lexer: &Lexer, lexeme: &str) -> Result<(), ThrushCom
                 Ok(())
             } else {
                 Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Integer out of bounds.".into(),
                     None,
                     span,
@@ -57,7 +57,7 @@ pub fn check_integer_format(lexer: &Lexer, lexeme: &str) -> Result<(), ThrushCom
                     Ok(())
                 } else {
                     Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Integer out of bounds.".into(),
                         None,
                         span,
@@ -66,7 +66,7 @@ pub fn check_integer_format(lexer: &Lexer, lexeme: &str) -> Result<(), ThrushCom
             }
 
             Err(_) => Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Integer out of bounds.".into(),
                 None,
                 span,
@@ -91,7 +91,7 @@ fn check_integer_binary_format(lexeme: &str, span: Span) -> Result<(), ThrushCom
                 Ok(())
             } else {
                 Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Integer out of bounds signed binary format.".into(),
                     None,
                     span,
@@ -109,7 +109,7 @@ fn check_integer_binary_format(lexeme: &str, span: Span) -> Result<(), ThrushCom
                     Ok(())
                 } else {
                     Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Integer out of bounds unsigned binary format.".into(),
                         None,
                         span,
@@ -118,7 +118,7 @@ fn check_integer_binary_format(lexeme: &str, span: Span) -> Result<(), ThrushCom
             }
 
             Err(_) => Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Integer invalid binary format.".into(),
                 None,
                 span,
@@ -143,7 +143,7 @@ fn check_integer_hex_format(lexeme: &str, span: Span) -> Result<(), ThrushCompil
                 Ok(())
             } else {
                 Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Integer out of bounds signed hexadecimal format.".into(),
                     None,
                     span,
@@ -161,7 +161,7 @@ fn check_integer_hex_format(lexeme: &str, span: Span) -> Result<(), ThrushCompil
                     Ok(())
                 } else {
                     Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Integer out of bounds unsigned hexadecimal format.".into(),
                         None,
                         span,
@@ -170,7 +170,7 @@ fn check_integer_hex_format(lexeme: &str, span: Span) -> Result<(), ThrushCompil
             }
 
             Err(_) => Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Integer invalid numeric hexadecimal format.".into(),
                 None,
                 span,
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::lexer::{Lexer, span::Span, token::Token, tokentype::TokenType},
 };
 
@@ -15,10 +15,10 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
             lexer.end_span();
 
             return Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Hexadecimal identifier '0x' cannot be repeated."),
                 None,
-                Span::new(lexer.line, lexer.span),
+                Span::new(lexer.file, lexer.line, lexer.span),
             ));
         }
 
@@ -26,10 +26,10 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
             lexer.end_span();
 
             return Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Binary identifier '0b' cannot be repeated."),
                 None,
-                Span::new(lexer.line, lexer.span),
+                Span::new(lexer.file, lexer.line, lexer.span),
             ));
         }
 
@@ -56,7 +56,7 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
 
     lexer.end_span();
 
-    let span: Span = Span::new(lexer.line, lexer.span);
+    let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
     let lexeme: String = lexer.lexeme();
 
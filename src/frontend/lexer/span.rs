@@ -1,7 +1,10 @@
 use std::fmt::{self, Display};
 
+use crate::core::compiler::sourcemap::FileId;
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Span {
+    pub file: FileId,
     pub line: usize,
     pub span: (usize, usize),
 }
@@ -13,8 +16,26 @@ impl Display for Span {
 }
 
 impl Span {
-    pub fn new(line: usize, span: (usize, usize)) -> Self {
-        Self { line, span }
+    pub fn new(file: FileId, line: usize, span: (usize, usize)) -> Self {
+        Self { file, line, span }
+    }
+
+    /// Builds a span covering from the start of `self` to the end of `other`.
+    #[must_use]
+    pub fn merge(&self, other: Span) -> Span {
+        if self.file != other.file {
+            return *self;
+        }
+
+        Span::new(
+            self.file,
+            self.line,
+            (self.span.0.min(other.span.0), self.span.1.max(other.span.1)),
+        )
+    }
+
+    pub fn get_file(&self) -> FileId {
+        self.file
     }
 
     pub fn get_line(&self) -> usize {
@@ -1,10 +1,11 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::lexer::{Lexer, span::Span, token::Token, tokentype::TokenType},
 };
 
 pub fn lex(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
     let mut found_end_quote: bool = false;
+    let mut found_invalid_escape: bool = false;
 
     while !lexer.end() {
         if lexer.peek() == '"' {
@@ -14,13 +15,20 @@ pub fn lex(lexer: &mut Lexer) -> Result<(), ThrushCompilerIssue> {
             break;
         }
 
-        self::advance_string_char(lexer)?;
+        if let Err(error) = self::advance_string_char(lexer) {
+            lexer.add_error(error);
+            found_invalid_escape = true;
+        }
     }
 
-    let string_span: Span = Span::new(lexer.line, lexer.span);
-    let raw_lexeme: String = lexer.shrink_lexeme();
+    let string_span: Span = Span::new(lexer.file, lexer.line, lexer.span);
+
+    if found_end_quote && found_invalid_escape {
+        lexer.make(TokenType::Error);
+        return Ok(());
+    }
 
-    self::validate_and_finalize_string(lexer, found_end_quote, string_span, raw_lexeme)?;
+    self::validate_and_finalize_string(lexer, found_end_quote, string_span)?;
 
     Ok(())
 }
@@ -31,10 +39,10 @@ fn handle_escape_sequence(lexer: &mut Lexer) -> Result<char, ThrushCompilerIssue
     if lexer.end() {
         lexer.end_span();
 
-        let span: Span = Span::new(lexer.line, lexer.span);
+        let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "Unexpected EOF after escape character.".into(),
             None,
             span,
@@ -55,10 +63,10 @@ fn handle_escape_sequence(lexer: &mut Lexer) -> Result<char, ThrushCompilerIssue
         _ => {
             lexer.end_span();
 
-            let span: Span = Span::new(lexer.line, lexer.span);
+            let span: Span = Span::new(lexer.file, lexer.line, lexer.span);
 
             Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Invalid escape sequence. Valid escapes are '\\n', '\\t', '\\r', '\\0', '\\\\', '\\'', and '\\\"'.".into(),
                 None,
                 span,
@@ -81,17 +89,18 @@ fn validate_and_finalize_string(
     lexer: &mut Lexer,
     found_end_quote: bool,
     span: Span,
-    lexeme: String,
 ) -> Result<(), ThrushCompilerIssue> {
     if !found_end_quote {
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "Unclosed literal string. Did you forget to close it with a '\"'?".into(),
             None,
             span,
         ));
     }
 
+    let lexeme: String = lexer.shrink_lexeme();
+
     lexer.tokens.push(Token {
         kind: TokenType::Str,
         ascii_lexeme: String::default(),
@@ -1,6 +1,6 @@
 use crate::{
     backend::llvm::compiler::attributes::LLVMAttribute,
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{lexer::span::Span, typesystem::types::Type},
 };
 
@@ -64,6 +64,8 @@ pub enum TokenType {
     AsmSyntax,
     AsmThrow,
     AsmSideEffects,
+    Allow,
+    Test,
 
     // --- Keywords ---
     Alloc,
@@ -82,6 +84,7 @@ pub enum TokenType {
     New,
     Fixed,
     Import,
+    CImport,
     SizeOf,
     Mut,
     Type,
@@ -111,6 +114,8 @@ pub enum TokenType {
     MemCpy,
     MemMove,
     MemSet,
+    Assert,
+    AssertEq,
 
     // --- Types ---
     S8,
@@ -134,6 +139,10 @@ pub enum TokenType {
     Addr,
     Array,
 
+    // --- Documentation ---
+    DocComment, // ' /// '
+
+    Error,
     Eof,
 }
 
@@ -244,6 +253,16 @@ impl TokenType {
     pub fn is_identifier(&self) -> bool {
         matches!(self, TokenType::Identifier)
     }
+
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        matches!(self, TokenType::Error)
+    }
+
+    #[must_use]
+    pub fn is_doc_comment(&self) -> bool {
+        matches!(self, TokenType::DocComment)
+    }
 }
 
 impl TokenType {
@@ -273,7 +292,7 @@ impl TokenType {
             TokenType::Void => Ok(Type::Void),
 
             any => Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 format!("{} isn't a valid type.", any),
                 None,
                 span,
@@ -301,6 +320,7 @@ impl TokenType {
             TokenType::AsmThrow => Some(LLVMAttribute::AsmThrow(span)),
             TokenType::AsmSideEffects => Some(LLVMAttribute::AsmSideEffects(span)),
             TokenType::AsmAlignStack => Some(LLVMAttribute::AsmAlignStack(span)),
+            TokenType::Test => Some(LLVMAttribute::Test(span)),
             _ => None,
         }
     }
@@ -324,6 +344,7 @@ impl TokenType {
                 | TokenType::AsmThrow
                 | TokenType::AsmSideEffects
                 | TokenType::AsmAlignStack
+                | TokenType::Test
         )
     }
 }
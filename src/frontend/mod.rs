@@ -1,3 +1,4 @@
+pub mod cimport;
 pub mod lexer;
 pub mod parser;
 pub mod semantic;
@@ -1,7 +1,7 @@
 use {
     crate::{
         backend::llvm::compiler::{attributes::LLVMAttribute, conventions::CallConvention},
-        core::errors::standard::ThrushCompilerIssue,
+        core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
         frontend::{
             lexer::{span::Span, token::Token, tokentype::TokenType},
             parser::ParserContext,
@@ -61,6 +61,14 @@ pub fn build_attributes<'parser>(
                 parser_ctx.only_advance()?;
             }
 
+            TokenType::Allow => {
+                self::build_allow_attribute(parser_ctx)?
+                    .into_iter()
+                    .for_each(|(lint, lint_span)| {
+                        attributes.push(LLVMAttribute::Allow(lint, lint_span));
+                    });
+            }
+
             TokenType::AsmSyntax => attributes.push(LLVMAttribute::AsmSyntax(
                 self::build_assembler_syntax_attribute(parser_ctx)?,
                 span,
@@ -87,13 +95,13 @@ fn build_external_attribute<'parser>(
 
     parser_ctx.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
     let name: &Token = parser_ctx.consume(
         TokenType::Str,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected a string literal for @extern(\"FFI NAME\")."),
     )?;
 
@@ -101,13 +109,55 @@ fn build_external_attribute<'parser>(
 
     parser_ctx.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
     Ok(ffi_name)
 }
 
+fn build_allow_attribute<'parser>(
+    parser_ctx: &mut ParserContext<'parser>,
+) -> Result<Vec<(&'parser str, Span)>, ThrushCompilerIssue> {
+    parser_ctx.only_advance()?;
+
+    parser_ctx.consume(
+        TokenType::LParen,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected '('."),
+    )?;
+
+    let mut lints: Vec<(&str, Span)> = Vec::with_capacity(5);
+
+    loop {
+        let lint_tk: &Token = parser_ctx.consume(
+            TokenType::Identifier,
+            DiagnosticCode::SyntaxError,
+            String::from("Expected a lint name for @allow(unused)."),
+        )?;
+
+        lints.push((lint_tk.get_lexeme(), lint_tk.get_span()));
+
+        if parser_ctx.check(TokenType::RParen) {
+            break;
+        }
+
+        parser_ctx.consume(
+            TokenType::Comma,
+            DiagnosticCode::SyntaxError,
+            String::from("Expected ','."),
+        )?;
+    }
+
+    parser_ctx.consume(
+        TokenType::RParen,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected ')'."),
+    )?;
+
+    Ok(lints)
+}
+
 fn build_assembler_syntax_attribute<'parser>(
     parser_ctx: &mut ParserContext<'parser>,
 ) -> Result<&'parser str, ThrushCompilerIssue> {
@@ -115,13 +165,13 @@ fn build_assembler_syntax_attribute<'parser>(
 
     parser_ctx.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
     let syntax_tk: &Token = parser_ctx.consume(
         TokenType::Str,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected a string literal for @asmsyntax(\"Intel\")."),
     )?;
 
@@ -132,7 +182,7 @@ fn build_assembler_syntax_attribute<'parser>(
 
     if !syntaxes.contains(&specified_syntax) {
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             format!(
                 "Unknown assembler syntax, valid are '{}'.",
                 syntaxes.join(", ")
@@ -144,7 +194,7 @@ fn build_assembler_syntax_attribute<'parser>(
 
     parser_ctx.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -158,13 +208,13 @@ fn build_call_convention_attribute(
 
     parser_ctx.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
     let convention_tk: &Token = parser_ctx.consume(
         TokenType::Str,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected a literal 'str' for @convention(\"CONVENTION NAME\")."),
     )?;
 
@@ -174,7 +224,7 @@ fn build_call_convention_attribute(
     if let Some(call_convention) = CALL_CONVENTIONS.get(name) {
         parser_ctx.consume(
             TokenType::RParen,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected ')'."),
         )?;
 
@@ -183,12 +233,12 @@ fn build_call_convention_attribute(
 
     parser_ctx.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Unknown call convention."),
         None,
         span,
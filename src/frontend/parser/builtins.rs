@@ -1,6 +1,6 @@
 use crate::{
     backend::llvm::compiler::builtins::Builtin,
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, expr, expressions::reference, typegen},
@@ -14,13 +14,13 @@ pub fn build_halloc<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let memcpy_tk: &Token = parser_context.consume(
         TokenType::Halloc,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'halloc' keyword."),
     )?;
 
     parser_context.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
@@ -30,7 +30,7 @@ pub fn build_halloc<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -48,13 +48,13 @@ pub fn build_memcpy<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let memcpy_tk: &Token = parser_context.consume(
         TokenType::MemCpy,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'memcpy' keyword."),
     )?;
 
     parser_context.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
@@ -64,7 +64,7 @@ pub fn build_memcpy<'parser>(
 
     parser_context.consume(
         TokenType::Comma,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ','."),
     )?;
 
@@ -72,7 +72,7 @@ pub fn build_memcpy<'parser>(
 
     parser_context.consume(
         TokenType::Comma,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ','."),
     )?;
 
@@ -80,7 +80,7 @@ pub fn build_memcpy<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -100,13 +100,13 @@ pub fn build_memmove<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let memcpy_tk: &Token = parser_context.consume(
         TokenType::MemMove,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'memmove' keyword."),
     )?;
 
     parser_context.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
@@ -116,7 +116,7 @@ pub fn build_memmove<'parser>(
 
     parser_context.consume(
         TokenType::Comma,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ','."),
     )?;
 
@@ -124,7 +124,7 @@ pub fn build_memmove<'parser>(
 
     parser_context.consume(
         TokenType::Comma,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ','."),
     )?;
 
@@ -132,7 +132,7 @@ pub fn build_memmove<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -152,13 +152,13 @@ pub fn build_memset<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let memcpy_tk: &Token = parser_context.consume(
         TokenType::MemSet,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'memset' keyword."),
     )?;
 
     parser_context.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
@@ -168,7 +168,7 @@ pub fn build_memset<'parser>(
 
     parser_context.consume(
         TokenType::Comma,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ','."),
     )?;
 
@@ -176,7 +176,7 @@ pub fn build_memset<'parser>(
 
     parser_context.consume(
         TokenType::Comma,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ','."),
     )?;
 
@@ -184,7 +184,7 @@ pub fn build_memset<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -204,13 +204,13 @@ pub fn build_alignof<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let sizeof_tk: &Token = parser_context.consume(
         TokenType::AlignOf,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected 'alignof' keyword.".into(),
     )?;
 
     parser_context.consume(
         TokenType::LParen,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected '('.".into(),
     )?;
 
@@ -228,7 +228,7 @@ pub fn build_alignof<'parser>(
 
         parser_context.consume(
             TokenType::RParen,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected ')'."),
         )?;
 
@@ -245,7 +245,7 @@ pub fn build_alignof<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected ')'.".into(),
     )?;
 
@@ -257,3 +257,82 @@ pub fn build_alignof<'parser>(
         span,
     })
 }
+
+pub fn build_assert<'parser>(
+    parser_context: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, ThrushCompilerIssue> {
+    let assert_tk: &Token = parser_context.consume(
+        TokenType::Assert,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected 'assert' keyword."),
+    )?;
+
+    parser_context.consume(
+        TokenType::LParen,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected '('."),
+    )?;
+
+    let span: Span = assert_tk.get_span();
+
+    let condition: Ast = expr::build_expr(parser_context)?;
+
+    parser_context.consume(
+        TokenType::RParen,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected ')'."),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: Builtin::Assert {
+            condition: condition.into(),
+            span,
+        },
+        kind: Type::Void,
+        span,
+    })
+}
+
+pub fn build_assert_eq<'parser>(
+    parser_context: &mut ParserContext<'parser>,
+) -> Result<Ast<'parser>, ThrushCompilerIssue> {
+    let assert_eq_tk: &Token = parser_context.consume(
+        TokenType::AssertEq,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected 'assert_eq' keyword."),
+    )?;
+
+    parser_context.consume(
+        TokenType::LParen,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected '('."),
+    )?;
+
+    let span: Span = assert_eq_tk.get_span();
+
+    let left: Ast = expr::build_expr(parser_context)?;
+
+    parser_context.consume(
+        TokenType::Comma,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected ','."),
+    )?;
+
+    let right: Ast = expr::build_expr(parser_context)?;
+
+    parser_context.consume(
+        TokenType::RParen,
+        DiagnosticCode::SyntaxError,
+        String::from("Expected ')'."),
+    )?;
+
+    Ok(Ast::Builtin {
+        builtin: Builtin::AssertEq {
+            left: left.into(),
+            right: right.into(),
+            span,
+        },
+        kind: Type::Void,
+        span,
+    })
+}
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{parser::ParserContext, types::parser::stmts::traits::TokenExtensions},
 };
 
@@ -10,7 +10,7 @@ pub fn check_unreacheable_state(
         parser_context.only_advance()?;
 
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Unreachable for execution."),
             None,
             parser_context.previous().get_span(),
@@ -27,7 +27,7 @@ pub fn check_inside_loop_state(
         parser_context.only_advance()?;
 
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("It must be contained within a loop block."),
             None,
             parser_context.previous().get_span(),
@@ -44,7 +44,7 @@ pub fn check_inside_function_state(
         parser_context.only_advance()?;
 
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("It must be contained within a function block."),
             None,
             parser_context.previous().get_span(),
@@ -59,7 +59,7 @@ pub fn check_main_scope_state(
 ) -> Result<(), ThrushCompilerIssue> {
     if !parser_context.is_main_scope() {
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("It must be contained within the main scope."),
             None,
             parser_context.previous().get_span(),
@@ -74,7 +74,7 @@ pub fn check_double_entrypoint_state(
 ) -> Result<(), ThrushCompilerIssue> {
     if parser_context.get_control_ctx().get_entrypoint() {
         return Err(ThrushCompilerIssue::Error(
-            "Duplicated entrypoint".into(),
+            DiagnosticCode::DuplicatedEntrypoint,
             "The language not support two entrypoints.".into(),
             None,
             parser_context.previous().get_span(),
@@ -91,7 +91,7 @@ pub fn check_double_global_assembler_state(
         parser_context.only_advance()?;
 
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "The global assembler is per-file.".into(),
             None,
             parser_context.previous().get_span(),
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, checks, expr, typegen},
@@ -25,13 +25,13 @@ pub fn build_assembler_function<'parser>(
 
     parser_context.consume(
         TokenType::AsmFn,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'asmfn' keyword."),
     )?;
 
     let asm_function_name_tk: &Token = parser_context.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected name to the function."),
     )?;
 
@@ -42,7 +42,7 @@ pub fn build_assembler_function<'parser>(
 
     parser_context.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
@@ -58,7 +58,7 @@ pub fn build_assembler_function<'parser>(
 
         let parameter_name_tk: &'parser Token = parser_context.consume(
             TokenType::Identifier,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected 'identifier'."),
         )?;
 
@@ -83,7 +83,7 @@ pub fn build_assembler_function<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -91,7 +91,7 @@ pub fn build_assembler_function<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -104,7 +104,7 @@ pub fn build_assembler_function<'parser>(
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -132,7 +132,7 @@ pub fn build_assembler_function<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -142,13 +142,13 @@ pub fn build_assembler_function<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -176,7 +176,7 @@ pub fn build_assembler_function<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -186,7 +186,7 @@ pub fn build_assembler_function<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, tokentype::TokenType},
         parser::{ParserContext, checks, statements::block},
@@ -16,19 +16,19 @@ pub fn build_entrypoint<'parser>(
 
     parser_context.consume(
         TokenType::LParen,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected '('.".into(),
     )?;
 
     parser_context.consume(
         TokenType::RParen,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected ')'.".into(),
     )?;
 
     parser_context.consume(
         TokenType::U32,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected 'u32'.".into(),
     )?;
 
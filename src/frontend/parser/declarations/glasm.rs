@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, checks, expr},
@@ -14,7 +14,7 @@ pub fn build_global_assembler<'parser>(
 
     let glasm_keyword_tk: &Token = parser_context.consume(
         TokenType::GlobalAsm,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected 'glasm' keyword.".into(),
     )?;
 
@@ -22,7 +22,7 @@ pub fn build_global_assembler<'parser>(
 
     parser_context.consume(
         TokenType::LParen,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected '('.".into(),
     )?;
 
@@ -31,13 +31,13 @@ pub fn build_global_assembler<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected ')'.".into(),
     )?;
 
     parser_context.consume(
         TokenType::SemiColon,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected ';'.".into(),
     )?;
 
@@ -1,11 +1,14 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, expr, typegen},
         types::{
             ast::{Ast, metadata::constant::ConstantMetadata},
-            parser::stmts::{traits::TokenExtensions, types::ThrushAttributes},
+            parser::stmts::{
+                traits::TokenExtensions,
+                types::{ThrushAttributes, ThrushDocs},
+            },
         },
         typesystem::types::Type,
     },
@@ -15,15 +18,17 @@ pub fn build_global_const<'parser>(
     parser_context: &mut ParserContext<'parser>,
     declare_forward: bool,
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
+    let docs: ThrushDocs = parser_context.get_docs();
+
     parser_context.consume(
         TokenType::Const,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected 'const' keyword.".into(),
     )?;
 
     let const_tk: &Token = parser_context.consume(
         TokenType::Identifier,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected name.".into(),
     )?;
 
@@ -34,7 +39,7 @@ pub fn build_global_const<'parser>(
 
     parser_context.consume(
         TokenType::Colon,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected ':'.".into(),
     )?;
 
@@ -43,7 +48,11 @@ pub fn build_global_const<'parser>(
     let attributes: ThrushAttributes =
         attributes::build_attributes(parser_context, &[TokenType::Eq])?;
 
-    parser_context.consume(TokenType::Eq, "Syntax error".into(), "Expected '='.".into())?;
+    parser_context.consume(
+        TokenType::Eq,
+        DiagnosticCode::SyntaxError,
+        "Expected '='.".into(),
+    )?;
 
     let value: Ast = expr::build_expression(parser_context)?;
 
@@ -61,6 +70,7 @@ pub fn build_global_const<'parser>(
         kind: const_type,
         value: value.into(),
         attributes,
+        docs,
         metadata: ConstantMetadata::new(true),
         span,
     })
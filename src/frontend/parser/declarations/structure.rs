@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, checks, typegen},
@@ -7,7 +7,7 @@ use crate::{
             ast::Ast,
             parser::stmts::{
                 traits::{StructFieldsExtensions, TokenExtensions},
-                types::{StructFields, ThrushAttributes},
+                types::{StructFields, ThrushAttributes, ThrushDocs},
             },
         },
         typesystem::types::Type,
@@ -20,15 +20,17 @@ pub fn build_structure<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     checks::check_main_scope_state(parser_context)?;
 
+    let docs: ThrushDocs = parser_context.get_docs();
+
     parser_context.consume(
         TokenType::Struct,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'struct' keyword."),
     )?;
 
     let name: &Token = parser_context.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected structure name."),
     )?;
 
@@ -40,7 +42,7 @@ pub fn build_structure<'parser>(
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -55,7 +57,7 @@ pub fn build_structure<'parser>(
         if parser_context.check(TokenType::Identifier) {
             let field_tk: &Token = parser_context.consume(
                 TokenType::Identifier,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected identifier."),
             )?;
 
@@ -64,7 +66,7 @@ pub fn build_structure<'parser>(
 
             parser_context.consume(
                 TokenType::Colon,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ':'."),
             )?;
 
@@ -85,12 +87,12 @@ pub fn build_structure<'parser>(
             } else if parser_context.check_to(TokenType::Identifier, 0) {
                 parser_context.consume(
                     TokenType::Comma,
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     String::from("Expected ','."),
                 )?;
             } else {
                 return Err(ThrushCompilerIssue::Error(
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     String::from("Expected identifier."),
                     None,
                     parser_context.previous().get_span(),
@@ -100,7 +102,7 @@ pub fn build_structure<'parser>(
             parser_context.only_advance()?;
 
             return Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected structure fields identifiers."),
                 None,
                 parser_context.previous().get_span(),
@@ -110,7 +112,7 @@ pub fn build_structure<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
@@ -131,6 +133,7 @@ pub fn build_structure<'parser>(
         fields: fields_types.clone(),
         kind: fields_types.get_type(),
         attributes,
+        docs,
         span,
     })
 }
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, checks, expr, typegen},
@@ -7,7 +7,7 @@ use crate::{
             ast::Ast,
             parser::stmts::{
                 traits::TokenExtensions,
-                types::{EnumFields, ThrushAttributes},
+                types::{EnumFields, ThrushAttributes, ThrushDocs},
             },
         },
         typesystem::types::Type,
@@ -20,15 +20,17 @@ pub fn build_enum<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     checks::check_main_scope_state(parser_context)?;
 
+    let docs: ThrushDocs = parser_context.get_docs();
+
     parser_context.consume(
         TokenType::Enum,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'enum'."),
     )?;
 
     let name: &Token = parser_context.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected enum name."),
     )?;
 
@@ -40,7 +42,7 @@ pub fn build_enum<'parser>(
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -62,7 +64,7 @@ pub fn build_enum<'parser>(
 
             parser_context.consume(
                 TokenType::Colon,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ':'."),
             )?;
 
@@ -70,7 +72,7 @@ pub fn build_enum<'parser>(
 
             if !field_type.is_numeric() {
                 return Err(ThrushCompilerIssue::Error(
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     String::from("Expected integer, boolean, char or floating-point types."),
                     None,
                     span,
@@ -87,7 +89,7 @@ pub fn build_enum<'parser>(
                 } else if field_type.is_char_type() {
                     if default_integer_value > char::MAX as u64 {
                         return Err(ThrushCompilerIssue::Error(
-                            "Syntax error".into(),
+                            DiagnosticCode::SyntaxError,
                             "Char overflow.".into(),
                             None,
                             span,
@@ -97,7 +99,7 @@ pub fn build_enum<'parser>(
                     Ast::new_char(field_type, default_integer_value, span)
                 } else {
                     return Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Expected integer, boolean, char or floating-point types.".into(),
                         None,
                         span,
@@ -112,7 +114,11 @@ pub fn build_enum<'parser>(
                 continue;
             }
 
-            parser_context.consume(TokenType::Eq, "Syntax error".into(), "Expected '='.".into())?;
+            parser_context.consume(
+                TokenType::Eq,
+                DiagnosticCode::SyntaxError,
+                "Expected '='.".into(),
+            )?;
 
             let expression: Ast = expr::build_expr(parser_context)?;
             let expression_type: &Type = expression.get_value_type()?;
@@ -124,7 +130,7 @@ pub fn build_enum<'parser>(
                 && !expression_type.is_char_type()
             {
                 return Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Expected integer, boolean, char or floating-point types.".into(),
                     None,
                     expression_span,
@@ -133,7 +139,7 @@ pub fn build_enum<'parser>(
 
             parser_context.consume(
                 TokenType::SemiColon,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ';'."),
             )?;
 
@@ -143,7 +149,7 @@ pub fn build_enum<'parser>(
         }
 
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "Expected identifier in enum field.".into(),
             None,
             parser_context.advance()?.get_span(),
@@ -152,13 +158,13 @@ pub fn build_enum<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected '}'.".into(),
     )?;
 
     parser_context.consume(
         TokenType::SemiColon,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected ';'.".into(),
     )?;
 
@@ -178,6 +184,7 @@ pub fn build_enum<'parser>(
         name: enum_name,
         fields: enum_fields,
         attributes: enum_attributes,
+        docs,
         span,
     })
 }
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::tokentype::TokenType,
         parser::{checks, expressions::precedences::or},
@@ -22,7 +22,7 @@ pub fn build_expression<'parser>(
 
     parser_context.consume(
         TokenType::SemiColon,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ';'."),
     )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, tokentype::TokenType},
         parser::{ParserContext, expr},
@@ -13,7 +13,7 @@ pub fn build_address_indexes<'parser>(
 ) -> Result<Vec<Ast<'parser>>, ThrushCompilerIssue> {
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -33,7 +33,7 @@ pub fn build_address_indexes<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -41,13 +41,13 @@ pub fn build_address_indexes<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
     if indexes.is_empty() {
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             "At least one index was expected.".into(),
             None,
             span,
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, expr},
@@ -13,7 +13,7 @@ pub fn build_array<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let array_start_tk: &Token = parser_context.consume(
         TokenType::LBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '['."),
     )?;
 
@@ -36,7 +36,7 @@ pub fn build_array<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -44,7 +44,7 @@ pub fn build_array<'parser>(
 
     parser_context.consume(
         TokenType::RBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ']'."),
     )?;
 
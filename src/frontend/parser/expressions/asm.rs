@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, expr, typegen},
@@ -16,7 +16,7 @@ pub fn build_asm_code_block<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let asm_tk: &Token = parser_context.consume(
         TokenType::Asm,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'asm' keyword."),
     )?;
 
@@ -44,7 +44,7 @@ pub fn build_asm_code_block<'parser>(
             } else {
                 parser_context.consume(
                     TokenType::Colon,
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     String::from("Expected ','."),
                 )?;
             }
@@ -52,14 +52,14 @@ pub fn build_asm_code_block<'parser>(
 
         parser_context.consume(
             TokenType::RParen,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected ')'."),
         )?;
     }
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -76,7 +76,7 @@ pub fn build_asm_code_block<'parser>(
 
         if !raw_str.is_str() {
             return Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected string literal value.".into(),
                 None,
                 raw_str_span,
@@ -96,7 +96,7 @@ pub fn build_asm_code_block<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -106,13 +106,13 @@ pub fn build_asm_code_block<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -140,7 +140,7 @@ pub fn build_asm_code_block<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -150,7 +150,7 @@ pub fn build_asm_code_block<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, tokentype::TokenType},
         parser::{ParserContext, expr},
@@ -54,7 +54,7 @@ pub fn build_call<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -62,7 +62,7 @@ pub fn build_call<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, expr},
@@ -21,13 +21,13 @@ pub fn build_constructor<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     parser_context.consume(
         TokenType::New,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected 'new' keyword.".into(),
     )?;
 
     let struct_tk: &Token = parser_context.consume(
         TokenType::Identifier,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected 'identifier' keyword.".into(),
     )?;
 
@@ -39,7 +39,7 @@ pub fn build_constructor<'parser>(
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -59,13 +59,13 @@ pub fn build_constructor<'parser>(
 
             parser_context.consume(
                 TokenType::Colon,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ':'."),
             )?;
 
             if !struct_found.contains_field(field_name) {
                 return Err(ThrushCompilerIssue::Error(
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     String::from("Expected existing structure field name."),
                     None,
                     field_span,
@@ -74,7 +74,7 @@ pub fn build_constructor<'parser>(
 
             if amount >= fields_required {
                 return Err(ThrushCompilerIssue::Error(
-                    String::from("Too many fields in structure"),
+                    DiagnosticCode::TooManyStructureFields,
                     format!("Expected '{}' fields, not '{}'.", fields_required, amount),
                     None,
                     span,
@@ -100,12 +100,12 @@ pub fn build_constructor<'parser>(
             } else if parser_context.check_to(TokenType::Identifier, 0) {
                 parser_context.consume(
                     TokenType::Comma,
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     String::from("Expected ','."),
                 )?;
             } else {
                 return Err(ThrushCompilerIssue::Error(
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     String::from("Expected identifier."),
                     None,
                     parser_context.previous().get_span(),
@@ -113,7 +113,7 @@ pub fn build_constructor<'parser>(
             }
         } else {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected field name."),
                 None,
                 span,
@@ -125,7 +125,7 @@ pub fn build_constructor<'parser>(
 
     if amount_fields != fields_required {
         return Err(ThrushCompilerIssue::Error(
-            String::from("Missing fields in structure"),
+            DiagnosticCode::MissingStructureFields,
             format!(
                 "Expected '{}' arguments, but '{}' was gived.",
                 fields_required, amount_fields
@@ -137,7 +137,7 @@ pub fn build_constructor<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
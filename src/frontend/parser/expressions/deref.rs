@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, expr},
@@ -20,7 +20,7 @@ pub fn build_dereference<'parser>(
         while parser_context.check(TokenType::Deref) {
             parser_context.consume(
                 TokenType::Deref,
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected 'deref' keyword.".into(),
             )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::ParserContext,
@@ -34,7 +34,7 @@ pub fn build_enum_value<'parser>(
 
     let field_tk: &Token = parser_context.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected enum name."),
     )?;
 
@@ -42,7 +42,7 @@ pub fn build_enum_value<'parser>(
 
     if !union.contain_field(field_name) {
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             format!("Not found '{}' field in '{}' enum.", name, field_name),
             None,
             span,
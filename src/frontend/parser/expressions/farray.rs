@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, expr},
@@ -14,13 +14,13 @@ pub fn build_fixed_array<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     parser_context.consume(
         TokenType::Fixed,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'fixed' keyword."),
     )?;
 
     let array_start_tk: &Token = parser_context.consume(
         TokenType::LBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '['."),
     )?;
 
@@ -43,7 +43,7 @@ pub fn build_fixed_array<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -51,7 +51,7 @@ pub fn build_fixed_array<'parser>(
 
     parser_context.consume(
         TokenType::RBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ']'."),
     )?;
 
@@ -67,7 +67,7 @@ pub fn build_fixed_array<'parser>(
             array_type = Type::FixedArray(item.get_value_type()?.clone().into(), size)
         } else {
             return Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "The size limit of an array was exceeded.".into(),
                 None,
                 span,
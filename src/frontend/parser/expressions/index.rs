@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::{span::Span, tokentype::TokenType},
         parser::{ParserContext, expr},
@@ -63,7 +66,7 @@ pub fn build_index<'parser>(
         } else {
             parser_context.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -71,7 +74,7 @@ pub fn build_index<'parser>(
 
     parser_context.consume(
         TokenType::RBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ']'."),
     )?;
 
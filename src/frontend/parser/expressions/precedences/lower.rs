@@ -1,6 +1,6 @@
 use crate::{
     backend::llvm::compiler::attributes::LLVMAttribute,
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{
@@ -38,6 +38,9 @@ pub fn lower_precedence<'parser>(
 
         TokenType::AlignOf => builtins::build_alignof(parser_context)?,
 
+        TokenType::Assert => builtins::build_assert(parser_context)?,
+        TokenType::AssertEq => builtins::build_assert_eq(parser_context)?,
+
         TokenType::Asm => asm::build_asm_code_block(parser_context)?,
 
         TokenType::Alloc => {
@@ -62,7 +65,7 @@ pub fn lower_precedence<'parser>(
 
                 _ => {
                     return Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Expected site allocation attribute.".into(),
                         None,
                         span,
@@ -72,7 +75,7 @@ pub fn lower_precedence<'parser>(
 
             parser_context.consume(
                 TokenType::LBrace,
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected '{'.".into(),
             )?;
 
@@ -91,7 +94,7 @@ pub fn lower_precedence<'parser>(
 
             parser_context.consume(
                 TokenType::RBrace,
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected '}'.".into(),
             )?;
 
@@ -111,14 +114,14 @@ pub fn lower_precedence<'parser>(
 
             parser_context.consume(
                 TokenType::Comma,
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected ','.".into(),
             )?;
 
             if parser_context.check(TokenType::Identifier) {
                 let identifier_tk: &Token = parser_context.consume(
                     TokenType::Identifier,
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Expected 'identifier'.".into(),
                 )?;
 
@@ -155,7 +158,7 @@ pub fn lower_precedence<'parser>(
 
                 parser_context.consume(
                     TokenType::Comma,
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Expected ','.".into(),
                 )?;
 
@@ -175,7 +178,7 @@ pub fn lower_precedence<'parser>(
 
             parser_context.consume(
                 TokenType::Comma,
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected ','.".into(),
             )?;
 
@@ -234,7 +237,7 @@ pub fn lower_precedence<'parser>(
 
             parser_context.consume(
                 TokenType::RParen,
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected ')'.".into(),
             )?;
 
@@ -348,7 +351,7 @@ pub fn lower_precedence<'parser>(
             let previous: &Token = parser_context.advance()?;
 
             return Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 format!("Statement '{}' don't allowed.", previous.lexeme),
                 None,
                 previous.span,
@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::ParserContext,
@@ -42,7 +45,7 @@ pub fn build_property<'parser>(
 
     let first_property: &Token = parser_context.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected property name."),
     )?;
 
@@ -53,7 +56,7 @@ pub fn build_property<'parser>(
     while parser_context.match_token(TokenType::Dot)? {
         let property: &Token = parser_context.consume(
             TokenType::Identifier,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected property name."),
         )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::ParserContext,
@@ -171,7 +171,7 @@ pub fn build_reference<'parser>(
     }
 
     Err(ThrushCompilerIssue::Error(
-        "Unknown reference".into(),
+        DiagnosticCode::UnknownReference,
         "It is not a valid reference.".into(),
         None,
         span,
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, expressions::reference, typegen},
@@ -13,13 +13,13 @@ pub fn build_sizeof<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     let sizeof_tk: &Token = parser_context.consume(
         TokenType::SizeOf,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'sizeof' keyword."),
     )?;
 
     parser_context.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
@@ -37,7 +37,7 @@ pub fn build_sizeof<'parser>(
 
         parser_context.consume(
             TokenType::RParen,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected ')'."),
         )?;
 
@@ -52,7 +52,7 @@ pub fn build_sizeof<'parser>(
 
     parser_context.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -18,22 +18,24 @@ use ahash::AHashMap as HashMap;
 use contexts::typectx::ParserTypeContext;
 use symbols::SymbolsTable;
 
-use crate::core::compiler::options::CompilerFile;
+use crate::core::compiler::sourcemap::SourceMap;
 use crate::core::console::logging::{self, LoggingType};
+use crate::core::diagnostic::codes::DiagnosticCode;
 use crate::core::diagnostic::diagnostician::Diagnostician;
 use crate::core::errors::standard::ThrushCompilerIssue;
 use crate::frontend::lexer::token::Token;
 use crate::frontend::lexer::tokentype::TokenType;
 use crate::frontend::parser::contexts::controlctx::ParserControlContext;
 use crate::frontend::types::ast::Ast;
+use crate::frontend::types::parser::stmts::types::ThrushDocs;
 use crate::frontend::types::parser::symbols::types::{AssemblerFunctions, Functions};
 
-const MINIMAL_STATEMENT_CAPACITY: usize = 100_000;
 const MINIMAL_GLOBAL_CAPACITY: usize = 2024;
 
 pub struct ParserContext<'parser> {
     ast: Vec<Ast<'parser>>,
-    tokens: &'parser [Token],
+    tokens: Vec<&'parser Token>,
+    docs: HashMap<usize, ThrushDocs<'parser>>,
     errors: Vec<ThrushCompilerIssue>,
     bugs: Vec<ThrushCompilerIssue>,
 
@@ -48,19 +50,33 @@ pub struct ParserContext<'parser> {
 
 pub struct Parser<'parser> {
     tokens: &'parser [Token],
-    file: &'parser CompilerFile,
+    source_map: &'parser SourceMap,
 }
 
 impl<'parser> Parser<'parser> {
     pub fn parse(
         tokens: &'parser [Token],
-        file: &'parser CompilerFile,
+        source_map: &'parser SourceMap,
+    ) -> (ParserContext<'parser>, bool) {
+        Self { tokens, source_map }.start(None)
+    }
+
+    /// Parses with the symbols that earlier parses left, as every snippet of a REPL
+    /// session does with the declarations entered before it.
+    pub fn parse_with_symbols(
+        tokens: &'parser [Token],
+        source_map: &'parser SourceMap,
+        symbols: SymbolsTable<'parser>,
     ) -> (ParserContext<'parser>, bool) {
-        Self { tokens, file }.start()
+        Self { tokens, source_map }.start(Some(symbols))
     }
 
-    fn start(&mut self) -> (ParserContext<'parser>, bool) {
-        let mut parser_context: ParserContext = ParserContext::new(self.tokens, self.file);
+    fn start(&mut self, symbols: Option<SymbolsTable<'parser>>) -> (ParserContext<'parser>, bool) {
+        let mut parser_context: ParserContext = ParserContext::new(self.tokens, self.source_map);
+
+        if let Some(symbols) = symbols {
+            parser_context.symbols = symbols;
+        }
 
         parser_context.declare_forward();
 
@@ -72,7 +88,7 @@ impl<'parser> Parser<'parser> {
                 Err(error) => {
                     if error.is_bug() {
                         parser_context.add_bug(error);
-                    } else {
+                    } else if !parser_context.is_at_lexer_error() {
                         parser_context.add_error(error);
                     }
 
@@ -90,18 +106,37 @@ impl<'parser> Parser<'parser> {
 }
 
 impl<'parser> ParserContext<'parser> {
-    pub fn new(tokens: &'parser [Token], file: &'parser CompilerFile) -> Self {
+    pub fn new(tokens: &'parser [Token], source_map: &'parser SourceMap) -> Self {
         let functions: Functions = HashMap::with_capacity(MINIMAL_GLOBAL_CAPACITY);
         let asm_functions: AssemblerFunctions = HashMap::with_capacity(MINIMAL_GLOBAL_CAPACITY);
 
+        let mut parsed_tokens: Vec<&Token> = Vec::with_capacity(tokens.len());
+        let mut docs: HashMap<usize, ThrushDocs> = HashMap::with_capacity(100);
+        let mut pending_docs: ThrushDocs = Vec::new();
+
+        // The doc comments are kept apart, attached to the token that follows them.
+        tokens.iter().for_each(|token| {
+            if token.kind.is_doc_comment() {
+                pending_docs.push(self::get_doc_text(&token.lexeme));
+                return;
+            }
+
+            if !pending_docs.is_empty() {
+                docs.insert(parsed_tokens.len(), std::mem::take(&mut pending_docs));
+            }
+
+            parsed_tokens.push(token);
+        });
+
         Self {
-            tokens,
-            ast: Vec::with_capacity(MINIMAL_STATEMENT_CAPACITY),
+            tokens: parsed_tokens,
+            docs,
+            ast: Vec::new(),
             errors: Vec::with_capacity(100),
             bugs: Vec::with_capacity(100),
             control_ctx: ParserControlContext::new(),
             type_ctx: ParserTypeContext::new(),
-            diagnostician: Diagnostician::new(file),
+            diagnostician: Diagnostician::new(source_map),
             symbols: SymbolsTable::with_functions(functions, asm_functions),
             current: 0,
             scope: 0,
@@ -128,7 +163,7 @@ impl<'parser> ParserContext<'parser> {
     pub fn consume(
         &mut self,
         kind: TokenType,
-        title: String,
+        code: DiagnosticCode,
         help: String,
     ) -> Result<&'parser Token, ThrushCompilerIssue> {
         if self.peek().kind == kind {
@@ -136,7 +171,7 @@ impl<'parser> ParserContext<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            title,
+            code,
             help,
             None,
             self.previous().span,
@@ -159,7 +194,7 @@ impl<'parser> ParserContext<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("EOF has been reached."),
             None,
             self.peek().span,
@@ -173,7 +208,7 @@ impl<'parser> ParserContext<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("EOF has been reached."),
             None,
             self.peek().span,
@@ -208,7 +243,7 @@ impl<'parser> ParserContext<'parser> {
 
     #[must_use]
     pub fn peek(&self) -> &'parser Token {
-        self.tokens.get(self.current).unwrap_or_else(|| {
+        self.tokens.get(self.current).copied().unwrap_or_else(|| {
             logging::log(
                 LoggingType::FrontEndPanic,
                 "Attempting to get token in invalid current position.",
@@ -220,21 +255,30 @@ impl<'parser> ParserContext<'parser> {
 
     #[must_use]
     pub fn previous(&self) -> &'parser Token {
-        self.tokens.get(self.current - 1).unwrap_or_else(|| {
-            logging::log(
-                LoggingType::FrontEndPanic,
-                &format!(
-                    "Attempting to get token in invalid previous position in line '{}'.",
-                    self.peek().span.get_line()
-                ),
-            );
-            unreachable!()
-        })
+        self.tokens
+            .get(self.current - 1)
+            .copied()
+            .unwrap_or_else(|| {
+                logging::log(
+                    LoggingType::FrontEndPanic,
+                    &format!(
+                        "Attempting to get token in invalid previous position in line '{}'.",
+                        self.peek().span.get_line()
+                    ),
+                );
+                unreachable!()
+            })
     }
 
     pub fn declare_forward(&mut self) {
         declaration::parse_forward(self);
     }
+
+    /// The '///' comments written right before the current token.
+    #[must_use]
+    pub fn get_docs(&self) -> ThrushDocs<'parser> {
+        self.docs.get(&self.current).cloned().unwrap_or_default()
+    }
 }
 
 impl ParserContext<'_> {
@@ -247,6 +291,15 @@ impl ParserContext<'_> {
     pub fn is_eof(&self) -> bool {
         self.peek().kind == TokenType::Eof
     }
+
+    #[must_use]
+    pub fn is_at_lexer_error(&self) -> bool {
+        if self.peek().kind.is_error() {
+            return true;
+        }
+
+        self.current > 0 && self.tokens[self.current - 1].kind.is_error()
+    }
 }
 
 impl<'parser> ParserContext<'parser> {
@@ -285,6 +338,10 @@ impl<'parser> ParserContext<'parser> {
     pub fn get_ast(&self) -> &[Ast<'parser>] {
         &self.ast
     }
+
+    pub fn get_errors_count(&self) -> usize {
+        self.errors.len() + self.bugs.len()
+    }
 }
 
 impl<'parser> ParserContext<'parser> {
@@ -300,3 +357,9 @@ impl<'parser> ParserContext<'parser> {
         self.bugs.push(error);
     }
 }
+
+fn get_doc_text(lexeme: &str) -> &str {
+    let text: &str = lexeme.strip_prefix("///").unwrap_or(lexeme);
+
+    text.strip_prefix(' ').unwrap_or(text).trim_end()
+}
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{lexer::span::Span, typesystem::types::Type},
 };
 
@@ -8,7 +8,7 @@ pub fn float(lexeme: &str, span: Span) -> Result<(Type, f64), ThrushCompilerIssu
 
     if dot_count > 1 {
         return Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "Only one decimal marker was expected.".into(),
             None,
             span,
@@ -24,7 +24,7 @@ pub fn float(lexeme: &str, span: Span) -> Result<(Type, f64), ThrushCompilerIssu
     }
 
     Err(ThrushCompilerIssue::Error(
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Float out of bounds.".into(),
         None,
         span,
@@ -64,7 +64,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                     return Ok((Type::S64, number as u64));
                 } else {
                     return Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Integer out of bounds signed hexadecimal format.".into(),
                         None,
                         span,
@@ -84,7 +84,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                         return Ok((Type::U64, number as u64));
                     } else {
                         return Err(ThrushCompilerIssue::Error(
-                            "Syntax error".into(),
+                            DiagnosticCode::SyntaxError,
                             "Integer out of bounds unsigned hexadecimal format.".into(),
                             None,
                             span,
@@ -93,7 +93,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                 }
 
                 Err(_) => Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Integer invalid numeric hexadecimal format.".into(),
                     None,
                     span,
@@ -120,7 +120,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                     return Ok((Type::U64, number as u64));
                 } else {
                     return Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Integer out of bounds signed binary format.".into(),
                         None,
                         span,
@@ -140,7 +140,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                         return Ok((Type::U64, number as u64));
                     } else {
                         return Err(ThrushCompilerIssue::Error(
-                            "Syntax error".into(),
+                            DiagnosticCode::SyntaxError,
                             "Integer out of bounds unsigned binary format.".into(),
                             None,
                             span,
@@ -149,7 +149,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                 }
 
                 Err(_) => Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Integer invalid binary format.".into(),
                     None,
                     span,
@@ -170,7 +170,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                 return Ok((Type::U64, number as u64));
             } else {
                 return Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Integer out of bounds.".into(),
                     None,
                     span,
@@ -190,7 +190,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
                     Ok((Type::U64, number as u64))
                 } else {
                     Err(ThrushCompilerIssue::Error(
-                        "Syntax error".into(),
+                        DiagnosticCode::SyntaxError,
                         "Integer out of bounds.".into(),
                         None,
                         span,
@@ -199,7 +199,7 @@ pub fn integer(lexeme: &str, span: Span) -> Result<(Type, u64), ThrushCompilerIs
             }
 
             Err(_) => Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Integer out of bounds.".into(),
                 None,
                 span,
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, checks, statement},
@@ -14,7 +14,7 @@ pub fn build_block<'parser>(
 
     let block_tk: &Token = parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -30,6 +30,8 @@ pub fn build_block<'parser>(
         stmts.push(stmt)
     }
 
+    let span: Span = span.merge(parser_context.previous().get_span());
+
     parser_context.get_mut_symbols().end_scope();
     *parser_context.get_mut_scope() -= 1;
 
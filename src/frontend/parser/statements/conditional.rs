@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, checks, expr, statements::block},
@@ -16,7 +16,7 @@ pub fn build_conditional<'parser>(
 
     let if_tk: &Token = parser_context.consume(
         TokenType::If,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'if' keyword."),
     )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, checks},
@@ -14,7 +14,7 @@ pub fn build_continue<'parser>(
 
     let continue_tk: &Token = parser_context.consume(
         TokenType::Continue,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'continue' keyword."),
     )?;
 
@@ -27,7 +27,7 @@ pub fn build_continue<'parser>(
 
     parser_context.consume(
         TokenType::SemiColon,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ';'."),
     )?;
 
@@ -41,7 +41,7 @@ pub fn build_break<'parser>(
 
     let break_tk: &Token = parser_context.consume(
         TokenType::Break,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'break' keyword."),
     )?;
 
@@ -54,7 +54,7 @@ pub fn build_break<'parser>(
 
     parser_context.consume(
         TokenType::SemiColon,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ';'."),
     )?;
 
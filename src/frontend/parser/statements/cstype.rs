@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, checks, typegen},
@@ -22,13 +22,13 @@ pub fn build_custom_type<'parser>(
 
     parser_context.consume(
         TokenType::Type,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'type' keyword."),
     )?;
 
     let name: &Token = parser_context.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected type name."),
     )?;
 
@@ -38,7 +38,7 @@ pub fn build_custom_type<'parser>(
 
     parser_context.consume(
         TokenType::Eq,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '='."),
     )?;
 
@@ -47,7 +47,7 @@ pub fn build_custom_type<'parser>(
 
     parser_context.consume(
         TokenType::LBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '{'."),
     )?;
 
@@ -60,7 +60,7 @@ pub fn build_custom_type<'parser>(
 
     parser_context.consume(
         TokenType::RBrace,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '}'."),
     )?;
 
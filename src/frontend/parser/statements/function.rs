@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{
@@ -10,7 +10,7 @@ use crate::{
             parser::{
                 stmts::{
                     traits::{ThrushAttributesExtensions, TokenExtensions},
-                    types::ThrushAttributes,
+                    types::{ThrushAttributes, ThrushDocs},
                 },
                 symbols::types::ParametersTypes,
             },
@@ -25,15 +25,17 @@ pub fn build_function<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     checks::check_main_scope_state(parser_ctx)?;
 
+    let docs: ThrushDocs = parser_ctx.get_docs();
+
     parser_ctx.consume(
         TokenType::Fn,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'fn' keyword."),
     )?;
 
     let function_name_tk: &Token = parser_ctx.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected identifier."),
     )?;
 
@@ -59,7 +61,7 @@ pub fn build_function<'parser>(
 
     parser_ctx.consume(
         TokenType::LParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '('."),
     )?;
 
@@ -77,7 +79,7 @@ pub fn build_function<'parser>(
 
         let parameter_tk: &Token = parser_ctx.consume(
             TokenType::Identifier,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected parameter name."),
         )?;
 
@@ -88,7 +90,7 @@ pub fn build_function<'parser>(
 
         parser_ctx.consume(
             TokenType::Colon,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected ':'."),
         )?;
 
@@ -112,7 +114,7 @@ pub fn build_function<'parser>(
         } else {
             parser_ctx.consume(
                 TokenType::Comma,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ','."),
             )?;
         }
@@ -120,7 +122,7 @@ pub fn build_function<'parser>(
 
     parser_ctx.consume(
         TokenType::RParen,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ')'."),
     )?;
 
@@ -130,6 +132,7 @@ pub fn build_function<'parser>(
         attributes::build_attributes(parser_ctx, &[TokenType::SemiColon, TokenType::LBrace])?;
 
     let function_has_ignore: bool = attributes.has_ignore_attribute();
+    let function_is_test: bool = attributes.has_test_attribute();
 
     let mut function: Ast = Ast::Function {
         name: function_name,
@@ -139,19 +142,27 @@ pub fn build_function<'parser>(
         body: Ast::Null { span }.into(),
         return_type: return_type.clone(),
         attributes,
+        docs,
         span,
     };
 
     if declare_forward {
-        if let Err(error) = parser_ctx.get_mut_symbols().new_function(
-            function_name,
-            (
-                return_type,
-                ParametersTypes::new(parameters_types),
-                function_has_ignore,
-            ),
-            span,
-        ) {
+        // Test functions are only called by the test harness, never by the program.
+        let declaration: Result<(), ThrushCompilerIssue> = if function_is_test {
+            Ok(())
+        } else {
+            parser_ctx.get_mut_symbols().new_function(
+                function_name,
+                (
+                    return_type,
+                    ParametersTypes::new(parameters_types),
+                    function_has_ignore,
+                ),
+                span,
+            )
+        };
+
+        if let Err(error) = declaration {
             parser_ctx.add_error(error);
         }
 
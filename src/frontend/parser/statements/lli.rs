@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, checks, expr, typegen},
@@ -15,13 +15,13 @@ pub fn build_lli<'parser>(
 
     parser_ctx.consume(
         TokenType::Instr,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'instr' keyword."),
     )?;
 
     let instr_tk: &Token = parser_ctx.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected name."),
     )?;
 
@@ -30,7 +30,7 @@ pub fn build_lli<'parser>(
 
     parser_ctx.consume(
         TokenType::Colon,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ':'."),
     )?;
 
@@ -38,7 +38,7 @@ pub fn build_lli<'parser>(
 
     parser_ctx.consume(
         TokenType::Eq,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '='."),
     )?;
 
@@ -46,7 +46,7 @@ pub fn build_lli<'parser>(
 
     parser_ctx.consume(
         TokenType::SemiColon,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ';'."),
     )?;
 
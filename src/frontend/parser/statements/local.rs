@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, checks, expr, typegen},
@@ -18,7 +18,7 @@ pub fn build_local<'parser>(
 
     parser_ctx.consume(
         TokenType::Local,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'local' keyword."),
     )?;
 
@@ -26,7 +26,7 @@ pub fn build_local<'parser>(
 
     let local_tk: &Token = parser_ctx.consume(
         TokenType::Identifier,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected identifier."),
     )?;
 
@@ -37,7 +37,7 @@ pub fn build_local<'parser>(
 
     parser_ctx.consume(
         TokenType::Colon,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ':'."),
     )?;
 
@@ -72,7 +72,7 @@ pub fn build_local<'parser>(
 
     parser_ctx.consume(
         TokenType::Eq,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '='."),
     )?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{
@@ -17,7 +17,7 @@ pub fn build_for_loop<'parser>(
 
     let for_tk: &Token = parser_context.consume(
         TokenType::For,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'for' keyword."),
     )?;
 
@@ -50,7 +50,7 @@ pub fn build_loop<'parser>(
 
     let loop_tk: &Token = parser_context.consume(
         TokenType::Loop,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'loop' keyword."),
     )?;
 
@@ -83,7 +83,7 @@ pub fn build_while_loop<'parser>(
 
     let while_tk: &Token = parser_context.consume(
         TokenType::While,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'while' keyword."),
     )?;
 
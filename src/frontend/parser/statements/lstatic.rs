@@ -1,11 +1,14 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, attributes, checks, expr, typegen},
         types::{
             ast::{Ast, metadata::staticvar::StaticMetadata},
-            parser::stmts::{traits::TokenExtensions, types::ThrushAttributes},
+            parser::stmts::{
+                traits::TokenExtensions,
+                types::{ThrushAttributes, ThrushDocs},
+            },
         },
         typesystem::types::Type,
     },
@@ -16,9 +19,11 @@ pub fn build_static<'parser>(
 ) -> Result<Ast<'parser>, ThrushCompilerIssue> {
     self::check_state(parser_context)?;
 
+    let docs: ThrushDocs = parser_context.get_docs();
+
     parser_context.consume(
         TokenType::Static,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected 'static' keyword.".into(),
     )?;
 
@@ -26,7 +31,7 @@ pub fn build_static<'parser>(
 
     let static_tk: &Token = parser_context.consume(
         TokenType::Identifier,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected name.".into(),
     )?;
 
@@ -37,7 +42,7 @@ pub fn build_static<'parser>(
 
     parser_context.consume(
         TokenType::Colon,
-        "Syntax error".into(),
+        DiagnosticCode::SyntaxError,
         "Expected ':'.".into(),
     )?;
 
@@ -46,7 +51,11 @@ pub fn build_static<'parser>(
     let attributes: ThrushAttributes =
         attributes::build_attributes(parser_context, &[TokenType::Eq])?;
 
-    parser_context.consume(TokenType::Eq, "Syntax error".into(), "Expected '='.".into())?;
+    parser_context.consume(
+        TokenType::Eq,
+        DiagnosticCode::SyntaxError,
+        "Expected '='.".into(),
+    )?;
 
     let value: Ast = expr::build_expression(parser_context)?;
 
@@ -64,6 +73,7 @@ pub fn build_static<'parser>(
         kind: static_type,
         value: value.into(),
         attributes,
+        docs,
         metadata,
         span,
     })
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::{ParserContext, checks, expr},
@@ -15,7 +15,7 @@ pub fn build_return<'parser>(
 
     let return_tk: &Token = parser_context.consume(
         TokenType::Return,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected 'return' keyword."),
     )?;
 
@@ -41,7 +41,7 @@ pub fn build_return<'parser>(
 
     parser_context.consume(
         TokenType::SemiColon,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ';'."),
     )?;
 
@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         types::{
@@ -96,12 +99,13 @@ impl<'parser> SymbolsTable<'parser> {
                 ..
             } = parameter
             {
-                if self.parameters.contains_key(name) {
-                    return Err(ThrushCompilerIssue::Error(
-                        "Parameter already declared".into(),
+                if let Some((_, _, previous_span)) = self.parameters.get(name) {
+                    return Err(ThrushCompilerIssue::LabeledError(
+                        DiagnosticCode::ParameterAlreadyDeclared,
                         format!("'{}' parameter already declared before.", name),
                         None,
                         *span,
+                        Box::new([(*previous_span, "previous definition here".into())]),
                     ));
                 }
 
@@ -120,12 +124,13 @@ impl<'parser> SymbolsTable<'parser> {
         span: Span,
     ) -> Result<(), ThrushCompilerIssue> {
         if let Some(last_scope) = self.llis.last_mut() {
-            if last_scope.contains_key(name) {
-                return Err(ThrushCompilerIssue::Error(
-                    String::from("Low level instruction already declared"),
+            if let Some((_, previous_span)) = last_scope.get(name) {
+                return Err(ThrushCompilerIssue::LabeledError(
+                    DiagnosticCode::LLIAlreadyDeclared,
                     format!("Low level instruction '{}' already declared before.", name),
                     None,
                     span,
+                    Box::new([(*previous_span, String::from("previous definition here"))]),
                 ));
             }
 
@@ -150,12 +155,18 @@ impl<'parser> SymbolsTable<'parser> {
         span: Span,
     ) -> Result<(), ThrushCompilerIssue> {
         if let Some(last_scope) = self.locals.last_mut() {
-            if last_scope.contains_key(name) || self.parameters.contains_key(name) {
-                return Err(ThrushCompilerIssue::Error(
-                    String::from("Local variable already declared"),
+            let previous_span: Option<Span> = last_scope
+                .get(name)
+                .map(|(_, _, span)| *span)
+                .or_else(|| self.parameters.get(name).map(|(_, _, span)| *span));
+
+            if let Some(previous_span) = previous_span {
+                return Err(ThrushCompilerIssue::LabeledError(
+                    DiagnosticCode::LocalAlreadyDeclared,
                     format!("'{}' local variable already declared before.", name),
                     None,
                     span,
+                    Box::new([(previous_span, String::from("previous definition here"))]),
                 ));
             }
 
@@ -181,7 +192,7 @@ impl<'parser> SymbolsTable<'parser> {
     ) -> Result<(), ThrushCompilerIssue> {
         if self.global_statics.contains_key(name) {
             return Err(ThrushCompilerIssue::Error(
-                "Static already declared".into(),
+                DiagnosticCode::StaticAlreadyDeclared,
                 format!("'{}' static already declared before.", name),
                 None,
                 span,
@@ -202,7 +213,7 @@ impl<'parser> SymbolsTable<'parser> {
         if let Some(last_scope) = self.statics.last_mut() {
             if last_scope.contains_key(name) {
                 return Err(ThrushCompilerIssue::Error(
-                    String::from("Static already declared"),
+                    DiagnosticCode::StaticAlreadyDeclared,
                     format!("'{}' static already declared before.", name),
                     None,
                     span,
@@ -231,7 +242,7 @@ impl<'parser> SymbolsTable<'parser> {
     ) -> Result<(), ThrushCompilerIssue> {
         if self.global_constants.contains_key(name) {
             return Err(ThrushCompilerIssue::Error(
-                "Constant already declared".into(),
+                DiagnosticCode::ConstantAlreadyDeclared,
                 format!("'{}' constant already declared before.", name),
                 None,
                 span,
@@ -252,7 +263,7 @@ impl<'parser> SymbolsTable<'parser> {
         if let Some(last_scope) = self.constants.last_mut() {
             if last_scope.contains_key(name) {
                 return Err(ThrushCompilerIssue::Error(
-                    String::from("Constant already declared"),
+                    DiagnosticCode::ConstantAlreadyDeclared,
                     format!("'{}' constant already declared before.", name),
                     None,
                     span,
@@ -281,7 +292,7 @@ impl<'parser> SymbolsTable<'parser> {
     ) -> Result<(), ThrushCompilerIssue> {
         if self.custom_types.contains_key(name) {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Custom type already declared"),
+                DiagnosticCode::CustomTypeAlreadyDeclared,
                 format!("'{}' custom type already declared before.", name),
                 None,
                 span,
@@ -301,7 +312,7 @@ impl<'parser> SymbolsTable<'parser> {
     ) -> Result<(), ThrushCompilerIssue> {
         if self.structs.contains_key(name) {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Structure already declared"),
+                DiagnosticCode::StructureAlreadyDeclared,
                 format!("'{}' structure already declared before.", name),
                 None,
                 span,
@@ -321,7 +332,7 @@ impl<'parser> SymbolsTable<'parser> {
     ) -> Result<(), ThrushCompilerIssue> {
         if self.enums.contains_key(name) {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Enum already declared"),
+                DiagnosticCode::EnumAlreadyDeclared,
                 format!("'{}' enum already declared before.", name),
                 None,
                 span,
@@ -341,7 +352,7 @@ impl<'parser> SymbolsTable<'parser> {
     ) -> Result<(), ThrushCompilerIssue> {
         if self.asm_functions.contains_key(name) {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Assembly function already declared"),
+                DiagnosticCode::AssemblerFunctionAlreadyDeclared,
                 format!("'{}' assembler function already declared before.", name),
                 None,
                 span,
@@ -361,7 +372,7 @@ impl<'parser> SymbolsTable<'parser> {
     ) -> Result<(), ThrushCompilerIssue> {
         if self.functions.contains_key(name) {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Function already declared"),
+                DiagnosticCode::FunctionAlreadyDeclared,
                 format!("'{}' function already declared before.", name),
                 None,
                 span,
@@ -569,7 +580,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Not found"),
+            DiagnosticCode::NotFound,
             format!("'{}' isn't declared or defined.", name),
             None,
             span,
@@ -588,7 +599,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Not found"),
+            DiagnosticCode::NotFound,
             String::from("LLI not found."),
             None,
             span,
@@ -606,7 +617,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Not found"),
+            DiagnosticCode::NotFound,
             String::from("Struct not found."),
             None,
             span,
@@ -624,7 +635,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Not found"),
+            DiagnosticCode::NotFound,
             String::from("Assembler function not found."),
             None,
             span,
@@ -642,7 +653,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            "Not found".into(),
+            DiagnosticCode::NotFound,
             "Function not found.".into(),
             None,
             span,
@@ -660,7 +671,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            "Not found".into(),
+            DiagnosticCode::NotFound,
             "Enum not found.".into(),
             None,
             span,
@@ -678,7 +689,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            "Not found".into(),
+            DiagnosticCode::NotFound,
             "Custom type not found.".into(),
             None,
             span,
@@ -697,7 +708,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            "Not found".into(),
+            DiagnosticCode::NotFound,
             "Local not found.".into(),
             None,
             span,
@@ -732,7 +743,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            "Not found".into(),
+            DiagnosticCode::NotFound,
             "Static reference not found.".into(),
             None,
             span,
@@ -767,7 +778,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            "Not found".into(),
+            DiagnosticCode::NotFound,
             "Constant reference not found.".into(),
             None,
             span,
@@ -785,7 +796,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Not found"),
+            DiagnosticCode::NotFound,
             String::from("Parameter not found in this scope."),
             None,
             span,
@@ -803,7 +814,7 @@ impl<'parser> SymbolsTable<'parser> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            String::from("Structure not found"),
+            DiagnosticCode::StructureNotFound,
             format!("'{}' structure not defined.", name),
             None,
             span,
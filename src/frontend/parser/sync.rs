@@ -44,6 +44,8 @@ impl ParserContext<'_> {
 }
 
 fn sync_with_declaration(parser_context: &mut ParserContext) -> Result<(), ThrushCompilerIssue> {
+    self::skip_lexer_error(parser_context)?;
+
     loop {
         if parser_context.is_eof() {
             break;
@@ -51,7 +53,7 @@ fn sync_with_declaration(parser_context: &mut ParserContext) -> Result<(), Thrus
 
         let peeked: &Token = parser_context.peek();
 
-        if SYNC_DECLARATIONS.contains(&peeked.kind) {
+        if SYNC_DECLARATIONS.contains(&peeked.kind) || peeked.kind.is_error() {
             break;
         }
 
@@ -65,6 +67,8 @@ fn sync_with_declaration(parser_context: &mut ParserContext) -> Result<(), Thrus
 }
 
 fn sync_with_statement(parser_context: &mut ParserContext) -> Result<(), ThrushCompilerIssue> {
+    self::skip_lexer_error(parser_context)?;
+
     loop {
         if parser_context.is_eof() {
             break;
@@ -81,7 +85,10 @@ fn sync_with_statement(parser_context: &mut ParserContext) -> Result<(), ThrushC
 
         let peeked: &Token = parser_context.peek();
 
-        if SYNC_STATEMENTS.contains(&peeked.kind) || SYNC_DECLARATIONS.contains(&peeked.kind) {
+        if SYNC_STATEMENTS.contains(&peeked.kind)
+            || SYNC_DECLARATIONS.contains(&peeked.kind)
+            || peeked.kind.is_error()
+        {
             break;
         }
 
@@ -92,6 +99,8 @@ fn sync_with_statement(parser_context: &mut ParserContext) -> Result<(), ThrushC
 }
 
 fn sync_with_expression(parser_context: &mut ParserContext) -> Result<(), ThrushCompilerIssue> {
+    self::skip_lexer_error(parser_context)?;
+
     loop {
         if parser_context.is_eof() {
             break;
@@ -108,7 +117,10 @@ fn sync_with_expression(parser_context: &mut ParserContext) -> Result<(), Thrush
 
         let peeked: &Token = parser_context.peek();
 
-        if SYNC_STATEMENTS.contains(&peeked.kind) || SYNC_DECLARATIONS.contains(&peeked.kind) {
+        if SYNC_STATEMENTS.contains(&peeked.kind)
+            || SYNC_DECLARATIONS.contains(&peeked.kind)
+            || peeked.kind.is_error()
+        {
             break;
         }
 
@@ -117,3 +129,13 @@ fn sync_with_expression(parser_context: &mut ParserContext) -> Result<(), Thrush
 
     Ok(())
 }
+
+/// The lexer already reported the invalid token the parse stopped at, so it is left
+/// behind; the synchronization stops at the next one.
+fn skip_lexer_error(parser_context: &mut ParserContext) -> Result<(), ThrushCompilerIssue> {
+    if parser_context.check(TokenType::Error) {
+        parser_context.only_advance()?;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, token::Token, tokentype::TokenType},
         parser::expr,
@@ -51,7 +51,7 @@ pub fn build_type(parser_context: &mut ParserContext<'_>) -> Result<Type, Thrush
                 ty if ty.is_void_type() => Ok(ty),
 
                 what_heck => Err(ThrushCompilerIssue::Error(
-                    String::from("Syntax error"),
+                    DiagnosticCode::SyntaxError,
                     format!("Expected type, not '{}'", what_heck),
                     None,
                     span,
@@ -88,7 +88,7 @@ pub fn build_type(parser_context: &mut ParserContext<'_>) -> Result<Type, Thrush
                     return Ok(custom_type_fields.get_type());
                 } else {
                     return Err(ThrushCompilerIssue::Error(
-                        String::from("Syntax error"),
+                        DiagnosticCode::SyntaxError,
                         format!("Not found type '{}'.", name),
                         None,
                         span,
@@ -97,7 +97,7 @@ pub fn build_type(parser_context: &mut ParserContext<'_>) -> Result<Type, Thrush
             }
 
             return Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 format!("Expected type, not '{}'", name),
                 None,
                 parser_context.previous().span,
@@ -105,7 +105,7 @@ pub fn build_type(parser_context: &mut ParserContext<'_>) -> Result<Type, Thrush
         }
 
         what_heck => Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             format!("Expected type, not '{}'", what_heck),
             None,
             parser_context.previous().span,
@@ -123,7 +123,7 @@ fn build_mut_type(
 
     if inner_type.is_mut_type() {
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             "Nested mutable type 'mut mut T' ins't allowed.".into(),
             None,
             span,
@@ -132,7 +132,7 @@ fn build_mut_type(
 
     if inner_type.is_ptr_type() {
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             "Mutable pointer type 'mut ptr<T>', or 'mut ptr' isn't allowed.".into(),
             None,
             span,
@@ -154,7 +154,7 @@ fn build_array_type(
 ) -> Result<Type, ThrushCompilerIssue> {
     parser_context.consume(
         TokenType::LBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '['."),
     )?;
 
@@ -163,7 +163,7 @@ fn build_array_type(
     if parser_context.check(TokenType::SemiColon) {
         parser_context.consume(
             TokenType::SemiColon,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected ';'."),
         )?;
 
@@ -171,7 +171,7 @@ fn build_array_type(
 
         if !size.is_integer() {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 "Expected integer value.".into(),
                 None,
                 span,
@@ -180,7 +180,7 @@ fn build_array_type(
 
         if !size.is_unsigned_integer()? || !size.is_lessu32bit_integer()? {
             return Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 "Expected any unsigned integer value less than or equal to 32 bits.".into(),
                 None,
                 span,
@@ -192,7 +192,7 @@ fn build_array_type(
         if let Ok(array_size) = u32::try_from(raw_array_size) {
             parser_context.consume(
                 TokenType::RBracket,
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected ']'."),
             )?;
 
@@ -200,7 +200,7 @@ fn build_array_type(
         }
 
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             "Expected any unsigned 32 bits integer value.".into(),
             None,
             span,
@@ -209,7 +209,7 @@ fn build_array_type(
 
     parser_context.consume(
         TokenType::RBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected ']'."),
     )?;
 
@@ -222,7 +222,7 @@ fn build_recursive_type(
 ) -> Result<Type, ThrushCompilerIssue> {
     parser_context.consume(
         TokenType::LBracket,
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         String::from("Expected '['."),
     )?;
 
@@ -235,7 +235,7 @@ fn build_recursive_type(
 
         parser_context.consume(
             TokenType::RBracket,
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             String::from("Expected ']'."),
         )?;
 
@@ -243,7 +243,7 @@ fn build_recursive_type(
     }
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Syntax error"),
+        DiagnosticCode::SyntaxError,
         format!("Expected pointer type, not '{}'", before_type),
         None,
         parser_context.previous().span,
@@ -1,10 +1,11 @@
 use ahash::AHashSet;
 
 use crate::{
+    backend::llvm::compiler::attributes::LLVMAttribute,
     core::{
-        compiler::options::CompilerFile,
+        compiler::sourcemap::SourceMap,
         console::logging::{self, LoggingType},
-        diagnostic::diagnostician::Diagnostician,
+        diagnostic::{codes::DiagnosticCode, diagnostician::Diagnostician},
         errors::standard::ThrushCompilerIssue,
     },
     frontend::types::{
@@ -28,13 +29,13 @@ pub struct AttributeChecker<'attr_checker> {
 impl<'attr_checker> AttributeChecker<'attr_checker> {
     pub fn new(
         ast: &'attr_checker [Ast<'attr_checker>],
-        file: &'attr_checker CompilerFile,
+        source_map: &'attr_checker SourceMap,
     ) -> Self {
         Self {
             ast,
             errors: Vec::with_capacity(100),
             currrent: 0,
-            dignostician: Diagnostician::new(file),
+            dignostician: Diagnostician::new(source_map),
         }
     }
 
@@ -74,22 +75,44 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
 
         if let Ast::Function {
             attributes,
+            parameters,
+            return_type,
             body,
             span,
             ..
         } = ast
         {
+            if let Some(test_span) = attributes.match_attr(LLVMAttributeComparator::Test) {
+                if !parameters.is_empty() || !return_type.is_void_type() {
+                    self.add_error(ThrushCompilerIssue::Error(
+                        DiagnosticCode::AttributeError,
+                        "Test functions cannot take parameters or return a value.".into(),
+                        None,
+                        test_span,
+                    ));
+                }
+
+                if body.is_null() || attributes.has_extern_attribute() {
+                    self.add_error(ThrushCompilerIssue::Error(
+                        DiagnosticCode::AttributeError,
+                        "Test functions must have a body and cannot be external.".into(),
+                        None,
+                        test_span,
+                    ));
+                }
+            }
+
             if !body.is_null() && attributes.has_extern_attribute() {
                 if let Some(span) = attributes.match_attr(LLVMAttributeComparator::Extern) {
                     self.add_error(ThrushCompilerIssue::Error(
-                        "Attribute error".into(),
+                        DiagnosticCode::AttributeError,
                         "External functions cannot have a body.".into(),
                         None,
                         span,
                     ));
                 } else {
                     self.add_error(ThrushCompilerIssue::Error(
-                        "Attribute error".into(),
+                        DiagnosticCode::AttributeError,
                         "External functions cannot have a body.".into(),
                         None,
                         *span,
@@ -143,7 +166,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
         {
             if !metadata.is_global() && attributes.has_public_attribute() {
                 self.add_error(ThrushCompilerIssue::Error(
-                    "Attribute error".into(),
+                    DiagnosticCode::AttributeError,
                     "Local constant cannot have public visibility.".into(),
                     None,
                     *span,
@@ -162,7 +185,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
         {
             if !metadata.is_global() && attributes.has_public_attribute() {
                 self.add_error(ThrushCompilerIssue::Error(
-                    "Attribute error".into(),
+                    DiagnosticCode::AttributeError,
                     "Local static cannot have public visibility.".into(),
                     None,
                     *span,
@@ -186,13 +209,15 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
         attributes: &'attr_checker ThrushAttributes,
         applicant: AttributeCheckerAttributeApplicant,
     ) {
+        self.analyze_allowed_lints(attributes);
+
         match applicant {
             AttributeCheckerAttributeApplicant::Function => {
                 let repeated_attrs: ThrushAttributes = self.get_repeated_attrs(attributes);
 
                 repeated_attrs.iter().for_each(|attr| {
                     self.add_error(ThrushCompilerIssue::Error(
-                        "Repeated attribute".into(),
+                        DiagnosticCode::RepeatedAttribute,
                         "Repetitive attributes are disallowed.".into(),
                         None,
                         attr.get_span(),
@@ -202,7 +227,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                 if attributes.has_extern_attribute() && !attributes.has_public_attribute() {
                     if let Some(span) = attributes.match_attr(LLVMAttributeComparator::Extern) {
                         self.add_error(ThrushCompilerIssue::Error(
-                            "Missing attribute".into(),
+                            DiagnosticCode::MissingAttribute,
                             "External functions always have public visibility. Add the '@public' attribute.".into(),
                             None,
                             span,
@@ -213,7 +238,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                 if !attributes.has_extern_attribute() && attributes.has_ignore_attribute() {
                     if let Some(span) = attributes.match_attr(LLVMAttributeComparator::Ignore) {
                         self.add_error(ThrushCompilerIssue::Error(
-                            "Attribute error".into(),
+                            DiagnosticCode::AttributeError,
                             "The @ignore attribute requires the function to be annotated with @extern(\"something\").".into(),
                             None,
                             span,
@@ -224,7 +249,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                 if attributes.has_inlinealways_attr() && attributes.has_inline_attr() {
                     if let Some(span) = attributes.match_attr(LLVMAttributeComparator::InlineHint) {
                         self.add_error(ThrushCompilerIssue::Error(
-                            "Illogical attribute".into(),
+                            DiagnosticCode::IllogicalAttribute,
                             "The attribute is not valid. Use either '@alwaysinline' or '@inline' attribute.".into(),
                             None,
                             span,
@@ -235,7 +260,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                 if attributes.has_inline_attr() && attributes.has_noinline_attr() {
                     if let Some(span) = attributes.match_attr(LLVMAttributeComparator::NoInline) {
                         self.add_error(ThrushCompilerIssue::Error(
-                            "Illogical attribute".into(),
+                            DiagnosticCode::IllogicalAttribute,
                             "The attribute is not valid. Use either '@noinline' or '@inline' attribute.".into(),
                             None,
                             span,
@@ -246,7 +271,7 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
                 if attributes.has_inlinealways_attr() && attributes.has_noinline_attr() {
                     if let Some(span) = attributes.match_attr(LLVMAttributeComparator::NoInline) {
                         self.add_error(ThrushCompilerIssue::Error(
-                            String::from("Illogical attribute"),
+                            DiagnosticCode::IllogicalAttribute,
                             String::from(
                                 "The attribute is not valid. Use either '@alwaysinline' or '@inline' attribute.",
                             ),
@@ -265,16 +290,42 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
 
                 repeated_attrs.iter().for_each(|attr| {
                     self.add_error(ThrushCompilerIssue::Error(
-                        String::from("Repeated attribute"),
+                        DiagnosticCode::RepeatedAttribute,
                         String::from("Repetitive attributes are disallowed."),
                         None,
                         attr.get_span(),
                     ));
                 });
+
+                if let Some(span) = attributes.match_attr(LLVMAttributeComparator::Test) {
+                    self.add_error(ThrushCompilerIssue::Error(
+                        DiagnosticCode::AttributeError,
+                        "The @test attribute only applies to functions.".into(),
+                        None,
+                        span,
+                    ));
+                }
             }
         }
     }
 
+    fn analyze_allowed_lints(&mut self, attributes: &'attr_checker ThrushAttributes) {
+        attributes.iter().for_each(|attr| match attr {
+            LLVMAttribute::Allow(lint, span) if !DiagnosticCode::is_known_lint(lint) => {
+                self.add_error(ThrushCompilerIssue::Error(
+                    DiagnosticCode::AttributeError,
+                    format!("Unknown lint '{}'.", lint),
+                    Some(String::from(
+                        "Use 'unused', 'warnings', a lint name like 'unused_local' or a warning code like 'W0004'.",
+                    )),
+                    *span,
+                ));
+            }
+
+            _ => (),
+        });
+    }
+
     fn get_repeated_attrs(
         &self,
         attributes: &'attr_checker ThrushAttributes,
@@ -282,11 +333,14 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
         let mut storage: AHashSet<LLVMAttributeComparator> = AHashSet::with_capacity(20);
         let mut repeated_attrs: ThrushAttributes = Vec::with_capacity(20);
 
-        attributes.iter().for_each(|attr| {
-            if !storage.insert(attr.into_llvm_attr_cmp()) {
-                repeated_attrs.push(*attr);
-            }
-        });
+        attributes
+            .iter()
+            .filter(|attr| !attr.is_allow_attribute())
+            .for_each(|attr| {
+                if !storage.insert(attr.into_llvm_attr_cmp()) {
+                    repeated_attrs.push(*attr);
+                }
+            });
 
         repeated_attrs
     }
@@ -295,6 +349,10 @@ impl<'attr_checker> AttributeChecker<'attr_checker> {
         self.errors.push(error);
     }
 
+    pub fn get_errors_count(&self) -> usize {
+        self.errors.len()
+    }
+
     fn advance(&mut self) {
         if !self.is_eof() {
             self.currrent += 1;
@@ -1,16 +1,19 @@
 use crate::{
     core::{
-        compiler::options::CompilerFile,
+        compiler::sourcemap::SourceMap,
         console::logging::{self, LoggingType},
-        diagnostic::diagnostician::Diagnostician,
+        diagnostic::{codes::DiagnosticCode, diagnostician::Diagnostician},
         errors::standard::ThrushCompilerIssue,
     },
-    frontend::types::{
-        ast::Ast,
-        parser::stmts::{traits::ThrushAttributesExtensions, types::ThrushAttributes},
-        semantic::linter::{
-            traits::LLVMAttributeComparatorExtensions,
-            types::{LLVMAttributeComparator, LinterAttributeApplicant},
+    frontend::{
+        semantic::lints::LintLevels,
+        types::{
+            ast::Ast,
+            parser::stmts::{traits::ThrushAttributesExtensions, types::ThrushAttributes},
+            semantic::linter::{
+                traits::LLVMAttributeComparatorExtensions,
+                types::{LLVMAttributeComparator, LinterAttributeApplicant},
+            },
         },
     },
 };
@@ -21,19 +24,27 @@ pub struct AttributesLinter<'attr_linter> {
     warnings: Vec<ThrushCompilerIssue>,
     current: usize,
     dignostician: Diagnostician,
+    lint_levels: LintLevels<'attr_linter>,
+    denied_lints: usize,
 }
 
 impl<'attr_linter> AttributesLinter<'attr_linter> {
-    pub fn new(ast: &'attr_linter [Ast<'attr_linter>], file: &'attr_linter CompilerFile) -> Self {
+    pub fn new(
+        ast: &'attr_linter [Ast<'attr_linter>],
+        source_map: &'attr_linter SourceMap,
+        lint_levels: LintLevels<'attr_linter>,
+    ) -> Self {
         Self {
             ast,
             warnings: Vec::with_capacity(100),
             current: 0,
-            dignostician: Diagnostician::new(file),
+            dignostician: Diagnostician::new(source_map),
+            lint_levels,
+            denied_lints: 0,
         }
     }
 
-    pub fn check(&mut self) {
+    pub fn check(&mut self) -> bool {
         while !self.is_eof() {
             let current_stmt: &Ast = self.peek();
 
@@ -42,10 +53,15 @@ impl<'attr_linter> AttributesLinter<'attr_linter> {
             self.advance();
         }
 
-        self.warnings.iter().for_each(|warn: &ThrushCompilerIssue| {
-            self.dignostician
-                .build_diagnostic(warn, LoggingType::Warning);
-        });
+        self.denied_lints = self
+            .lint_levels
+            .report(&mut self.dignostician, &self.warnings);
+
+        self.denied_lints > 0
+    }
+
+    pub fn get_denied_lints_count(&self) -> usize {
+        self.denied_lints
     }
 
     fn analyze_stmt(&mut self, stmt: &'attr_linter Ast) {
@@ -75,7 +91,7 @@ impl<'attr_linter> AttributesLinter<'attr_linter> {
                 {
                     if let Some(attr_span) = attributes.match_attr(LLVMAttributeComparator::Hot) {
                         self.add_warning(ThrushCompilerIssue::Warning(
-                            String::from("Possible undefined behavior"),
+                            DiagnosticCode::PossibleUndefinedBehavior,
                             String::from(
                                 "Excessive optimization of a function or method can result in undefined behavior in specific scenarios or make it unsuitable for intended use. Exercise caution to ensure compatibility and stability.",
                             ),
@@ -94,7 +110,7 @@ impl<'attr_linter> AttributesLinter<'attr_linter> {
 
                     organized_contrary_attrs.iter().for_each(|attr| {
                         self.add_warning(ThrushCompilerIssue::Warning(
-                            String::from("Not applicable attribute"),
+                            DiagnosticCode::NotApplicableAttribute,
                             String::from("This attribute isn't applicable."),
                             attr.get_span(),
                         ));
@@ -112,7 +128,9 @@ impl<'attr_linter> AttributesLinter<'attr_linter> {
         attributes
             .iter()
             .filter_map(|attr| match attr {
-                attr if attr.into_llvm_attr_cmp() != point_attr => Some(*attr),
+                attr if !attr.is_allow_attribute() && attr.into_llvm_attr_cmp() != point_attr => {
+                    Some(*attr)
+                }
                 _ => None,
             })
             .collect()
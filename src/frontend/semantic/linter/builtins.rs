@@ -32,6 +32,15 @@ pub fn analyze_builtin<'linter>(linter: &mut Linter<'linter>, builtin: &'linter
             linter.analyze_expr(size);
         }
 
+        Builtin::Assert { condition, .. } => {
+            linter.analyze_expr(condition);
+        }
+
+        Builtin::AssertEq { left, right, .. } => {
+            linter.analyze_expr(left);
+            linter.analyze_expr(right);
+        }
+
         _ => (),
     }
 }
@@ -4,14 +4,14 @@ use symbols::LinterSymbolsTable;
 
 use crate::{
     core::{
-        compiler::options::CompilerFile,
+        compiler::sourcemap::SourceMap,
         console::logging::{self, LoggingType},
-        diagnostic::diagnostician::Diagnostician,
+        diagnostic::{codes::DiagnosticCode, diagnostician::Diagnostician},
         errors::standard::ThrushCompilerIssue,
     },
     frontend::{
         lexer::span::Span,
-        semantic::linter::expressions::deref,
+        semantic::{linter::expressions::deref, lints::LintLevels},
         types::{ast::Ast, parser::stmts::traits::ThrushAttributesExtensions},
     },
 };
@@ -28,26 +28,37 @@ mod symbols;
 #[derive(Debug)]
 pub struct Linter<'linter> {
     ast: &'linter [Ast<'linter>],
+    declarations: &'linter [Ast<'linter>],
     current: usize,
     warnings: Vec<ThrushCompilerIssue>,
     bugs: Vec<ThrushCompilerIssue>,
     diagnostician: Diagnostician,
     symbols: LinterSymbolsTable<'linter>,
+    lint_levels: LintLevels<'linter>,
+    denied_lints: usize,
 }
 
 impl<'linter> Linter<'linter> {
-    pub fn new(ast: &'linter [Ast], file: &'linter CompilerFile) -> Self {
+    pub fn new(
+        ast: &'linter [Ast],
+        declarations: &'linter [Ast],
+        source_map: &'linter SourceMap,
+        lint_levels: LintLevels<'linter>,
+    ) -> Self {
         Self {
             ast,
+            declarations,
             current: 0,
             warnings: Vec::with_capacity(100),
             bugs: Vec::with_capacity(100),
-            diagnostician: Diagnostician::new(file),
+            diagnostician: Diagnostician::new(source_map),
             symbols: LinterSymbolsTable::new(),
+            lint_levels,
+            denied_lints: 0,
         }
     }
 
-    pub fn check(&mut self) {
+    pub fn check(&mut self) -> bool {
         self.forward_all();
 
         while !self.is_eof() {
@@ -64,10 +75,15 @@ impl<'linter> Linter<'linter> {
             self.diagnostician.build_diagnostic(bug, LoggingType::Bug);
         });
 
-        self.warnings.iter().for_each(|warn: &ThrushCompilerIssue| {
-            self.diagnostician
-                .build_diagnostic(warn, LoggingType::Warning);
-        });
+        self.denied_lints = self
+            .lint_levels
+            .report(&mut self.diagnostician, &self.warnings);
+
+        self.denied_lints > 0
+    }
+
+    pub fn get_denied_lints_count(&self) -> usize {
+        self.denied_lints
     }
 
     pub fn analyze_decl(&mut self, node: &'linter Ast) {
@@ -369,7 +385,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Local not used"),
+                        DiagnosticCode::UnusedLocal,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -377,7 +393,7 @@ impl<'linter> Linter<'linter> {
 
                 if !is_mutable_used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Mutable local not used"),
+                        DiagnosticCode::UnusedMutableLocal,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -392,7 +408,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Local constant not used"),
+                        DiagnosticCode::UnusedLocalConstant,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -408,7 +424,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Local Static not used"),
+                        DiagnosticCode::UnusedLocalStatic,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -416,7 +432,7 @@ impl<'linter> Linter<'linter> {
 
                 if !is_mutable_used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Local mutable static not used"),
+                        DiagnosticCode::UnusedLocalMutableStatic,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -431,7 +447,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("LLI not used"),
+                        DiagnosticCode::UnusedLLI,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -447,7 +463,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Parameter not used"),
+                        DiagnosticCode::UnusedParameter,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -455,7 +471,7 @@ impl<'linter> Linter<'linter> {
 
                 if !is_mutable_used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Mutable parameter not used"),
+                        DiagnosticCode::UnusedMutableParameter,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -474,7 +490,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        "Static not used".into(),
+                        DiagnosticCode::UnusedStatic,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -490,7 +506,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Constant not used"),
+                        DiagnosticCode::UnusedConstant,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -506,7 +522,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Function not used"),
+                        DiagnosticCode::UnusedFunction,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -522,7 +538,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Assembler function not used"),
+                        DiagnosticCode::UnusedAssemblerFunction,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -538,7 +554,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Enum not used"),
+                        DiagnosticCode::UnusedEnum,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -552,7 +568,7 @@ impl<'linter> Linter<'linter> {
 
                     if !used {
                         self.warnings.push(ThrushCompilerIssue::Warning(
-                            String::from("Enum field not used"),
+                            DiagnosticCode::UnusedEnumField,
                             format!("'{}' not used.", name),
                             span,
                         ));
@@ -569,7 +585,7 @@ impl<'linter> Linter<'linter> {
 
                 if !used {
                     self.warnings.push(ThrushCompilerIssue::Warning(
-                        String::from("Structure not used"),
+                        DiagnosticCode::UnusedStructure,
                         format!("'{}' not used.", name),
                         span,
                     ));
@@ -583,7 +599,7 @@ impl<'linter> Linter<'linter> {
 
                     if !used {
                         self.warnings.push(ThrushCompilerIssue::Warning(
-                            String::from("Structure field not used"),
+                            DiagnosticCode::UnusedStructureField,
                             format!("'{}' not used.", name),
                             span,
                         ));
@@ -595,6 +611,7 @@ impl<'linter> Linter<'linter> {
     pub fn forward_all(&mut self) {
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|ast| ast.is_static())
             .for_each(|ast| {
                 if let Ast::Static {
@@ -611,6 +628,7 @@ impl<'linter> Linter<'linter> {
 
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|ast| ast.is_constant())
             .for_each(|ast| {
                 if let Ast::Const { name, span, .. } = ast {
@@ -620,6 +638,7 @@ impl<'linter> Linter<'linter> {
 
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|stmt| stmt.is_struct())
             .for_each(|stmt| {
                 if let Ast::Struct {
@@ -649,6 +668,7 @@ impl<'linter> Linter<'linter> {
 
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|stmt| stmt.is_enum())
             .for_each(|stmt| {
                 if let Ast::Enum {
@@ -672,6 +692,7 @@ impl<'linter> Linter<'linter> {
 
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|stmt| stmt.is_function())
             .for_each(|stmt| {
                 if let Ast::Function {
@@ -681,13 +702,20 @@ impl<'linter> Linter<'linter> {
                     ..
                 } = stmt
                 {
-                    self.symbols
-                        .new_function(name, (*span, attributes.has_public_attribute()));
+                    // Test functions are called by the test harness.
+                    self.symbols.new_function(
+                        name,
+                        (
+                            *span,
+                            attributes.has_public_attribute() || attributes.has_test_attribute(),
+                        ),
+                    );
                 }
             });
 
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|stmt| stmt.is_asm_function())
             .for_each(|stmt| {
                 if let Ast::AssemblerFunction {
@@ -0,0 +1,191 @@
+use crate::{
+    backend::llvm::compiler::attributes::LLVMAttribute,
+    core::{
+        compiler::lints::{LintLevel, LintsConfiguration},
+        console::logging::LoggingType,
+        diagnostic::{codes::DiagnosticCode, diagnostician::Diagnostician},
+        errors::standard::ThrushCompilerIssue,
+    },
+    frontend::{
+        lexer::span::Span,
+        types::{ast::Ast, parser::stmts::types::ThrushAttributes},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct LintLevels<'lints> {
+    configuration: &'lints LintsConfiguration,
+    allowed: Vec<(Span, &'lints str)>,
+}
+
+impl<'lints> LintLevels<'lints> {
+    pub fn new(ast: &'lints [Ast<'lints>], configuration: &'lints LintsConfiguration) -> Self {
+        let mut lint_levels: LintLevels = Self {
+            configuration,
+            allowed: Vec::with_capacity(10),
+        };
+
+        ast.iter()
+            .for_each(|node| lint_levels.collect_allowed(node));
+
+        lint_levels
+    }
+
+    /// Resolves the level of a warning. An '@allow' on an enclosing declaration
+    /// always wins over the command line flags.
+    pub fn get_level(&self, code: DiagnosticCode, span: Span) -> LintLevel {
+        let allowed: bool = self.allowed.iter().any(|(region, lint)| {
+            code.matches_lint(lint)
+                && region.get_file() == span.get_file()
+                && region.get_span_start() <= span.get_span_start()
+                && region.get_span_end() >= span.get_span_end()
+        });
+
+        if allowed {
+            return LintLevel::Allow;
+        }
+
+        self.configuration.get_level(code)
+    }
+
+    /// Prints the warnings at their resolved level and returns how many of them were denied.
+    pub fn report(
+        &self,
+        diagnostician: &mut Diagnostician,
+        warnings: &[ThrushCompilerIssue],
+    ) -> usize {
+        let mut denied: usize = 0;
+
+        warnings.iter().for_each(|warn| {
+            let level: LintLevel = match warn {
+                ThrushCompilerIssue::Warning(code, _, span) => self.get_level(*code, *span),
+                _ => LintLevel::Warn,
+            };
+
+            match level {
+                LintLevel::Allow => (),
+                LintLevel::Warn => diagnostician.build_diagnostic(warn, LoggingType::Warning),
+                LintLevel::Deny => {
+                    denied += 1;
+                    diagnostician.build_diagnostic(warn, LoggingType::Error);
+                }
+            }
+        });
+
+        denied
+    }
+
+    fn collect_allowed(&mut self, node: &'lints Ast) {
+        match node {
+            Ast::Function {
+                attributes,
+                body,
+                span,
+                ..
+            } => {
+                let mut region: Span = self.merge_attributes(*span, attributes);
+
+                if !body.is_null() {
+                    region = region.merge(body.get_span());
+                }
+
+                self.add_allowed(region, attributes);
+                self.collect_allowed(body);
+            }
+
+            Ast::Struct {
+                attributes,
+                fields,
+                span,
+                ..
+            } => {
+                let region: Span = fields
+                    .1
+                    .iter()
+                    .fold(self.merge_attributes(*span, attributes), |region, field| {
+                        region.merge(field.3)
+                    });
+
+                self.add_allowed(region, attributes);
+            }
+
+            Ast::Enum {
+                attributes,
+                fields,
+                span,
+                ..
+            } => {
+                let region: Span = fields
+                    .iter()
+                    .fold(self.merge_attributes(*span, attributes), |region, field| {
+                        region.merge(field.1.get_span())
+                    });
+
+                self.add_allowed(region, attributes);
+            }
+
+            Ast::AssemblerFunction {
+                attributes, span, ..
+            }
+            | Ast::Const {
+                attributes, span, ..
+            }
+            | Ast::Static {
+                attributes, span, ..
+            }
+            | Ast::Local {
+                attributes, span, ..
+            } => {
+                let region: Span = self.merge_attributes(*span, attributes);
+                self.add_allowed(region, attributes);
+            }
+
+            Ast::EntryPoint { body, .. } => self.collect_allowed(body),
+
+            Ast::Block { stmts, .. } => {
+                stmts.iter().for_each(|stmt| self.collect_allowed(stmt));
+            }
+
+            Ast::If {
+                block,
+                elseif,
+                anyway,
+                ..
+            } => {
+                self.collect_allowed(block);
+
+                elseif.iter().for_each(|elif| self.collect_allowed(elif));
+
+                if let Some(otherwise) = anyway {
+                    self.collect_allowed(otherwise);
+                }
+            }
+
+            Ast::Elif { block, .. }
+            | Ast::Else { block, .. }
+            | Ast::While { block, .. }
+            | Ast::Loop { block, .. } => self.collect_allowed(block),
+
+            Ast::For { local, block, .. } => {
+                self.collect_allowed(local);
+                self.collect_allowed(block);
+            }
+
+            _ => (),
+        }
+    }
+
+    fn merge_attributes(&self, span: Span, attributes: &ThrushAttributes) -> Span {
+        attributes
+            .iter()
+            .fold(span, |region, attr| region.merge(attr.get_span()))
+    }
+
+    fn add_allowed(&mut self, region: Span, attributes: &ThrushAttributes<'lints>) {
+        attributes.iter().for_each(|attr| {
+            if let LLVMAttribute::Allow(lint, _) = attr {
+                self.allowed.push((region, lint));
+            }
+        });
+    }
+}
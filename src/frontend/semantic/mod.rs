@@ -1,11 +1,16 @@
 use attrchecker::AttributeChecker;
 use linter::{Linter, attributes::AttributesLinter};
+use lints::LintLevels;
 use typechecker::TypeChecker;
 
-use crate::{core::compiler::options::CompilerFile, frontend::types::ast::Ast};
+use crate::{
+    core::compiler::{lints::LintsConfiguration, sourcemap::SourceMap},
+    frontend::types::ast::Ast,
+};
 
 pub mod attrchecker;
 pub mod linter;
+pub mod lints;
 pub mod typechecker;
 
 pub struct SemanticAnalyzer<'semantic_analyzer> {
@@ -18,12 +23,50 @@ pub struct SemanticAnalyzer<'semantic_analyzer> {
 impl<'semantic_analyzer> SemanticAnalyzer<'semantic_analyzer> {
     pub fn new(
         ast: &'semantic_analyzer [Ast<'semantic_analyzer>],
-        file: &'semantic_analyzer CompilerFile,
+        source_map: &'semantic_analyzer SourceMap,
+        lints_configuration: &'semantic_analyzer LintsConfiguration,
     ) -> Self {
-        let type_checker: TypeChecker = TypeChecker::new(ast, file);
-        let attr_checker: AttributeChecker = AttributeChecker::new(ast, file);
-        let linter: Linter = Linter::new(ast, file);
-        let attr_linter: AttributesLinter = AttributesLinter::new(ast, file);
+        Self::create(ast, &[], None, source_map, lints_configuration)
+    }
+
+    /// Checks a snippet of a REPL session against the declarations checked before,
+    /// which are only declared again. The locals of earlier snippets that the entry
+    /// declares again were assigned by those snippets.
+    pub fn with_declarations(
+        ast: &'semantic_analyzer [Ast<'semantic_analyzer>],
+        declarations: &'semantic_analyzer [Ast<'semantic_analyzer>],
+        repl_entry: &'semantic_analyzer str,
+        source_map: &'semantic_analyzer SourceMap,
+        lints_configuration: &'semantic_analyzer LintsConfiguration,
+    ) -> Self {
+        Self::create(
+            ast,
+            declarations,
+            Some(repl_entry),
+            source_map,
+            lints_configuration,
+        )
+    }
+
+    fn create(
+        ast: &'semantic_analyzer [Ast<'semantic_analyzer>],
+        declarations: &'semantic_analyzer [Ast<'semantic_analyzer>],
+        repl_entry: Option<&'semantic_analyzer str>,
+        source_map: &'semantic_analyzer SourceMap,
+        lints_configuration: &'semantic_analyzer LintsConfiguration,
+    ) -> Self {
+        let lint_levels: LintLevels = LintLevels::new(ast, lints_configuration);
+
+        let type_checker: TypeChecker = TypeChecker::new(
+            ast,
+            declarations,
+            repl_entry,
+            source_map,
+            lint_levels.clone(),
+        );
+        let attr_checker: AttributeChecker = AttributeChecker::new(ast, source_map);
+        let linter: Linter = Linter::new(ast, declarations, source_map, lint_levels.clone());
+        let attr_linter: AttributesLinter = AttributesLinter::new(ast, source_map, lint_levels);
 
         Self {
             type_checker,
@@ -39,8 +82,10 @@ impl<'semantic_analyzer> SemanticAnalyzer<'semantic_analyzer> {
             let attr_checker_throw_errors: bool = self.attr_checker.check();
 
             if !type_checker_throw_errors && !attr_checker_throw_errors && !parser_throwed_errors {
-                self.linter.check();
-                self.attr_linter.check();
+                let linter_denied_lints: bool = self.linter.check();
+                let attr_linter_denied_lints: bool = self.attr_linter.check();
+
+                return linter_denied_lints || attr_linter_denied_lints;
             }
 
             return type_checker_throw_errors || attr_checker_throw_errors || parser_throwed_errors;
@@ -48,4 +93,11 @@ impl<'semantic_analyzer> SemanticAnalyzer<'semantic_analyzer> {
 
         true
     }
+
+    pub fn get_errors_count(&self) -> usize {
+        self.type_checker.get_errors_count()
+            + self.attr_checker.get_errors_count()
+            + self.linter.get_denied_lints_count()
+            + self.attr_linter.get_denied_lints_count()
+    }
 }
@@ -1,8 +1,10 @@
 use crate::{
     backend::llvm::compiler::builtins::Builtin,
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
-        lexer::span::Span, semantic::typechecker::TypeChecker, types::ast::Ast,
+        lexer::span::Span,
+        semantic::typechecker::{TypeChecker, checks, metadata::TypeCheckerExprMetadata},
+        types::ast::Ast,
         typesystem::types::Type,
     },
 };
@@ -30,10 +32,72 @@ pub fn validate_builtin<'type_checker>(
             size,
         } => self::validate_memcpy(typechecker, destination, source, size),
 
+        Builtin::Assert { condition, .. } => self::validate_assert(typechecker, condition),
+
+        Builtin::AssertEq { left, right, .. } => self::validate_assert_eq(typechecker, left, right),
+
         Builtin::Halloc { .. } | Builtin::AlignOf { .. } => Ok(()),
     }
 }
 
+pub fn validate_assert<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    condition: &'type_checker Ast,
+) -> Result<(), ThrushCompilerIssue> {
+    let span: Span = condition.get_span();
+
+    let metadata: TypeCheckerExprMetadata =
+        TypeCheckerExprMetadata::new(condition.is_literal(), None, span);
+
+    if let Err(error) = checks::type_check(
+        &Type::Bool,
+        condition.get_value_type()?,
+        Some(condition),
+        None,
+        metadata,
+    ) {
+        typechecker.add_error(error);
+    }
+
+    typechecker.analyze_stmt(condition)?;
+
+    Ok(())
+}
+
+pub fn validate_assert_eq<'type_checker>(
+    typechecker: &mut TypeChecker<'type_checker>,
+    left: &'type_checker Ast,
+    right: &'type_checker Ast,
+) -> Result<(), ThrushCompilerIssue> {
+    let left_type: &Type = left.get_value_type()?;
+    let right_type: &Type = right.get_value_type()?;
+
+    if !left_type.is_integer_type()
+        && !left_type.is_float_type()
+        && !left_type.is_bool_type()
+        && !left_type.is_ptr_type()
+    {
+        typechecker.add_error(ThrushCompilerIssue::Error(
+            DiagnosticCode::TypeError,
+            "Expected integer, float, boolean or raw pointer 'ptr' value.".into(),
+            None,
+            left.get_span(),
+        ));
+    }
+
+    let metadata: TypeCheckerExprMetadata =
+        TypeCheckerExprMetadata::new(right.is_literal(), None, right.get_span());
+
+    if let Err(error) = checks::type_check(left_type, right_type, Some(right), None, metadata) {
+        typechecker.add_error(error);
+    }
+
+    typechecker.analyze_stmt(left)?;
+    typechecker.analyze_stmt(right)?;
+
+    Ok(())
+}
+
 pub fn validate_memmove<'type_checker>(
     typechecker: &mut TypeChecker<'type_checker>,
     destination: &'type_checker Ast,
@@ -50,7 +114,7 @@ pub fn validate_memmove<'type_checker>(
 
     if !source_type.is_ptr_type() && !source_type.is_address_type() && !source_type.is_mut_type() {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected raw typed pointer 'ptr[T]', raw pointer 'ptr', memory address 'addr', or high-level pointer 'mut T' type.".into(),
             None,
             source_span,
@@ -62,7 +126,7 @@ pub fn validate_memmove<'type_checker>(
         && !destination_type.is_mut_type()
     {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected raw typed pointer 'ptr[T]', raw pointer 'ptr', memory address 'addr', or high-level pointer 'mut T' type."
                 .into(),
             None,
@@ -72,7 +136,7 @@ pub fn validate_memmove<'type_checker>(
 
     if !size.is_unsigned_integer()? {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected unsigned integer value.".into(),
             None,
             size_span,
@@ -102,7 +166,7 @@ pub fn validate_memcpy<'type_checker>(
 
     if !source_type.is_ptr_type() && !source_type.is_address_type() && !source_type.is_mut_type() {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected raw typed pointer 'ptr[T]', raw pointer 'ptr', memory address 'addr', or high-level pointer 'mut T' type.".into(),
             None,
             source_span,
@@ -114,7 +178,7 @@ pub fn validate_memcpy<'type_checker>(
         && !destination_type.is_mut_type()
     {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected raw typed pointer 'ptr[T]', raw pointer 'ptr', memory address 'addr', or high-level pointer 'mut T' type.".into(),
             None,
             destination_span,
@@ -123,7 +187,7 @@ pub fn validate_memcpy<'type_checker>(
 
     if !size.is_unsigned_integer()? {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected unsigned integer value.".into(),
             None,
             size_span,
@@ -154,7 +218,7 @@ pub fn validate_memset<'type_checker>(
         && !destination_type.is_mut_type()
     {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected raw typed pointer 'ptr[T]', raw pointer 'ptr', memory address 'addr', or high-level pointer 'mut T' type.".into(),
             None,
             destination_span,
@@ -163,7 +227,7 @@ pub fn validate_memset<'type_checker>(
 
     if !new_size.is_unsigned_integer()? {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected unsigned integer value.".into(),
             None,
             new_size_span,
@@ -172,7 +236,7 @@ pub fn validate_memset<'type_checker>(
 
     if !size.is_unsigned_integer()? {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             "Expected unsigned integer value.".into(),
             None,
             size_span,
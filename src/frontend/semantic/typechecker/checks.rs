@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, tokentype::TokenType},
         semantic::typechecker::metadata::TypeCheckerExprMetadata,
@@ -18,7 +18,7 @@ pub fn type_check(
     let span: Span = metadata.get_span();
 
     let error: ThrushCompilerIssue = ThrushCompilerIssue::Error(
-        "Mismatched types".into(),
+        DiagnosticCode::MismatchedTypes,
         format!("Expected '{}' but found '{}'.", lhs, rhs),
         None,
         span,
@@ -109,7 +109,7 @@ pub fn type_check(
                 .is_some_and(|position| position.at_local()) =>
         {
             Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Memory aliasing isn't allowed at high-level pointers.".into(),
                 None,
                 span,
@@ -387,7 +387,7 @@ pub fn check_type_cast(
 
     let abort_cast = || {
         Err(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             format!("Cannot cast '{}' to '{}'.", from_type, cast_type),
             None,
             *span,
@@ -1,6 +1,14 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
-    frontend::{lexer::span::Span, semantic::typechecker::TypeChecker, types::ast::Ast},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
+    frontend::{
+        lexer::span::Span,
+        semantic::typechecker::{TypeChecker, escape, flow},
+        types::ast::Ast,
+        typesystem::types::Type,
+    },
 };
 
 pub fn validate<'type_checker>(
@@ -13,14 +21,8 @@ pub fn validate<'type_checker>(
                 typechecker.add_error(type_error);
             }
 
-            if !body.has_return_for_function() {
-                typechecker.add_error(ThrushCompilerIssue::Error(
-                    "Type error".into(),
-                    "Expected return with type 'u32'.".into(),
-                    None,
-                    *span,
-                ));
-            }
+            flow::check_function(typechecker, body, &Type::U32, *span, false);
+            escape::check_function(typechecker, &[], body);
 
             Ok(())
         }
@@ -31,7 +33,7 @@ pub fn validate<'type_checker>(
             parameters.iter().try_for_each(|parameter| {
                 if parameter.get_value_type()?.is_void_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "The void type isn't a value.".into(),
                         None,
                         *span,
@@ -45,6 +47,7 @@ pub fn validate<'type_checker>(
         }
 
         Ast::Function {
+            ascii_name,
             parameters,
             body,
             return_type,
@@ -54,7 +57,7 @@ pub fn validate<'type_checker>(
             parameters.iter().try_for_each(|parameter| {
                 if parameter.get_any_type()?.is_void_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "The void type isn't a value.".into(),
                         None,
                         *span,
@@ -69,14 +72,11 @@ pub fn validate<'type_checker>(
                     typechecker.add_error(type_error);
                 }
 
-                if !body.has_return_for_function() && !return_type.is_void_type() {
-                    typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
-                        format!("Expected return with type '{}'.", return_type),
-                        None,
-                        *span,
-                    ));
-                }
+                // The body of a REPL entry declares again the locals of the earlier snippets.
+                let replays_bindings: bool = typechecker.get_repl_entry() == Some(*ascii_name);
+
+                flow::check_function(typechecker, body, return_type, *span, replays_bindings);
+                escape::check_function(typechecker, parameters, body);
             }
 
             Ok(())
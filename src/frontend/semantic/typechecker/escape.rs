@@ -0,0 +1,316 @@
+use ahash::AHashMap as HashMap;
+
+use crate::{
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
+    frontend::{
+        lexer::span::Span,
+        semantic::typechecker::TypeChecker,
+        types::{
+            ast::{Ast, types::AstEitherExpression},
+            parser::stmts::{sites::AllocationSite, traits::ThrushAttributesExtensions},
+        },
+    },
+};
+
+/// Where a name of a function body keeps its value.
+#[derive(Debug, Clone, Copy)]
+enum Storage {
+    /// A local or a parameter, with the stack memory that holds it, if any, and the stack
+    /// memory its pointer value comes from, if any.
+    Local {
+        stack: Option<Span>,
+        points_to: Option<Span>,
+    },
+
+    /// A static declared inside the function, which outlives it.
+    Static,
+}
+
+/// Follows where the pointers of a function body come from, to report the pointers to
+/// its stack memory that are returned or stored in a static.
+struct EscapeChecker<'checker, 'type_checker, 'escape> {
+    typechecker: &'checker mut TypeChecker<'type_checker>,
+    scopes: Vec<HashMap<&'escape str, Storage>>,
+}
+
+pub fn check_function<'escape>(
+    typechecker: &mut TypeChecker,
+    parameters: &'escape [Ast<'escape>],
+    body: &'escape Ast<'escape>,
+) {
+    let mut checker: EscapeChecker = EscapeChecker {
+        typechecker,
+        scopes: Vec::with_capacity(10),
+    };
+
+    checker.scopes.push(
+        parameters
+            .iter()
+            .filter_map(|parameter| match parameter {
+                Ast::FunctionParameter { name, span, .. } => Some((
+                    *name,
+                    Storage::Local {
+                        stack: Some(*span),
+                        points_to: None,
+                    },
+                )),
+                _ => None,
+            })
+            .collect(),
+    );
+
+    checker.check_stmt(body);
+}
+
+impl<'escape> EscapeChecker<'_, '_, 'escape> {
+    fn check_stmt(&mut self, node: &'escape Ast<'escape>) {
+        match node {
+            Ast::Block { stmts, .. } => {
+                self.scopes.push(HashMap::with_capacity(10));
+                stmts.iter().for_each(|stmt| self.check_stmt(stmt));
+                self.scopes.pop();
+            }
+
+            Ast::Local {
+                name,
+                value,
+                attributes,
+                span,
+                ..
+            } => {
+                let storage: Storage = Storage::Local {
+                    stack: (!attributes.has_heap_attr()).then_some(*span),
+                    points_to: self.get_origin(value),
+                };
+
+                self.declare(name, storage);
+            }
+
+            Ast::Static { name, .. } | Ast::Const { name, .. } => {
+                self.declare(name, Storage::Static);
+            }
+
+            Ast::If {
+                block,
+                elseif,
+                anyway,
+                ..
+            } => {
+                let mut branches: Vec<&Ast> = Vec::with_capacity(elseif.len() + 2);
+
+                branches.push(block);
+
+                elseif.iter().for_each(|elif| {
+                    if let Ast::Elif { block, .. } = elif {
+                        branches.push(block);
+                    }
+                });
+
+                match anyway.as_deref() {
+                    Some(Ast::Else { block, .. }) => {
+                        branches.push(block);
+                        self.check_branches(&branches, false);
+                    }
+
+                    _ => self.check_branches(&branches, true),
+                }
+            }
+
+            // The body of a loop may never run.
+            Ast::While { block, .. } | Ast::Loop { block, .. } => {
+                self.check_branches(&[block], true);
+            }
+
+            Ast::For { local, block, .. } => {
+                self.scopes.push(HashMap::with_capacity(1));
+
+                self.check_stmt(local);
+                self.check_branches(&[block], true);
+
+                self.scopes.pop();
+            }
+
+            Ast::Return {
+                expression: Some(expression),
+                span,
+                ..
+            } => {
+                if let Some(allocation) = self.get_origin(expression) {
+                    self.report(
+                        "A pointer to the stack memory of the function is returned.",
+                        *span,
+                        allocation,
+                    );
+                }
+            }
+
+            Ast::Mut {
+                source,
+                value,
+                span,
+                ..
+            } => {
+                let origin: Option<Span> = self.get_origin(value);
+
+                match &**source {
+                    Ast::Reference { name, .. } => match self.get_storage(name) {
+                        Some(Storage::Local { stack, .. }) => {
+                            self.assign(name, stack, origin);
+                        }
+
+                        _ => self.check_store(origin, *span),
+                    },
+
+                    Ast::Property { source, .. } | Ast::Index { source, .. }
+                        if !self.is_local_source(source) =>
+                    {
+                        self.check_store(origin, *span);
+                    }
+
+                    _ => (),
+                }
+            }
+
+            Ast::Write {
+                source,
+                write_value,
+                span,
+                ..
+            } if !self.is_local_source(source) => {
+                self.check_store(self.get_origin(write_value), *span);
+            }
+
+            _ => (),
+        }
+    }
+
+    /// Checks each branch from the current state, and keeps every pointer a branch leaves
+    /// pointing to the stack. With 'may_skip', none of the branches may run.
+    fn check_branches(&mut self, branches: &[&'escape Ast<'escape>], may_skip: bool) {
+        let entry: Vec<HashMap<&str, Storage>> = self.scopes.clone();
+
+        let mut exits: Vec<Vec<HashMap<&str, Storage>>> = Vec::with_capacity(branches.len() + 1);
+
+        if may_skip {
+            exits.push(entry.clone());
+        }
+
+        branches.iter().for_each(|branch| {
+            self.scopes = entry.clone();
+            self.check_stmt(branch);
+
+            exits.push(std::mem::take(&mut self.scopes));
+        });
+
+        self.scopes = entry;
+
+        self.scopes
+            .iter_mut()
+            .enumerate()
+            .for_each(|(depth, scope)| {
+                scope.iter_mut().for_each(|(name, storage)| {
+                    if let Storage::Local { points_to, .. } = storage {
+                        *points_to = exits
+                            .iter()
+                            .filter_map(|exit| match exit[depth].get(name) {
+                                Some(Storage::Local { points_to, .. }) => *points_to,
+                                _ => None,
+                            })
+                            .next();
+                    }
+                });
+            });
+    }
+
+    /// The stack allocation a pointer value comes from.
+    fn get_origin(&self, expression: &Ast) -> Option<Span> {
+        match expression {
+            Ast::Alloc {
+                site_allocation: AllocationSite::Stack,
+                span,
+                ..
+            } => Some(*span),
+
+            Ast::Address { source, .. } => match source {
+                (Some((name, _)), _) => match self.get_storage(name) {
+                    Some(Storage::Local { stack, .. }) => stack,
+                    _ => None,
+                },
+
+                (_, Some(expression)) => self.get_origin(expression),
+
+                _ => None,
+            },
+
+            Ast::Reference { name, .. } => match self.get_storage(name) {
+                Some(Storage::Local { points_to, .. }) => points_to,
+                _ => None,
+            },
+
+            Ast::As { from, .. } => self.get_origin(from),
+            Ast::Group { expression, .. } => self.get_origin(expression),
+
+            _ => None,
+        }
+    }
+
+    fn check_store(&mut self, origin: Option<Span>, span: Span) {
+        if let Some(allocation) = origin {
+            self.report(
+                "A pointer to the stack memory of the function is stored outside of it.",
+                span,
+                allocation,
+            );
+        }
+    }
+
+    fn report(&mut self, message: &str, span: Span, allocation: Span) {
+        self.typechecker
+            .add_error(ThrushCompilerIssue::LabeledError(
+                DiagnosticCode::DanglingStackPointer,
+                String::from(message),
+                Some(String::from(
+                    "The stack memory is released when the function returns. Allocate it with 'alloc @heap' or 'alloc @static' instead.",
+                )),
+                span,
+                Box::new([(allocation, String::from("stack memory allocated here"))]),
+            ));
+    }
+
+    fn is_local_source(&self, source: &AstEitherExpression) -> bool {
+        match source {
+            (Some((name, _)), _) => {
+                matches!(self.get_storage(name), Some(Storage::Local { .. }))
+            }
+
+            _ => true,
+        }
+    }
+}
+
+impl<'escape> EscapeChecker<'_, '_, 'escape> {
+    fn declare(&mut self, name: &'escape str, storage: Storage) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, storage);
+        }
+    }
+
+    fn assign(&mut self, name: &str, stack: Option<Span>, points_to: Option<Span>) {
+        if let Some(storage) = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+        {
+            *storage = Storage::Local { stack, points_to };
+        }
+    }
+
+    fn get_storage(&self, name: &str) -> Option<Storage> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .copied()
+    }
+}
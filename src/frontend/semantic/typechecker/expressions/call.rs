@@ -1,6 +1,6 @@
 use {
     crate::{
-        core::errors::standard::ThrushCompilerIssue,
+        core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
         frontend::{
             lexer::span::Span,
             semantic::typechecker::{TypeChecker, checks, metadata::TypeCheckerExprMetadata},
@@ -30,7 +30,7 @@ pub fn validate<'type_checker>(
 
     if required_size != provided_size && !ignore_more_arguments {
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             format!(
                 "Expected arguments total '{}', not '{}'.",
                 required_size, provided_size
@@ -40,7 +40,7 @@ pub fn validate<'type_checker>(
         ));
 
         typechecker.add_error(ThrushCompilerIssue::Error(
-            "Type error".into(),
+            DiagnosticCode::TypeError,
             format!("Arguments were expected in the order: '{}'.", types_display),
             None,
             *span,
@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span, semantic::typechecker::TypeChecker, types::ast::Ast,
         typesystem::types::Type,
@@ -18,7 +21,7 @@ pub fn validate<'type_checker>(
             if !value_type.is_ptr_type() && !value_type.is_mut_type() && !value_type.is_const_type()
             {
                 typechecker.add_error(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "Expected raw typed pointer 'ptr[T]', raw pointer 'ptr', high-level pointer 'mut T' type, or constant 'const T', for dereference."
                         .into(),
                     None,
@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         semantic::typechecker::TypeChecker,
@@ -27,7 +30,7 @@ pub fn validate<'type_checker>(
 
                 if !reference.is_allocated() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "An assigned value was expected, such as ptr[T], ptr, addr, or high-level pointer mut T.".into(),
                         None,
                         *span,
@@ -39,7 +42,7 @@ pub fn validate<'type_checker>(
                 if reference_type.is_ptr_type() {
                     if !reference_type.is_typed_ptr_type() {
                         typechecker.add_error(ThrushCompilerIssue::Error(
-                            "Type error".into(),
+                            DiagnosticCode::TypeError,
                             "Expected raw typed pointer ptr[T].".into(),
                             None,
                             *span,
@@ -48,7 +51,7 @@ pub fn validate<'type_checker>(
 
                     if reference_type.is_typed_ptr_type() && reference_type.is_all_ptr_type() {
                         typechecker.add_error(ThrushCompilerIssue::Error(
-                            "Type error".into(),
+                            DiagnosticCode::TypeError,
                             "A raw typed pointer type was expected, with a typed internal type."
                                 .into(),
                             None,
@@ -63,7 +66,7 @@ pub fn validate<'type_checker>(
                     && !reference_type.is_fixed_array_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected deep type, array, or fixed array.".into(),
                         None,
                         *span,
@@ -77,7 +80,7 @@ pub fn validate<'type_checker>(
                 if expr_type.is_ptr_type() {
                     if !expr_type.is_typed_ptr_type() {
                         typechecker.add_error(ThrushCompilerIssue::Error(
-                            "Type error".into(),
+                            DiagnosticCode::TypeError,
                             "Expected raw typed pointer ptr[T].".into(),
                             None,
                             *span,
@@ -86,7 +89,7 @@ pub fn validate<'type_checker>(
 
                     if expr_type.is_typed_ptr_type() && expr_type.is_all_ptr_type() {
                         typechecker.add_error(ThrushCompilerIssue::Error(
-                            "Type error".into(),
+                            DiagnosticCode::TypeError,
                             "A raw typed pointer type was expected, with a typed internal type."
                                 .into(),
                             None,
@@ -101,7 +104,7 @@ pub fn validate<'type_checker>(
                     && !expr_type.is_fixed_array_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected deep type, array, or fixed array.".into(),
                         None,
                         *span,
@@ -112,7 +115,7 @@ pub fn validate<'type_checker>(
             indexes.iter().try_for_each(|indexe| {
                 if !indexe.is_unsigned_integer()? {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected any unsigned integer value.".into(),
                         None,
                         *span,
@@ -6,7 +6,10 @@ mod index;
 mod property;
 
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         semantic::typechecker::{
@@ -64,7 +67,7 @@ pub fn validate<'type_checker>(
             if operator.is_plus_plus_operator() || operator.is_minus_minus_operator() {
                 if !expression.is_reference() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected a reference.".into(),
                         None,
                         *span,
@@ -73,7 +76,7 @@ pub fn validate<'type_checker>(
 
                 if !expression.is_mutable() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected a mutable reference.".into(),
                         None,
                         *span,
@@ -99,7 +102,7 @@ pub fn validate<'type_checker>(
         Ast::FixedArray { items, kind, span } => {
             if kind.is_void_type() {
                 return Err(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "An element is expected.".into(),
                     None,
                     *span,
@@ -135,7 +138,7 @@ pub fn validate<'type_checker>(
         } => {
             if kind.is_void_type() {
                 return Err(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "An element is expected.".into(),
                     None,
                     *span,
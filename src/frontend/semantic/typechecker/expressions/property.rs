@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         semantic::typechecker::TypeChecker,
@@ -28,7 +31,7 @@ pub fn validate<'type_checker>(
                     && !reference_type.is_ptr_struct_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "A structure type was expected within the high-level pointer 'mut T', the raw typed pointer 'mut T', or a structure 'struct T'.".into(),
                         None,
                         reference_span,
@@ -49,7 +52,7 @@ pub fn validate<'type_checker>(
                     && !expr_type.is_ptr_struct_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "A structure type was expected within the high-level pointer 'mut T', the raw typed pointer 'mut T', or a structure 'struct T'.".into(),
                         None,
                         expr_span,
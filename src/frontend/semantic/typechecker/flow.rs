@@ -0,0 +1,356 @@
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+
+use crate::{
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
+    frontend::{
+        lexer::span::Span,
+        semantic::typechecker::TypeChecker,
+        types::ast::{Ast, types::AstEitherExpression},
+        typesystem::types::Type,
+    },
+};
+
+/// What is known at a point of a function body.
+#[derive(Debug, Clone)]
+struct FlowState<'flow> {
+    reachable: bool,
+    assigned: HashSet<&'flow str>,
+}
+
+impl FlowState<'_> {
+    /// The state where two paths join: a local is assigned only if it is on both paths.
+    fn merge(self, other: Self) -> Self {
+        match (self.reachable, other.reachable) {
+            (false, _) => other,
+            (_, false) => self,
+            _ => Self {
+                reachable: true,
+                assigned: self
+                    .assigned
+                    .intersection(&other.assigned)
+                    .copied()
+                    .collect(),
+            },
+        }
+    }
+
+    fn unreachable() -> Self {
+        Self {
+            reachable: false,
+            assigned: HashSet::new(),
+        }
+    }
+}
+
+/// Follows the paths of a function body to report the missing returns, the reads of
+/// locals declared without a value before they are assigned, and the unreachable
+/// statements.
+struct FlowChecker<'checker, 'type_checker, 'flow> {
+    typechecker: &'checker mut TypeChecker<'type_checker>,
+    scopes: Vec<HashMap<&'flow str, bool>>,
+    breaks: Vec<FlowState<'flow>>,
+    replays_bindings: bool,
+}
+
+pub fn check_function<'flow>(
+    typechecker: &mut TypeChecker,
+    body: &'flow Ast<'flow>,
+    return_type: &Type,
+    span: Span,
+    replays_bindings: bool,
+) {
+    let mut checker: FlowChecker = FlowChecker {
+        typechecker,
+        scopes: Vec::with_capacity(10),
+        breaks: Vec::with_capacity(10),
+        replays_bindings,
+    };
+
+    let mut state: FlowState = FlowState {
+        reachable: true,
+        assigned: HashSet::with_capacity(10),
+    };
+
+    checker.check_stmt(body, &mut state);
+
+    if state.reachable && !return_type.is_void_type() {
+        checker.typechecker.add_error(ThrushCompilerIssue::Error(
+            DiagnosticCode::MissingReturn,
+            format!(
+                "Expected return with type '{}' at the end of every path.",
+                return_type
+            ),
+            Some(String::from(
+                "Add a 'return' to the paths that reach the end of the function.",
+            )),
+            span,
+        ));
+    }
+}
+
+impl<'flow> FlowChecker<'_, '_, 'flow> {
+    fn check_stmt(&mut self, node: &'flow Ast<'flow>, state: &mut FlowState<'flow>) {
+        match node {
+            Ast::Block { stmts, .. } => self.check_block(stmts, state),
+
+            Ast::Local {
+                name,
+                value,
+                metadata,
+                ..
+            } => {
+                if !metadata.is_undefined() {
+                    self.check_expr(value, state);
+                }
+
+                // The locals of earlier REPL snippets, replayed in the body of the entry,
+                // were assigned by those snippets.
+                let is_replayed: bool = self.replays_bindings && self.scopes.len() == 1;
+
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.insert(name, metadata.is_undefined() && !is_replayed);
+                }
+
+                state.assigned.remove(name);
+            }
+
+            Ast::If {
+                condition,
+                block,
+                elseif,
+                anyway,
+                ..
+            } => {
+                self.check_expr(condition, state);
+
+                let mut joined: FlowState = self.check_branch(block, state);
+
+                elseif.iter().for_each(|elif| {
+                    if let Ast::Elif {
+                        condition, block, ..
+                    } = elif
+                    {
+                        self.check_expr(condition, state);
+                        joined = joined.clone().merge(self.check_branch(block, state));
+                    }
+                });
+
+                *state = match anyway.as_deref() {
+                    Some(Ast::Else { block, .. }) => joined.merge(self.check_branch(block, state)),
+                    _ => joined.merge(state.clone()),
+                };
+            }
+
+            Ast::While { cond, block, .. } => {
+                self.check_expr(cond, state);
+
+                let breaks: FlowState = self.check_loop_body(block, state);
+
+                // A 'while true' is only left through its 'break' statements.
+                *state = match &**cond {
+                    Ast::Boolean { value: 1, .. } => breaks,
+                    _ => state.clone().merge(breaks),
+                };
+            }
+
+            Ast::Loop { block, .. } => {
+                *state = self.check_loop_body(block, state);
+            }
+
+            Ast::For {
+                local,
+                cond,
+                actions,
+                block,
+                ..
+            } => {
+                self.scopes.push(HashMap::with_capacity(1));
+
+                self.check_stmt(local, state);
+                self.check_expr(cond, state);
+
+                self.breaks.push(FlowState::unreachable());
+
+                let mut body: FlowState = state.clone();
+
+                self.check_stmt(block, &mut body);
+                self.check_expr(actions, &mut body);
+
+                let breaks: FlowState = self.breaks.pop().unwrap_or_else(FlowState::unreachable);
+
+                self.scopes.pop();
+
+                *state = state.clone().merge(breaks);
+            }
+
+            Ast::Return { expression, .. } => {
+                if let Some(expression) = expression {
+                    self.check_expr(expression, state);
+                }
+
+                state.reachable = false;
+            }
+
+            Ast::Break { .. } => {
+                if let Some(breaks) = self.breaks.pop() {
+                    self.breaks.push(breaks.merge(state.clone()));
+                }
+
+                state.reachable = false;
+            }
+
+            Ast::Continue { .. } => state.reachable = false,
+
+            _ => self.check_expr(node, state),
+        }
+    }
+
+    fn check_block(&mut self, stmts: &'flow [Ast<'flow>], state: &mut FlowState<'flow>) {
+        let assigned_before: HashSet<&str> = state.assigned.clone();
+
+        // Only the first unreachable statement of a block is reported.
+        let mut reported: bool = !state.reachable;
+
+        self.scopes.push(HashMap::with_capacity(10));
+
+        stmts.iter().for_each(|stmt| {
+            if !state.reachable && !reported {
+                self.typechecker.add_warning(ThrushCompilerIssue::Warning(
+                    DiagnosticCode::UnreachableCode,
+                    String::from("Unreachable statement."),
+                    stmt.get_span(),
+                ));
+
+                reported = true;
+            }
+
+            self.check_stmt(stmt, state);
+        });
+
+        // The locals of the block shadowed the outer ones with the same name.
+        if let Some(scope) = self.scopes.pop() {
+            scope.keys().for_each(|name| {
+                if assigned_before.contains(name) {
+                    state.assigned.insert(name);
+                } else {
+                    state.assigned.remove(name);
+                }
+            });
+        }
+    }
+
+    fn check_branch(
+        &mut self,
+        block: &'flow Ast<'flow>,
+        state: &FlowState<'flow>,
+    ) -> FlowState<'flow> {
+        let mut branch: FlowState = state.clone();
+
+        self.check_stmt(block, &mut branch);
+
+        branch
+    }
+
+    /// The state after a loop body, joined from its 'break' statements.
+    ///
+    /// The body may run any number of times, so it starts from the state before the loop.
+    fn check_loop_body(
+        &mut self,
+        block: &'flow Ast<'flow>,
+        state: &FlowState<'flow>,
+    ) -> FlowState<'flow> {
+        self.breaks.push(FlowState::unreachable());
+
+        self.check_branch(block, state);
+
+        self.breaks.pop().unwrap_or_else(FlowState::unreachable)
+    }
+
+    fn check_expr(&mut self, node: &'flow Ast<'flow>, state: &mut FlowState<'flow>) {
+        match node {
+            Ast::Reference { name, span, .. } => {
+                if state.reachable && self.is_unassigned(name, state) {
+                    self.typechecker.add_error(ThrushCompilerIssue::Error(
+                        DiagnosticCode::UnassignedLocal,
+                        format!("'{}' is read before a value is assigned to it.", name),
+                        Some(String::from(
+                            "Assign it on every path that reaches this read.",
+                        )),
+                        *span,
+                    ));
+
+                    // Reported once, at its first read.
+                    state.assigned.insert(name);
+                }
+            }
+
+            Ast::Mut { source, value, .. } => {
+                self.check_expr(value, state);
+                self.check_assignment(source, state);
+            }
+
+            // The local may be assigned through its address.
+            Ast::Address {
+                source, indexes, ..
+            } => {
+                self.assign_source(source, state);
+
+                indexes
+                    .iter()
+                    .for_each(|index| self.check_expr(index, state));
+            }
+
+            _ => node
+                .get_children()
+                .into_iter()
+                .for_each(|child| self.check_expr(child, state)),
+        }
+    }
+
+    fn check_assignment(&mut self, target: &'flow Ast<'flow>, state: &mut FlowState<'flow>) {
+        match target {
+            Ast::Reference { name, .. } => {
+                state.assigned.insert(name);
+            }
+
+            Ast::Property { source, .. } => self.assign_source(source, state),
+
+            Ast::Index {
+                source, indexes, ..
+            } => {
+                self.assign_source(source, state);
+
+                indexes
+                    .iter()
+                    .for_each(|index| self.check_expr(index, state));
+            }
+
+            _ => self.check_expr(target, state),
+        }
+    }
+
+    fn assign_source(
+        &mut self,
+        source: &'flow AstEitherExpression<'flow>,
+        state: &mut FlowState<'flow>,
+    ) {
+        if let Some((name, _)) = &source.0 {
+            state.assigned.insert(name);
+        }
+
+        if let Some(expression) = &source.1 {
+            self.check_assignment(expression, state);
+        }
+    }
+
+    fn is_unassigned(&self, name: &str, state: &FlowState) -> bool {
+        let declared_without_value: bool = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .is_some_and(|undefined| *undefined);
+
+        declared_without_value && !state.assigned.contains(name)
+    }
+}
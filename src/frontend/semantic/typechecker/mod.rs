@@ -2,16 +2,22 @@ use symbols::TypeCheckerSymbolsTable;
 
 use crate::{
     core::{
-        compiler::options::CompilerFile, console::logging::LoggingType,
-        diagnostic::diagnostician::Diagnostician, errors::standard::ThrushCompilerIssue,
+        compiler::sourcemap::SourceMap, console::logging::LoggingType,
+        diagnostic::codes::DiagnosticCode, diagnostic::diagnostician::Diagnostician,
+        errors::standard::ThrushCompilerIssue,
+    },
+    frontend::{
+        semantic::lints::LintLevels,
+        types::{ast::Ast, parser::stmts::traits::ThrushAttributesExtensions},
     },
-    frontend::types::{ast::Ast, parser::stmts::traits::ThrushAttributesExtensions},
 };
 
 mod builtins;
 mod checks;
 mod declarations;
+mod escape;
 mod expressions;
+mod flow;
 mod metadata;
 mod position;
 mod statements;
@@ -21,27 +27,38 @@ mod validations;
 #[derive(Debug)]
 pub struct TypeChecker<'type_checker> {
     ast: &'type_checker [Ast<'type_checker>],
+    declarations: &'type_checker [Ast<'type_checker>],
+    repl_entry: Option<&'type_checker str>,
     position: usize,
     bugs: Vec<ThrushCompilerIssue>,
     errors: Vec<ThrushCompilerIssue>,
     warnings: Vec<ThrushCompilerIssue>,
     symbols: TypeCheckerSymbolsTable<'type_checker>,
     diagnostician: Diagnostician,
+    lint_levels: LintLevels<'type_checker>,
+    denied_lints: usize,
 }
 
 impl<'type_checker> TypeChecker<'type_checker> {
     pub fn new(
         ast: &'type_checker [Ast<'type_checker>],
-        file: &'type_checker CompilerFile,
+        declarations: &'type_checker [Ast<'type_checker>],
+        repl_entry: Option<&'type_checker str>,
+        source_map: &'type_checker SourceMap,
+        lint_levels: LintLevels<'type_checker>,
     ) -> Self {
         Self {
             ast,
+            declarations,
+            repl_entry,
             position: 0,
             bugs: Vec::with_capacity(100),
             errors: Vec::with_capacity(100),
             warnings: Vec::with_capacity(100),
             symbols: TypeCheckerSymbolsTable::new(),
-            diagnostician: Diagnostician::new(file),
+            diagnostician: Diagnostician::new(source_map),
+            lint_levels,
+            denied_lints: 0,
         }
     }
 
@@ -58,10 +75,9 @@ impl<'type_checker> TypeChecker<'type_checker> {
             self.advance();
         }
 
-        self.warnings.iter().for_each(|warn| {
-            self.diagnostician
-                .build_diagnostic(warn, LoggingType::Warning);
-        });
+        self.denied_lints = self
+            .lint_levels
+            .report(&mut self.diagnostician, &self.warnings);
 
         if !self.errors.is_empty() || !self.bugs.is_empty() {
             self.bugs.iter().for_each(|warn| {
@@ -76,7 +92,7 @@ impl<'type_checker> TypeChecker<'type_checker> {
             return true;
         }
 
-        false
+        self.denied_lints > 0
     }
 
     pub fn analyze_decl(&mut self, node: &'type_checker Ast) -> Result<(), ThrushCompilerIssue> {
@@ -374,7 +390,7 @@ impl<'type_checker> TypeChecker<'type_checker> {
         if let Ast::SizeOf { sizeof, span, .. } = node {
             if sizeof.is_void_type() {
                 self.add_error(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "The void type isn't a value.".into(),
                     None,
                     *span,
@@ -414,6 +430,7 @@ impl<'type_checker> TypeChecker<'type_checker> {
     pub fn declare_forward(&mut self) {
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|stmt| stmt.is_asm_function())
             .for_each(|stmt| {
                 if let Ast::AssemblerFunction {
@@ -430,6 +447,7 @@ impl<'type_checker> TypeChecker<'type_checker> {
 
         self.ast
             .iter()
+            .chain(self.declarations)
             .filter(|stmt| stmt.is_function())
             .for_each(|stmt| {
                 if let Ast::Function {
@@ -472,6 +490,15 @@ impl TypeChecker<'_> {
     pub fn add_bug(&mut self, error: ThrushCompilerIssue) {
         self.bugs.push(error);
     }
+
+    pub fn get_errors_count(&self) -> usize {
+        self.errors.len() + self.bugs.len() + self.denied_lints
+    }
+
+    /// The entry function of the REPL snippet being checked, if any.
+    pub fn get_repl_entry(&self) -> Option<&str> {
+        self.repl_entry
+    }
 }
 
 impl TypeChecker<'_> {
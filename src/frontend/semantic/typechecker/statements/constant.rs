@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         semantic::typechecker::{TypeChecker, checks, metadata::TypeCheckerExprMetadata},
@@ -27,7 +30,7 @@ pub fn validate<'type_checker>(
 
             if !value.is_llvm_constant_value() {
                 return Err(ThrushCompilerIssue::Error(
-                    "Syntax error".into(),
+                    DiagnosticCode::SyntaxError,
                     "Expected compile-time sized value.".into(),
                     None,
                     expression_span,
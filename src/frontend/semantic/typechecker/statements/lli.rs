@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         semantic::typechecker::{TypeChecker, checks, metadata::TypeCheckerExprMetadata},
@@ -29,7 +32,7 @@ pub fn validate<'type_checker>(
 
             if lli_type.is_void_type() {
                 typechecker.add_error(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "The void type isn't a value.".into(),
                     None,
                     *span,
@@ -58,7 +61,7 @@ pub fn validate<'type_checker>(
 
                 if !reference_type.is_ptr_type() && !reference_type.is_address_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected 'ptr<T>', 'ptr', or 'addr' type.".into(),
                         None,
                         span,
@@ -74,7 +77,7 @@ pub fn validate<'type_checker>(
 
                 if !expr_type.is_ptr_type() && !expr_type.is_address_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected 'ptr<T>', 'ptr' or 'addr' type.".into(),
                         None,
                         span,
@@ -101,7 +104,7 @@ pub fn validate<'type_checker>(
 
                 if !reference_type.is_ptr_type() && !reference_type.is_address_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected 'ptr<T>', 'ptr', or 'addr' type.".into(),
                         None,
                         span,
@@ -110,7 +113,7 @@ pub fn validate<'type_checker>(
 
                 if reference_type.is_ptr_type() && !reference_type.is_typed_ptr_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected raw typed pointer ptr<T>.".into(),
                         None,
                         span,
@@ -121,7 +124,7 @@ pub fn validate<'type_checker>(
                     && !reference_type.is_ptr_fixed_array_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected raw typed pointer type with deep type 'struct T', or 'array[T; N]'."
                             .into(),
                         None,
@@ -131,7 +134,7 @@ pub fn validate<'type_checker>(
 
                 if reference_type.is_address_type() {
                     typechecker.add_warning(ThrushCompilerIssue::Warning(
-                        "Undefined behavior".into(), 
+                        DiagnosticCode::UndefinedBehavior, 
                         "*Maybe* this value at runtime causes undefined behavior because it is anything at runtime, and memory calculation needs valid pointers or deep types.".into(), 
                        span
                     ));
@@ -144,7 +147,7 @@ pub fn validate<'type_checker>(
 
                 if !expr_type.is_ptr_type() && !expr_type.is_address_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected 'ptr<T>', 'ptr', or 'addr' type.".into(),
                         None,
                         span,
@@ -153,7 +156,7 @@ pub fn validate<'type_checker>(
 
                 if expr_type.is_ptr_type() && !expr_type.is_typed_ptr_type() {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected raw typed pointer ptr<T>.".into(),
                         None,
                         span,
@@ -164,7 +167,7 @@ pub fn validate<'type_checker>(
                     && !expr_type.is_ptr_fixed_array_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected raw typed pointer type with deep type 'struct T', or 'array[T; N]'.".into(),
                         None,
                         span,
@@ -173,7 +176,7 @@ pub fn validate<'type_checker>(
 
                 if expr_type.is_address_type() {
                     typechecker.add_warning(ThrushCompilerIssue::Warning(
-                        "Undefined behavior".into(), 
+                        DiagnosticCode::UndefinedBehavior, 
                         "*Maybe* this value at runtime causes undefined behavior because it is anything at runtime, and memory calculation needs valid pointers or deep types.".into(), 
                         span
                     ));
@@ -183,7 +186,7 @@ pub fn validate<'type_checker>(
             indexes.iter().try_for_each(|indexe| {
                 if !indexe.is_unsigned_integer()? {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected any unsigned integer value.".into(),
                         None,
                         *span,
@@ -212,7 +215,7 @@ pub fn validate<'type_checker>(
                     && !reference_type.is_mut_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected 'ptr<T>', 'ptr', 'addr', or 'mut T' type.".into(),
                         None,
                         span,
@@ -229,7 +232,7 @@ pub fn validate<'type_checker>(
                     && !expr_type.is_mut_type()
                 {
                     typechecker.add_error(ThrushCompilerIssue::Error(
-                        "Type error".into(),
+                        DiagnosticCode::TypeError,
                         "Expected 'ptr<T>', 'ptr', 'addr', or 'mut T' type.".into(),
                         None,
                         span,
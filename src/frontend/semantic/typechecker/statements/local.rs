@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         semantic::typechecker::{
@@ -35,7 +38,7 @@ pub fn validate<'type_checker>(
 
             if local_type.is_void_type() {
                 typechecker.add_error(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "The void type isn't a value.".into(),
                     None,
                     *span,
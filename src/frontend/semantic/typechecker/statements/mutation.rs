@@ -1,5 +1,8 @@
 use crate::{
-    core::errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    core::{
+        diagnostic::codes::DiagnosticCode,
+        errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
+    },
     frontend::{
         lexer::span::Span,
         semantic::typechecker::{TypeChecker, checks, metadata::TypeCheckerExprMetadata},
@@ -27,7 +30,7 @@ pub fn validate<'type_checker>(
 
             if !source.is_allocated() && !source_type.is_ptr_type() && !source_type.is_mut_type() {
                 typechecker.add_error(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "Expected raw typed pointer 'ptr[T]', raw pointer 'ptr', or high-level pointer 'mut T' type."
                         .into(),
                     None,
@@ -37,7 +40,7 @@ pub fn validate<'type_checker>(
 
             if !source.is_mutable() {
                 typechecker.add_error(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "The reference must be marked as mutable.".into(),
                     None,
                     source.get_span(),
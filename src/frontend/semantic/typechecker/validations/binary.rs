@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, tokentype::TokenType},
         typesystem::types::Type,
@@ -38,7 +38,7 @@ fn validate_binary_gate(
     }
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Mismatched Types"),
+        DiagnosticCode::MismatchedTypes,
         format!("'{} {} {}' isn't allowed.", a, op, b),
         None,
         span,
@@ -60,7 +60,7 @@ fn validate_binary_shift(
     }
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Mismatched Types"),
+        DiagnosticCode::MismatchedTypes,
         format!("'{} {} {}' is not allowed.", a, op, b),
         None,
         span,
@@ -84,7 +84,7 @@ fn validate_binary_comparasion(
     }
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Mismatched Types"),
+        DiagnosticCode::MismatchedTypes,
         format!("'{} {} {}' isn't allowed.", a, op, b),
         None,
         span,
@@ -128,7 +128,7 @@ fn validate_binary_equality(
     }
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Mismatched Types"),
+        DiagnosticCode::MismatchedTypes,
         format!("'{} {} {}' isn't allowed.", a, op, b),
         None,
         span,
@@ -164,7 +164,7 @@ fn validate_binary_arithmetic(
         (Type::F32 | Type::F64, Type::F32 | Type::F64) => Ok(()),
 
         _ => Err(ThrushCompilerIssue::Error(
-            String::from("Mismatched Types"),
+            DiagnosticCode::MismatchedTypes,
             format!("'{} {} {}' isn't allowed.", a, op, b),
             None,
             span,
@@ -1,5 +1,5 @@
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::{span::Span, tokentype::TokenType},
         typesystem::types::Type,
@@ -32,7 +32,7 @@ fn validate_general_unary(
     }
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Mismatched Types"),
+        DiagnosticCode::MismatchedTypes,
         format!("'{}' with '{}' isn't allowed.", operator, a),
         None,
         span,
@@ -45,7 +45,7 @@ fn validate_unary_bang(a: &Type, span: Span) -> Result<(), ThrushCompilerIssue>
     }
 
     Err(ThrushCompilerIssue::Error(
-        String::from("Mismatched Types"),
+        DiagnosticCode::MismatchedTypes,
         format!("'!{}' isn't allowed.", a),
         None,
         span,
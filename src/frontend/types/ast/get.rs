@@ -1,9 +1,15 @@
 use crate::{
+    backend::llvm::compiler::builtins::Builtin,
     core::{
         console::logging::{self, LoggingType},
+        diagnostic::codes::DiagnosticCode,
         errors::{position::CompilationPosition, standard::ThrushCompilerIssue},
     },
-    frontend::{lexer::span::Span, types::ast::Ast, typesystem::types::Type},
+    frontend::{
+        lexer::span::Span,
+        types::ast::{Ast, types::AstEitherExpression},
+        typesystem::types::Type,
+    },
 };
 
 impl Ast<'_> {
@@ -144,7 +150,7 @@ impl Ast<'_> {
             Ast::Pass { .. } => Ok(&Type::Void),
 
             _ => Err(ThrushCompilerIssue::Error(
-                String::from("Syntax error"),
+                DiagnosticCode::SyntaxError,
                 String::from("Expected a value to get a type."),
                 None,
                 self.get_span(),
@@ -306,7 +312,7 @@ impl Ast<'_> {
             }
 
             return Err(ThrushCompilerIssue::Error(
-                "Syntax error".into(),
+                DiagnosticCode::SyntaxError,
                 "Expected string literal.".into(),
                 None,
                 span,
@@ -314,7 +320,7 @@ impl Ast<'_> {
         }
 
         Err(ThrushCompilerIssue::Error(
-            "Syntax error".into(),
+            DiagnosticCode::SyntaxError,
             "Expected string literal.".into(),
             None,
             span,
@@ -335,3 +341,189 @@ impl Ast<'_> {
         ))
     }
 }
+
+impl<'ctx> Ast<'ctx> {
+    /// The nodes directly nested in this one, in source order.
+    pub fn get_children(&self) -> Vec<&Ast<'ctx>> {
+        let mut children: Vec<&Ast<'ctx>> = Vec::with_capacity(4);
+
+        match self {
+            Ast::FixedArray { items, .. } | Ast::Array { items, .. } => children.extend(items),
+
+            Ast::Index {
+                source, indexes, ..
+            }
+            | Ast::Address {
+                source, indexes, ..
+            } => {
+                self::push_source(&mut children, source);
+                children.extend(indexes);
+            }
+
+            Ast::Property { source, .. } | Ast::Load { source, .. } => {
+                self::push_source(&mut children, source);
+            }
+
+            Ast::Write {
+                source,
+                write_value,
+                ..
+            } => {
+                self::push_source(&mut children, source);
+                children.push(write_value);
+            }
+
+            Ast::Constructor { args, .. } => {
+                children.extend(args.iter().map(|(_, value, ..)| value));
+            }
+
+            Ast::If {
+                condition,
+                block,
+                elseif,
+                anyway,
+                ..
+            } => {
+                children.push(condition);
+                children.push(block);
+                children.extend(elseif);
+                children.extend(anyway.as_deref());
+            }
+
+            Ast::Elif {
+                condition, block, ..
+            } => {
+                children.push(condition);
+                children.push(block);
+            }
+
+            Ast::For {
+                local,
+                cond,
+                actions,
+                block,
+                ..
+            } => {
+                children.push(local);
+                children.push(cond);
+                children.push(actions);
+                children.push(block);
+            }
+
+            Ast::While { cond, block, .. } => {
+                children.push(cond);
+                children.push(block);
+            }
+
+            Ast::Else { block, .. } | Ast::Loop { block, .. } => children.push(block),
+
+            Ast::Block { stmts, .. } => children.extend(stmts),
+
+            Ast::Enum { fields, .. } => children.extend(fields.iter().map(|(_, value)| value)),
+
+            Ast::EntryPoint { body, .. } => children.push(body),
+
+            Ast::AssemblerFunction { parameters, .. } => children.extend(parameters),
+
+            Ast::Function {
+                parameters, body, ..
+            } => {
+                children.extend(parameters);
+                children.push(body);
+            }
+
+            Ast::Return { expression, .. } => children.extend(expression.as_deref()),
+
+            Ast::EnumValue { value, .. }
+            | Ast::Static { value, .. }
+            | Ast::Const { value, .. }
+            | Ast::Local { value, .. }
+            | Ast::LLI { value, .. }
+            | Ast::Deref { value, .. } => children.push(value),
+
+            Ast::Mut { source, value, .. } => {
+                children.push(source);
+                children.push(value);
+            }
+
+            Ast::As { from, .. } => children.push(from),
+
+            Ast::Call { args, .. } | Ast::AsmValue { args, .. } => children.extend(args),
+
+            Ast::BinaryOp { left, right, .. } => {
+                children.push(left);
+                children.push(right);
+            }
+
+            Ast::UnaryOp { expression, .. } | Ast::Group { expression, .. } => {
+                children.push(expression);
+            }
+
+            Ast::Builtin { builtin, .. } => match builtin {
+                Builtin::MemCpy {
+                    source,
+                    destination,
+                    size,
+                }
+                | Builtin::MemMove {
+                    source,
+                    destination,
+                    size,
+                } => {
+                    children.push(source);
+                    children.push(destination);
+                    children.push(size);
+                }
+
+                Builtin::MemSet {
+                    destination,
+                    new_size,
+                    size,
+                } => {
+                    children.push(destination);
+                    children.push(new_size);
+                    children.push(size);
+                }
+
+                Builtin::Assert { condition, .. } => children.push(condition),
+
+                Builtin::AssertEq { left, right, .. } => {
+                    children.push(left);
+                    children.push(right);
+                }
+
+                Builtin::Halloc { .. } | Builtin::AlignOf { .. } => (),
+            },
+
+            Ast::Str { .. }
+            | Ast::Char { .. }
+            | Ast::Boolean { .. }
+            | Ast::Integer { .. }
+            | Ast::Float { .. }
+            | Ast::GlobalAssembler { .. }
+            | Ast::NullPtr { .. }
+            | Ast::Struct { .. }
+            | Ast::Continue { .. }
+            | Ast::Break { .. }
+            | Ast::AssemblerFunctionParameter { .. }
+            | Ast::FunctionParameter { .. }
+            | Ast::Reference { .. }
+            | Ast::Alloc { .. }
+            | Ast::SizeOf { .. }
+            | Ast::Pass { .. }
+            | Ast::Null { .. } => (),
+        }
+
+        children
+    }
+}
+
+fn push_source<'a, 'ctx>(children: &mut Vec<&'a Ast<'ctx>>, source: &'a AstEitherExpression<'ctx>) {
+    if let Some((_, reference)) = &source.0 {
+        children.push(reference);
+    }
+
+    if let Some(expression) = &source.1 {
+        children.push(expression);
+    }
+}
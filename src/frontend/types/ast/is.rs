@@ -1,7 +1,10 @@
 use crate::{
     core::errors::standard::ThrushCompilerIssue,
     frontend::{
-        types::ast::{Ast, traits::LLVMAstExtensions},
+        types::{
+            ast::{Ast, traits::LLVMAstExtensions},
+            parser::stmts::traits::ThrushAttributesExtensions,
+        },
         typesystem::types::Type,
     },
 };
@@ -32,6 +35,11 @@ impl Ast<'_> {
         matches!(self, Ast::Function { .. })
     }
 
+    #[inline]
+    pub fn is_test_function(&self) -> bool {
+        matches!(self, Ast::Function { attributes, .. } if attributes.has_test_attribute())
+    }
+
     #[inline]
     pub fn is_asm_function(&self) -> bool {
         matches!(self, Ast::AssemblerFunction { .. })
@@ -105,14 +113,6 @@ impl Ast<'_> {
         self.is_return()
     }
 
-    pub fn has_return_for_function(&self) -> bool {
-        if let Ast::Block { stmts, .. } = self {
-            return stmts.iter().any(|stmt| stmt.has_return());
-        }
-
-        self.is_return()
-    }
-
     pub fn has_break(&self) -> bool {
         if let Ast::Block { stmts, .. } = self {
             return stmts.iter().any(|stmt| stmt.has_break());
@@ -26,7 +26,7 @@ use crate::{
             },
             parser::stmts::{
                 sites::AllocationSite,
-                types::{Constructor, EnumFields, StructFields, ThrushAttributes},
+                types::{Constructor, EnumFields, StructFields, ThrushAttributes, ThrushDocs},
             },
         },
         typesystem::types::Type,
@@ -106,6 +106,7 @@ pub enum Ast<'ctx> {
         kind: Type,
         span: Span,
         attributes: ThrushAttributes<'ctx>,
+        docs: ThrushDocs<'ctx>,
     },
 
     Constructor {
@@ -178,6 +179,7 @@ pub enum Ast<'ctx> {
         name: &'ctx str,
         fields: EnumFields<'ctx>,
         attributes: ThrushAttributes<'ctx>,
+        docs: ThrushDocs<'ctx>,
         span: Span,
     },
     EnumValue {
@@ -219,6 +221,7 @@ pub enum Ast<'ctx> {
         body: Rc<Ast<'ctx>>,
         return_type: Type,
         attributes: ThrushAttributes<'ctx>,
+        docs: ThrushDocs<'ctx>,
         span: Span,
     },
     FunctionParameter {
@@ -242,6 +245,7 @@ pub enum Ast<'ctx> {
         kind: Type,
         value: Rc<Ast<'ctx>>,
         attributes: ThrushAttributes<'ctx>,
+        docs: ThrushDocs<'ctx>,
         metadata: StaticMetadata,
         span: Span,
     },
@@ -253,6 +257,7 @@ pub enum Ast<'ctx> {
         kind: Type,
         value: Rc<Ast<'ctx>>,
         attributes: ThrushAttributes<'ctx>,
+        docs: ThrushDocs<'ctx>,
         metadata: ConstantMetadata,
         span: Span,
     },
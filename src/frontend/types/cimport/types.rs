@@ -0,0 +1,60 @@
+use crate::frontend::lexer::span::Span;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CTokenKind {
+    Identifier(String),
+    Number(String),
+    Str(String),
+    Char(String),
+    Punctuation(&'static str),
+    Directive(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct CToken {
+    pub kind: CTokenKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CType {
+    Void,
+    Bool,
+    Char,
+    Integer(bool, u32),
+    Float,
+    Double,
+    Pointer,
+    Struct(String),
+    Enum,
+    Array(Box<CType>, u32),
+    Unsupported(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum CDeclaration {
+    Function {
+        name: String,
+        parameters: Vec<(Option<String>, CType)>,
+        return_type: CType,
+        variadic: bool,
+    },
+
+    Struct {
+        name: String,
+        fields: Vec<(String, CType)>,
+    },
+
+    Enum {
+        name: String,
+        values: Vec<(String, i128)>,
+    },
+
+    Constant {
+        name: String,
+        value: i128,
+    },
+}
+
+pub type CTokens = Vec<CToken>;
+pub type CDeclarations = Vec<(CDeclaration, Span)>;
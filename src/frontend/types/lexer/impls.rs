@@ -51,6 +51,7 @@ impl std::fmt::Display for TokenType {
             TokenType::Stack => write!(f, "@stack"),
             TokenType::Static => write!(f, "@static"),
             TokenType::Heap => write!(f, "@heap"),
+            TokenType::Allow => write!(f, "@allow"),
             TokenType::AlwaysInline => write!(f, "@alwaysinline"),
             TokenType::AsmAlignStack => write!(f, "@asmalignstack"),
             TokenType::AsmSyntax => write!(f, "@asmsyntax"),
@@ -61,6 +62,7 @@ impl std::fmt::Display for TokenType {
             TokenType::Hot => write!(f, "@hot"),
             TokenType::Ignore => write!(f, "@ignore"),
             TokenType::Import => write!(f, "@import"),
+            TokenType::CImport => write!(f, "@cimport"),
             TokenType::InlineHint => write!(f, "@inlinehint"),
             TokenType::MinSize => write!(f, "@minsize"),
             TokenType::NoInline => write!(f, "@noinline"),
@@ -69,6 +71,7 @@ impl std::fmt::Display for TokenType {
             TokenType::SafeStack => write!(f, "@safestack"),
             TokenType::StrongStack => write!(f, "@strongstack"),
             TokenType::WeakStack => write!(f, "@weakstack"),
+            TokenType::Test => write!(f, "@test"),
             // Operators, Punctuation, and Special Constructs
             TokenType::Float => write!(f, "integer"),
             TokenType::Integer => write!(f, "float"),
@@ -85,7 +88,9 @@ impl std::fmt::Display for TokenType {
             TokenType::Comma => write!(f, ","),
             TokenType::Deref => write!(f, "deref"),
             TokenType::Dot => write!(f, "."),
+            TokenType::DocComment => write!(f, "///"),
             TokenType::Eof => write!(f, "EOF"),
+            TokenType::Error => write!(f, "error"),
             TokenType::Eq => write!(f, "="),
             TokenType::EqEq => write!(f, "=="),
             TokenType::Greater => write!(f, ">"),
@@ -124,6 +129,8 @@ impl std::fmt::Display for TokenType {
             TokenType::MemSet => write!(f, "memset"),
             TokenType::MemMove => write!(f, "memmove"),
             TokenType::MemCpy => write!(f, "memcpy"),
+            TokenType::Assert => write!(f, "assert"),
+            TokenType::AssertEq => write!(f, "assert_eq"),
         }
     }
 }
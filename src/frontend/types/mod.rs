@@ -1,4 +1,5 @@
 pub mod ast;
+pub mod cimport;
 pub mod lexer;
 pub mod parser;
 pub mod semantic;
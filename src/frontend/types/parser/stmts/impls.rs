@@ -72,6 +72,10 @@ impl ThrushAttributesExtensions for ThrushAttributes<'_> {
         self.iter().any(|attr| attr.is_asmthrow_attribute())
     }
 
+    fn has_test_attribute(&self) -> bool {
+        self.iter().any(|attr| attr.is_test_attribute())
+    }
+
     fn match_attr(&self, cmp: LLVMAttributeComparator) -> Option<Span> {
         if let Some(attr_found) = self.iter().find(|attr| attr.into_llvm_attr_cmp() == cmp) {
             return Some(attr_found.get_span());
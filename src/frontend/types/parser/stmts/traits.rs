@@ -86,5 +86,7 @@ pub trait ThrushAttributesExtensions {
     fn has_asmthrow_attribute(&self) -> bool;
     fn has_asmsideffects_attribute(&self) -> bool;
 
+    fn has_test_attribute(&self) -> bool;
+
     fn match_attr(&self, cmp: LLVMAttributeComparator) -> Option<Span>;
 }
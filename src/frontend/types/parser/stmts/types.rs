@@ -14,3 +14,6 @@ pub type CustomTypeFields<'ctx> = Vec<CustomTypeField<'ctx>>;
 pub type Constructor<'ctx> = Vec<(&'ctx str, Ast<'ctx>, Type, u32)>;
 
 pub type ThrushAttributes<'ctx> = Vec<LLVMAttribute<'ctx>>;
+
+/// The lines of the '///' comments above a declaration.
+pub type ThrushDocs<'ctx> = Vec<&'ctx str>;
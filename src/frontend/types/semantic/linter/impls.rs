@@ -24,6 +24,8 @@ impl LLVMAttributeComparatorExtensions for LLVMAttribute<'_> {
             LLVMAttribute::AsmSyntax(..) => LLVMAttributeComparator::AsmSyntax,
             LLVMAttribute::AsmThrow(..) => LLVMAttributeComparator::AsmThrow,
             LLVMAttribute::AsmSideEffects(..) => LLVMAttributeComparator::AsmSideEffects,
+            LLVMAttribute::Allow(..) => LLVMAttributeComparator::Allow,
+            LLVMAttribute::Test(..) => LLVMAttributeComparator::Test,
         }
     }
 }
@@ -67,4 +67,8 @@ pub enum LLVMAttributeComparator {
     AsmSyntax,
     AsmAlignStack,
     AsmSideEffects,
+
+    Allow,
+
+    Test,
 }
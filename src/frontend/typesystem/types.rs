@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    core::errors::standard::ThrushCompilerIssue,
+    core::{diagnostic::codes::DiagnosticCode, errors::standard::ThrushCompilerIssue},
     frontend::{
         lexer::span::Span,
         parser::symbols::SymbolsTable,
@@ -306,7 +306,7 @@ pub fn decompose_property(
                 inner_type
             } else {
                 return Err(ThrushCompilerIssue::Error(
-                    "Type error".into(),
+                    DiagnosticCode::TypeError,
                     "Properties of an non-typed pointer 'ptr' cannot be accessed.".into(),
                     None,
                     span,
@@ -376,7 +376,7 @@ pub fn decompose_property(
         }
 
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             format!("Expected existing property, not '{}'.", field_name,),
             None,
             span,
@@ -385,7 +385,7 @@ pub fn decompose_property(
 
     if position < property_names.len() {
         return Err(ThrushCompilerIssue::Error(
-            String::from("Syntax error"),
+            DiagnosticCode::SyntaxError,
             format!(
                 "Existing property '{}' is not a structure.",
                 property_names[position]
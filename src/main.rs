@@ -4,12 +4,15 @@ mod frontend;
 
 use {
     crate::core::{
-        compiler::thrushc::TheThrushCompiler,
+        compiler::{
+            options::CompilerOptions,
+            thrushc::{TheThrushCompiler, doc, repl},
+        },
         console::{cli::CLI, logging},
     },
     colored::{Colorize, control},
     lazy_static::lazy_static,
-    std::{env, process, time::Instant},
+    std::{env, process, process::Command, time::Instant},
 };
 
 fn main() {
@@ -22,10 +25,20 @@ fn main() {
 
     let cli: CLI = CLI::parse(env::args().collect());
 
+    if cli.get_options().get_repl_mode() {
+        repl::start(cli.get_options());
+    }
+
+    if let Some(format) = cli.get_options().get_doc_format() {
+        doc::generate(cli.get_options(), format);
+    }
+
     let start_time: Instant = Instant::now();
 
-    let compile_time: (u128, u128) =
-        TheThrushCompiler::new(cli.get_options().get_files(), cli.get_options()).compile();
+    let mut thrushc: TheThrushCompiler =
+        TheThrushCompiler::new(cli.get_options().get_files(), cli.get_options());
+
+    let compile_time: (u128, u128) = thrushc.compile();
 
     let thrushc_time: u128 = compile_time.0;
     let linking_time: u128 = compile_time.1;
@@ -61,5 +74,58 @@ fn main() {
         ),
     );
 
+    if thrushc.get_errors_count() > 0 {
+        process::exit(1);
+    }
+
+    if let Some(run_args) = cli.get_options().get_run_args() {
+        self::run_program(cli.get_options(), run_args, thrushc.get_linked());
+    }
+
     process::exit(0);
 }
+
+/// Runs the program built by 'thrushc run' and exits with its exit code.
+fn run_program(options: &CompilerOptions, run_args: &[String], linked: bool) -> ! {
+    // An output left by an earlier build isn't the program that was just compiled.
+    if !linked {
+        logging::log(
+            logging::LoggingType::Panic,
+            "The program was not linked by this build, so it can't run.",
+        );
+    }
+
+    let Some(program) = options
+        .get_llvm_backend_options()
+        .get_linking_compilers_configuration()
+        .get_output()
+    else {
+        logging::log(
+            logging::LoggingType::Panic,
+            "The project has no output to run.",
+        );
+
+        unreachable!()
+    };
+
+    logging::write(
+        logging::OutputIn::Stdout,
+        &format!(
+            "\n{} {}\n",
+            "Running".custom_color((141, 141, 142)).bold(),
+            program.display()
+        ),
+    );
+
+    match Command::new(program).args(run_args).status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(error) => {
+            logging::log(
+                logging::LoggingType::Panic,
+                &format!("Could not run '{}': {}.", program.display(), error),
+            );
+
+            unreachable!()
+        }
+    }
+}